@@ -0,0 +1,200 @@
+//! Abstraction of APPEND command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/append/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::append::AppendCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("append_key", "Hello").unwrap().wait().unwrap();
+//!
+//! let command = AppendCommand::new("append_key", " World");
+//! let response = client.send(command).unwrap().wait().unwrap();
+//!
+//! // Returns the length of the string after the append operation
+//! assert_eq!(11, response)
+//! ```
+//! # Shorthand
+//! [Client](Client#method.append) provides a shorthand method.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let _ = client.append("append_key", "value");
+//! ```
+//! # Chunked upload
+//! Values exceeding the available memory may be written in pieces by [Client](Client#method.set_chunked).
+//! The first chunk is written by SET, the following ones by APPEND, so the full value never needs to be held
+//! in memory at once.
+//!
+//! The reader fills the given buffer and returns the number of bytes written, 0 indicates the end of the value.
+//!
+//! *The upload is not atomic, so other clients could read a partial value in the meanwhile. If this is not
+//! acceptable, upload to a temporary key first and move it to the final key by RENAME afterward.*
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let mut source = &b"large value"[..];
+//! let length = client
+//!     .set_chunked("chunked_key", |buffer: &mut [u8]| {
+//!         let count = buffer.len().min(source.len());
+//!         buffer[..count].copy_from_slice(&source[..count]);
+//!         source = &source[count..];
+//!         count
+//!     }, 4)
+//!     .unwrap();
+//!
+//! assert_eq!(11, length);
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, IsNullFrame, ToInteger, ToStringBytes, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::future::Future;
+use crate::network::protocol::Protocol;
+use bytes::{Bytes, BytesMut};
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction for APPEND command
+pub struct AppendCommand {
+    key: Bytes,
+    value: Bytes,
+}
+
+impl AppendCommand {
+    pub fn new<K, V>(key: K, value: V) -> Self
+    where
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        AppendCommand {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl<F> Command<F> for AppendCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Length of the string after the append operation
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("APPEND").arg(&self.key).arg(&self.value).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [AppendCommand]
+    pub fn append<K, V>(
+        &'a self,
+        key: K,
+        value: V,
+    ) -> Result<Future<'a, N, C, P, AppendCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        self.send(AppendCommand::new(key, value))
+    }
+
+    /// Writes the value provided by the reader in chunks of the given size. The first chunk is written by SET,
+    /// all following chunks by APPEND. Blocks until all chunks are written and returns the total length.
+    ///
+    /// The reader fills the given buffer and returns the number of bytes written. 0 indicates the end of the value.
+    /// An empty value is written in case the reader does not provide any data.
+    /// [EncodingCommandFailed](CommandErrors::EncodingCommandFailed) is returned in case of a chunk size of 0.
+    ///
+    /// *Not atomic, so a partial value may be visible to other clients. Upload to a temporary key and RENAME
+    /// it afterward, if atomicity is required.*
+    pub fn set_chunked<K, R>(
+        &'a self,
+        key: K,
+        mut reader: R,
+        chunk_size: usize,
+    ) -> Result<usize, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: ToStringBytes,
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: IsNullFrame,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        R: FnMut(&mut [u8]) -> usize,
+    {
+        if chunk_size == 0 {
+            return Err(CommandErrors::EncodingCommandFailed);
+        }
+
+        let key: Bytes = key.into();
+        let mut total = 0;
+
+        loop {
+            let chunk = read_chunk(&mut reader, chunk_size);
+            let length = chunk.len();
+
+            if total == 0 {
+                self.set::<Bytes, Bytes>(key.clone(), chunk)?.wait()?;
+            } else if length > 0 {
+                self.append::<Bytes, Bytes>(key.clone(), chunk)?.wait()?;
+            }
+
+            if length == 0 {
+                return Ok(total);
+            }
+
+            total += length;
+        }
+    }
+}
+
+/// Reads the next chunk of the given max. size
+fn read_chunk<R: FnMut(&mut [u8]) -> usize>(reader: &mut R, chunk_size: usize) -> Bytes {
+    let mut buffer = BytesMut::zeroed(chunk_size);
+    let count = reader(&mut buffer).min(chunk_size);
+    buffer.truncate(count);
+    buffer.freeze()
+}
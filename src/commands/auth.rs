@@ -122,11 +122,7 @@ where
     }
 
     fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
-        if frame.to_string_option().ok_or(ResponseTypeError {})? != "OK" {
-            return Err(ResponseTypeError {});
-        }
-
-        Ok(())
+        frame.expect_simple_string("OK")
     }
 }
 
@@ -0,0 +1,204 @@
+//! Counter array based on BITFIELD command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/bitfield/>).
+//!
+//! # Counter array
+//! [CounterArray] packs many small unsigned counters of a fixed bit width into a single string key, e.g. for
+//! maintaining per-sensor event tallies in one key. The counters are addressed by index.
+//!
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::bitfield::{BitfieldOverflow, CounterArray};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!
+//! // 8 bit counters, saturating at 255
+//! let counters = CounterArray::new("sensor_events", 8).overflow(BitfieldOverflow::Sat);
+//! counters.set(&client, 3, 0).unwrap();
+//!
+//! assert_eq!(Some(1), counters.incr(&client, 3).unwrap());
+//! assert_eq!(1, counters.get(&client, 3).unwrap());
+//! ```
+//!
+//! # Overflow
+//! The behavior on overflow is defined by [BitfieldOverflow]. In case of [BitfieldOverflow::Fail], the counter is
+//! not modified and None is returned by [incr](CounterArray::incr) and [set](CounterArray::set).
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToNullableIntegerVec};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::protocol::Protocol;
+use alloc::format;
+use alloc::string::String;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Overflow behavior of BITFIELD write operations
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum BitfieldOverflow {
+    /// Wrap around on overflow and underflow (Redis default)
+    #[default]
+    Wrap,
+    /// Saturate at the min. or max. value
+    Sat,
+    /// Operation is not executed and null is returned
+    Fail,
+}
+
+impl BitfieldOverflow {
+    fn to_arg(self) -> &'static str {
+        match self {
+            BitfieldOverflow::Wrap => "WRAP",
+            BitfieldOverflow::Sat => "SAT",
+            BitfieldOverflow::Fail => "FAIL",
+        }
+    }
+}
+
+/// Array of fixed-width unsigned counters stored in a single string key
+#[derive(Debug, Clone)]
+pub struct CounterArray {
+    key: Bytes,
+
+    /// Encoding of a single counter, e.g. `u8`
+    encoding: Bytes,
+
+    overflow: BitfieldOverflow,
+}
+
+impl CounterArray {
+    /// Creates a new counter array using the given bit width per counter. Redis supports unsigned
+    /// counters of up to 63 bits.
+    pub fn new<K>(key: K, width: u8) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            key: key.into(),
+            encoding: <Bytes as From<String>>::from(format!("u{width}")),
+            overflow: BitfieldOverflow::default(),
+        }
+    }
+
+    /// Sets the overflow behavior of write operations
+    pub fn overflow(mut self, overflow: BitfieldOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Returns the value of the counter at the given index. Counters never written are zero.
+    pub fn get<'a, N, C, P>(
+        &self,
+        client: &'a Client<'a, N, C, P>,
+        index: usize,
+    ) -> Result<i64, CommandErrors>
+    where
+        N: TcpClientStack,
+        C: Clock,
+        P: Protocol,
+        AuthCommand: Command<<P as Protocol>::FrameType>,
+        HelloCommand: Command<<P as Protocol>::FrameType>,
+        <P as Protocol>::FrameType: From<CommandBuilder> + ToNullableIntegerVec,
+    {
+        let builder = self.builder().arg_static("GET").arg(&self.encoding).arg(&Self::offset(index));
+        client
+            .send(CounterCommand { builder })?
+            .wait()?
+            .ok_or(CommandErrors::CommandResponseViolation)
+    }
+
+    /// Increments the counter at the given index by one and returns the new value.
+    /// None in case the operation was not executed due to [BitfieldOverflow::Fail].
+    pub fn incr<'a, N, C, P>(
+        &self,
+        client: &'a Client<'a, N, C, P>,
+        index: usize,
+    ) -> Result<Option<i64>, CommandErrors>
+    where
+        N: TcpClientStack,
+        C: Clock,
+        P: Protocol,
+        AuthCommand: Command<<P as Protocol>::FrameType>,
+        HelloCommand: Command<<P as Protocol>::FrameType>,
+        <P as Protocol>::FrameType: From<CommandBuilder> + ToNullableIntegerVec,
+    {
+        let builder = self
+            .write_builder()
+            .arg_static("INCRBY")
+            .arg(&self.encoding)
+            .arg(&Self::offset(index))
+            .arg_static("1");
+        client.send(CounterCommand { builder })?.wait()
+    }
+
+    /// Sets the counter at the given index and returns the previous value.
+    /// None in case the operation was not executed due to [BitfieldOverflow::Fail].
+    pub fn set<'a, N, C, P>(
+        &self,
+        client: &'a Client<'a, N, C, P>,
+        index: usize,
+        value: u64,
+    ) -> Result<Option<i64>, CommandErrors>
+    where
+        N: TcpClientStack,
+        C: Clock,
+        P: Protocol,
+        AuthCommand: Command<<P as Protocol>::FrameType>,
+        HelloCommand: Command<<P as Protocol>::FrameType>,
+        <P as Protocol>::FrameType: From<CommandBuilder> + ToNullableIntegerVec,
+    {
+        let builder = self
+            .write_builder()
+            .arg_static("SET")
+            .arg(&self.encoding)
+            .arg(&Self::offset(index))
+            .arg(&Bytes::from(format!("{value}")));
+        client.send(CounterCommand { builder })?.wait()
+    }
+
+    fn builder(&self) -> CommandBuilder {
+        CommandBuilder::new("BITFIELD").arg(&self.key)
+    }
+
+    fn write_builder(&self) -> CommandBuilder {
+        self.builder().arg_static("OVERFLOW").arg_static(self.overflow.to_arg())
+    }
+
+    /// Offset of the counter at the given index, multiplied by the counter width on server side
+    fn offset(index: usize) -> Bytes {
+        Bytes::from(format!("#{index}"))
+    }
+}
+
+/// BITFIELD command consisting of a single operation
+struct CounterCommand {
+    builder: CommandBuilder,
+}
+
+impl<F> Command<F> for CounterCommand
+where
+    F: From<CommandBuilder> + ToNullableIntegerVec,
+{
+    type Response = Option<i64>;
+
+    fn encode(&self) -> F {
+        self.builder.clone().into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        match frame.to_nullable_integer_vec().ok_or(ResponseTypeError {})?[..] {
+            [value] => Ok(value),
+            _ => Err(ResponseTypeError {}),
+        }
+    }
+}
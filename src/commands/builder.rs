@@ -31,6 +31,7 @@
 //!     .into();
 //! ```
 use crate::commands::custom::CustomCommand;
+use crate::commands::ResponseTypeError;
 use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -77,6 +78,12 @@ impl CommandBuilder {
         self
     }
 
+    /// Adds casted string of signed integer
+    pub fn arg_int(mut self, arg: i64) -> Self {
+        self.elements.push(Bytes::from(arg.to_string()));
+        self
+    }
+
     /// Adds a byte argument
     /// Note: Besides static, the most efficient way caused by the nature how Bytes cloning is working
     pub fn arg(mut self, arg: &Bytes) -> Self {
@@ -130,6 +137,15 @@ impl From<CommandBuilder> for CustomCommand {
 /// Unification for `to_string()` of RESP2/3 frames
 pub trait ToStringOption {
     fn to_string_option(&self) -> Option<String>;
+
+    /// Asserts that the frame is a string matching the expected confirmation (e.g. "OK" or "QUEUED")
+    fn expect_simple_string(&self, expected: &str) -> Result<(), ResponseTypeError> {
+        if self.to_string_option().ok_or(ResponseTypeError {})? != expected {
+            return Err(ResponseTypeError {});
+        }
+
+        Ok(())
+    }
 }
 
 impl ToStringOption for Resp2Frame {
@@ -185,6 +201,104 @@ impl ToInteger for Resp3Frame {
     }
 }
 
+/// Unification for extracting boolean values of Frames
+///
+/// RESP3 replies may contain native boolean frames, while RESP2 replies represent booleans as `:1`/`:0`.
+pub trait ToBool {
+    /// Returns the boolean value. None in case frame is neither boolean type nor integer 0 or 1.
+    fn to_bool(&self) -> Option<bool>;
+}
+
+impl ToBool for Resp2Frame {
+    fn to_bool(&self) -> Option<bool> {
+        match self {
+            Resp2Frame::Integer(number) => integer_to_bool(*number),
+            _ => None,
+        }
+    }
+}
+
+impl ToBool for Resp3Frame {
+    fn to_bool(&self) -> Option<bool> {
+        match self {
+            Resp3Frame::Boolean { data, attributes: _ } => Some(*data),
+            Resp3Frame::Number { data, attributes: _ } => integer_to_bool(*data),
+            _ => None,
+        }
+    }
+}
+
+/// Maps integer replies 0 and 1 to boolean
+fn integer_to_bool(number: i64) -> Option<bool> {
+    match number {
+        0 => Some(false),
+        1 => Some(true),
+        _ => None,
+    }
+}
+
+/// Trait for converting RESP2/3 arrays of integers
+pub trait ToIntegerVec {
+    /// Converts the frame to a list of integer values
+    /// Returns None in case of protocol violation
+    fn to_integer_vec(&self) -> Option<Vec<i64>>;
+}
+
+impl ToIntegerVec for Resp2Frame {
+    fn to_integer_vec(&self) -> Option<Vec<i64>> {
+        match self {
+            Resp2Frame::Array(array) => array.iter().map(|item| item.to_integer()).collect(),
+            _ => None,
+        }
+    }
+}
+
+impl ToIntegerVec for Resp3Frame {
+    fn to_integer_vec(&self) -> Option<Vec<i64>> {
+        match self {
+            Resp3Frame::Array { data, attributes: _ } => data.iter().map(|item| item.to_integer()).collect(),
+            _ => None,
+        }
+    }
+}
+
+/// Trait for converting RESP2/3 arrays of integers, which may contain null elements (e.g. BITFIELD response)
+pub trait ToNullableIntegerVec {
+    /// Converts the frame to a list of optional integer values. Null elements are mapped to None.
+    /// Returns None in case of protocol violation
+    fn to_nullable_integer_vec(&self) -> Option<Vec<Option<i64>>>;
+}
+
+impl ToNullableIntegerVec for Resp2Frame {
+    fn to_nullable_integer_vec(&self) -> Option<Vec<Option<i64>>> {
+        match self {
+            Resp2Frame::Array(array) => array
+                .iter()
+                .map(|item| match item {
+                    Resp2Frame::Null => Some(None),
+                    _ => item.to_integer().map(Some),
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+impl ToNullableIntegerVec for Resp3Frame {
+    fn to_nullable_integer_vec(&self) -> Option<Vec<Option<i64>>> {
+        match self {
+            Resp3Frame::Array { data, attributes: _ } => data
+                .iter()
+                .map(|item| match item {
+                    Resp3Frame::Null => Some(None),
+                    _ => item.to_integer().map(Some),
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
 /// Trait for string extraction of RESP2/3 frames
 pub trait ToStringBytes {
     /// Extracts Bytes of Bulk (RESP2) or BLOB (RESP3) frames
@@ -276,3 +390,153 @@ impl ToBytesMap for Resp3Frame {
         Some(map)
     }
 }
+
+/// Trait for converting RESP2/3 arrays of strings
+pub trait ToBytesVec {
+    /// Converts the frame to a list of string values. RESP3 sets are accepted as well, in undefined order.
+    /// Returns None in case of protocol violation
+    fn to_bytes_vec(&self) -> Option<Vec<Bytes>>;
+}
+
+impl ToBytesVec for Resp2Frame {
+    fn to_bytes_vec(&self) -> Option<Vec<Bytes>> {
+        match self {
+            Resp2Frame::Array(array) => array
+                .iter()
+                .map(|item| match item {
+                    Resp2Frame::SimpleString(value) | Resp2Frame::BulkString(value) => Some(value.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+impl ToBytesVec for Resp3Frame {
+    fn to_bytes_vec(&self) -> Option<Vec<Bytes>> {
+        match self {
+            Resp3Frame::Array { data, attributes: _ } => resp3_string_elements(data.iter()),
+            Resp3Frame::Set { data, attributes: _ } => resp3_string_elements(data.iter()),
+            _ => None,
+        }
+    }
+}
+
+/// Collects the string values of RESP3 array or set elements. None if any element is not a string.
+fn resp3_string_elements<'a, I: Iterator<Item = &'a Resp3Frame>>(elements: I) -> Option<Vec<Bytes>> {
+    elements
+        .map(|item| match item {
+            Resp3Frame::BlobString { data, attributes: _ }
+            | Resp3Frame::SimpleString { data, attributes: _ } => Some(data.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Trait for converting RESP2/RESP3 arrays of string values, which may contain null elements (e.g. MGET response)
+pub trait ToNullableBytesVec {
+    /// Converts the frame to a list of optional string values. Null elements are mapped to None, while the
+    /// remaining elements are kept in order.
+    ///
+    /// Only element-level nulls are accepted, so None is returned in case of protocol violation, including a
+    /// top-level null instead of an array. An empty array results in an empty list.
+    fn to_nullable_bytes_vec(&self) -> Option<Vec<Option<Bytes>>>;
+}
+
+impl ToNullableBytesVec for Resp2Frame {
+    fn to_nullable_bytes_vec(&self) -> Option<Vec<Option<Bytes>>> {
+        match self {
+            Resp2Frame::Array(array) => array
+                .iter()
+                .map(|item| match item {
+                    Resp2Frame::Null => Some(None),
+                    Resp2Frame::SimpleString(value) | Resp2Frame::BulkString(value) => {
+                        Some(Some(value.clone()))
+                    }
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+impl ToNullableBytesVec for Resp3Frame {
+    fn to_nullable_bytes_vec(&self) -> Option<Vec<Option<Bytes>>> {
+        match self {
+            Resp3Frame::Array { data, attributes: _ } => data
+                .iter()
+                .map(|item| match item {
+                    Resp3Frame::Null => Some(None),
+                    Resp3Frame::BlobString { data, attributes: _ }
+                    | Resp3Frame::SimpleString { data, attributes: _ } => Some(Some(data.clone())),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+/// Trait for extracting big numbers (arbitrary-precision integers) of RESP2/3 frames
+pub trait ToBigNumber {
+    /// Returns the digits (including optional sign) of the big number
+    /// None in case frame is neither a RESP3 big number nor a RESP2 bulk string containing an integer
+    fn to_big_number(&self) -> Option<Bytes>;
+}
+
+impl ToBigNumber for Resp2Frame {
+    fn to_big_number(&self) -> Option<Bytes> {
+        match self {
+            Resp2Frame::BulkString(data) if is_big_number(data) => Some(data.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl ToBigNumber for Resp3Frame {
+    fn to_big_number(&self) -> Option<Bytes> {
+        match self {
+            Resp3Frame::BigNumber { data, attributes: _ } => Some(data.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Trait for extracting floating point numbers (e.g. sorted set scores) of RESP2/3 frames
+pub trait ToDouble {
+    /// Returns the parsed floating point number
+    /// None in case frame is neither a RESP3 double nor a bulk/blob string containing a number
+    fn to_double(&self) -> Option<f64>;
+}
+
+impl ToDouble for Resp2Frame {
+    fn to_double(&self) -> Option<f64> {
+        match self {
+            Resp2Frame::BulkString(data) => parse_double(data),
+            _ => None,
+        }
+    }
+}
+
+impl ToDouble for Resp3Frame {
+    fn to_double(&self) -> Option<f64> {
+        match self {
+            Resp3Frame::Double { data, attributes: _ } => Some(*data),
+            Resp3Frame::BlobString { data, attributes: _ } => parse_double(data),
+            _ => None,
+        }
+    }
+}
+
+/// Parses the given string representation of a floating point number, e.g. "3.5" or "inf"
+fn parse_double(data: &Bytes) -> Option<f64> {
+    core::str::from_utf8(data).ok()?.parse().ok()
+}
+
+/// Returns true if the given bytes represent a signed integer of arbitrary length
+fn is_big_number(data: &Bytes) -> bool {
+    let digits = data.strip_prefix(b"-").unwrap_or(data);
+    !digits.is_empty() && digits.iter().all(u8::is_ascii_digit)
+}
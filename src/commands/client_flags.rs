@@ -0,0 +1,153 @@
+//! Abstraction of CLIENT NO-EVICT and CLIENT NO-TOUCH commands.
+//!
+//! For general information about these commands, see the Redis documentation of
+//! [CLIENT NO-EVICT](<https://redis.io/commands/client-no-evict/>) and
+//! [CLIENT NO-TOUCH](<https://redis.io/commands/client-no-touch/>).
+//!
+//! Both flags apply to the current connection only, e.g. for a background maintenance connection, which should
+//! not perturb the LRU/LFU statistics of the keys accessed.
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::client_flags::{ClientNoEvictCommand, ClientNoTouchCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!
+//! // Connection is excluded from client eviction
+//! client.send(ClientNoEvictCommand::new(true)).unwrap().wait().unwrap();
+//!
+//! // Commands of this connection no longer alter the last access time of keys
+//! client.send(ClientNoTouchCommand::new(true)).unwrap().wait().unwrap();
+//! ```
+//! # Shorthand
+//! [Client](Client#method.no_evict) and [Client](Client#method.no_touch) provide shorthand methods.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! client.no_touch(true).unwrap().wait().unwrap();
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::set::ConfirmationResponse;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of CLIENT NO-EVICT command
+pub struct ClientNoEvictCommand {
+    enabled: bool,
+}
+
+impl ClientNoEvictCommand {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+impl<F> Command<F> for ClientNoEvictCommand
+where
+    F: From<CommandBuilder> + ToStringOption,
+{
+    type Response = ConfirmationResponse;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("CLIENT")
+            .arg_static("NO-EVICT")
+            .arg_static(switch(self.enabled))
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.expect_simple_string("OK")
+    }
+}
+
+/// Abstraction of CLIENT NO-TOUCH command
+pub struct ClientNoTouchCommand {
+    enabled: bool,
+}
+
+impl ClientNoTouchCommand {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+impl<F> Command<F> for ClientNoTouchCommand
+where
+    F: From<CommandBuilder> + ToStringOption,
+{
+    type Response = ConfirmationResponse;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("CLIENT")
+            .arg_static("NO-TOUCH")
+            .arg_static(switch(self.enabled))
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.expect_simple_string("OK")
+    }
+}
+
+/// ON/OFF token of the flag commands
+fn switch(enabled: bool) -> &'static str {
+    if enabled {
+        "ON"
+    } else {
+        "OFF"
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [ClientNoEvictCommand]
+    pub fn no_evict(
+        &'a self,
+        enabled: bool,
+    ) -> Result<Future<'a, N, C, P, ClientNoEvictCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(ClientNoEvictCommand::new(enabled))
+    }
+
+    /// Shorthand for [ClientNoTouchCommand]
+    pub fn no_touch(
+        &'a self,
+        enabled: bool,
+    ) -> Result<Future<'a, N, C, P, ClientNoTouchCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(ClientNoTouchCommand::new(enabled))
+    }
+}
@@ -0,0 +1,154 @@
+//! Abstraction of CLIENT LIST command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/client-list/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::client_list::ClientListCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!
+//! let clients = client.send(ClientListCommand::default()).unwrap().wait().unwrap();
+//!
+//! // At least the own connection is listed
+//! assert!(!clients.is_empty());
+//! assert!(clients[0].id().is_some());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.client_list) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let clients = client.client_list().unwrap().wait().unwrap();
+//!
+//! for info in clients {
+//!     let _ = info.get_str("addr");
+//! }
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::CommandBuilder;
+use crate::commands::custom::{RedisValue, ToRedisValue};
+use crate::commands::hello::HelloCommand;
+use crate::commands::multi_reply::{parse_properties, MultiReplyCommand};
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of CLIENT LIST command
+#[derive(Default)]
+pub struct ClientListCommand {}
+
+/// Properties of a single client connection
+#[derive(Debug, Clone)]
+pub struct ClientInfo {
+    /// Property/Value map (e.g. id, addr, name, db)
+    inner: BTreeMap<Bytes, Bytes>,
+}
+
+impl ClientInfo {
+    pub fn new(inner: BTreeMap<Bytes, Bytes>) -> Self {
+        Self { inner }
+    }
+
+    /// Extracts inner map
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_map(self) -> BTreeMap<Bytes, Bytes> {
+        self.inner
+    }
+
+    /// Returns the given property as &str. Returns None in case property is missing or value has invalid UTF8 encoding
+    pub fn get_str<F>(&self, property: F) -> Option<&str>
+    where
+        Bytes: From<F>,
+    {
+        let property: Bytes = property.into();
+
+        match self.inner.get(&property) {
+            None => None,
+            Some(value) => core::str::from_utf8(value).ok(),
+        }
+    }
+
+    /// Unique client ID
+    pub fn id(&self) -> Option<u64> {
+        self.get_str("id")?.parse().ok()
+    }
+
+    /// Address/Port of the client
+    pub fn addr(&self) -> Option<&str> {
+        self.get_str("addr")
+    }
+
+    /// Name set by CLIENT SETNAME. None if property is missing or empty.
+    pub fn name(&self) -> Option<&str> {
+        self.get_str("name").filter(|name| !name.is_empty())
+    }
+}
+
+impl MultiReplyCommand for ClientListCommand {
+    type Entry = ClientInfo;
+
+    fn parse_entry(&self, line: Bytes) -> Option<Self::Entry> {
+        Some(ClientInfo::new(parse_properties(&line)?))
+    }
+}
+
+impl<F> Command<F> for ClientListCommand
+where
+    F: From<CommandBuilder> + ToRedisValue,
+{
+    type Response = Vec<ClientInfo>;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("CLIENT").arg_static("LIST").into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        // Reply is a bulk string (RESP2) respectively verbatim string (RESP3)
+        let reply = match frame.to_redis_value() {
+            Some(RedisValue::Data(reply)) => reply,
+            _ => return Err(ResponseTypeError {}),
+        };
+
+        self.parse_reply(&reply).ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [ClientListCommand]
+    pub fn client_list(&'a self) -> Result<Future<'a, N, C, P, ClientListCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToRedisValue,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(ClientListCommand::default())
+    }
+}
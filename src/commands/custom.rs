@@ -3,9 +3,10 @@
 //! [CustomCommand] in combination with [CommandBuilder] can be used for executing arbitrary commands,
 //! which high level logic is not abstracted yet by this crate.
 //!
-//! Response is not evaluated, so pure [Resp2Frame](redis_protocol::resp2::types::BytesFrame)
+//! By default response is not evaluated, so pure [Resp2Frame](redis_protocol::resp2::types::BytesFrame)
 //! or [Resp3Frame](redis_protocol::resp3::types::BytesFrame) is returned.
-//! The only exception is that error responses are intercepted and converted to [CommandErrors::ErrorResponse](crate::network::CommandErrors::ErrorResponse)
+//! The only exception is that error responses are intercepted and converted to [CommandErrors::ErrorResponse]
+//! For simple response types, typed responses may be used instead (s. [Typed responses](#typed-responses)).
 //!
 //! *Please consider contributing command abstractions not supported yet.*
 //!
@@ -30,21 +31,159 @@
 //! let response = client.send(command).unwrap().wait().unwrap();
 //! assert_eq!("Hello World!", response.to_string().unwrap());
 //! ```
-use crate::commands::builder::CommandBuilder;
+//! # Typed responses
+//! For simple response types, the response may be evaluated by the command, so no matching of raw frames is required.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! // OK confirmation
+//! let command = CommandBuilder::new("SET").arg_static("custom_key").arg_static("10").to_command().expect_ok();
+//! client.send(command).unwrap().wait().unwrap();
+//!
+//! // Integer response
+//! let command = CommandBuilder::new("INCR").arg_static("custom_key").to_command().expect_integer();
+//! assert_eq!(11, client.send(command).unwrap().wait().unwrap());
+//!
+//! // String response
+//! let command = CommandBuilder::new("ECHO").arg_static("Hello World!").to_command().expect_string();
+//! assert_eq!("Hello World!", client.send(command).unwrap().wait().unwrap().unwrap());
+//! ```
+//! # Generic values
+//! [RedisValue] is a protocol independent representation of any response. [Client](Client#method.call) provides
+//! the simplest way of executing arbitrary commands, e.g. for quick scripts and prototyping.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::custom::RedisValue;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.set("custom_counter", "0").unwrap().wait().unwrap();
+//!#
+//! let value = client.call("INCR", &["custom_counter".into()]).unwrap();
+//! assert_eq!(RedisValue::Int(1), value);
+//! ```
+//! # Command templates
+//! App specific command families executed repeatedly with different arguments may be defined once by
+//! [CommandTemplate]. The keyword and fixed arguments are prepared once, while the closure appends the
+//! variable arguments on each instantiation.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::commands::custom::CommandTemplate;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let prefix = CommandBuilder::new("GEORADIUS").arg_static("sensors");
+//! let nearby = CommandTemplate::from_builder(prefix, |builder, (longitude, latitude): (&'static str, &'static str)| {
+//!     builder.arg_static(longitude).arg_static(latitude).arg_static("10").arg_static("km")
+//! });
+//!
+//! let _ = client.send(nearby.instantiate(("13.361389", "38.115556")));
+//! let _ = client.send(nearby.instantiate(("15.087269", "37.502669")));
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, IsNullFrame, ToInteger, ToStringBytes, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::set::ConfirmationResponse;
 use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors};
+use alloc::vec;
+use alloc::vec::Vec;
+use bytes::Bytes;
+use core::marker::PhantomData;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+use redis_protocol::resp2::types::BytesFrame as Resp2Frame;
+use redis_protocol::resp3::types::BytesFrame as Resp3Frame;
 
 /// Abstraction for arbitrary commands.
-pub struct CustomCommand {
+///
+/// By default the response is not evaluated, s. [RawResponse].
+pub struct CustomCommand<R = RawResponse> {
     builder: CommandBuilder,
+    response_type: PhantomData<R>,
 }
 
+/// Default response type. The frame is returned 1:1 without any evaluation.
+pub struct RawResponse {}
+
+/// Response if [CustomCommand::expect_integer] is used.
+pub type IntegerResponse = i64;
+
+/// Response if [CustomCommand::expect_string] is used.
+///
+/// Some => The received string value.
+/// None => Redis responded with NIL/NULL.
+pub type StringResponse = Option<Bytes>;
+
 impl CustomCommand {
     pub fn new(builder: CommandBuilder) -> Self {
-        CustomCommand { builder }
+        CustomCommand {
+            builder,
+            response_type: PhantomData,
+        }
+    }
+
+    /// Expects an integer response.
+    /// [CommandResponseViolation](crate::network::CommandErrors::CommandResponseViolation) is returned for any other response type.
+    pub fn expect_integer(self) -> CustomCommand<IntegerResponse> {
+        self.convert()
+    }
+
+    /// Expects a bulk (RESP2) or blob (RESP3) string or NIL/NULL response.
+    /// [CommandResponseViolation](crate::network::CommandErrors::CommandResponseViolation) is returned for any other response type.
+    pub fn expect_string(self) -> CustomCommand<StringResponse> {
+        self.convert()
+    }
+
+    /// Expects an "OK" confirmation.
+    /// [CommandResponseViolation](crate::network::CommandErrors::CommandResponseViolation) is returned for any other response.
+    pub fn expect_ok(self) -> CustomCommand<ConfirmationResponse> {
+        self.convert()
+    }
+
+    /// Converts any response to the protocol independent [RedisValue]
+    pub fn expect_value(self) -> CustomCommand<RedisValue> {
+        self.convert()
+    }
+
+    /// Switches the response type
+    fn convert<R>(self) -> CustomCommand<R> {
+        CustomCommand {
+            builder: self.builder,
+            response_type: PhantomData,
+        }
     }
 }
 
-impl<F> Command<F> for CustomCommand
+impl<F> Command<F> for CustomCommand<RawResponse>
 where
     F: From<CommandBuilder>,
 {
@@ -58,3 +197,204 @@ where
         Ok(frame)
     }
 }
+
+impl<F> Command<F> for CustomCommand<IntegerResponse>
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    type Response = IntegerResponse;
+
+    fn encode(&self) -> F {
+        self.builder.clone().into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<F> Command<F> for CustomCommand<StringResponse>
+where
+    F: From<CommandBuilder> + IsNullFrame + ToStringBytes,
+{
+    type Response = StringResponse;
+
+    fn encode(&self) -> F {
+        self.builder.clone().into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        if frame.is_null_frame() {
+            return Ok(None);
+        }
+
+        Ok(Some(frame.to_string_bytes().ok_or(ResponseTypeError {})?))
+    }
+}
+
+impl<F> Command<F> for CustomCommand<ConfirmationResponse>
+where
+    F: From<CommandBuilder> + ToStringOption,
+{
+    type Response = ConfirmationResponse;
+
+    fn encode(&self) -> F {
+        self.builder.clone().into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.expect_simple_string("OK")
+    }
+}
+
+impl<F> Command<F> for CustomCommand<RedisValue>
+where
+    F: From<CommandBuilder> + ToRedisValue,
+{
+    type Response = RedisValue;
+
+    fn encode(&self) -> F {
+        self.builder.clone().into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_redis_value().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Protocol independent representation of a response
+#[derive(Debug, Clone, PartialEq)]
+pub enum RedisValue {
+    /// NIL (RESP2) or NULL (RESP3)
+    Nil,
+    Int(i64),
+    /// RESP3 double
+    Double(f64),
+    /// RESP3 boolean
+    Bool(bool),
+    /// Simple string, e.g. `OK`
+    Status(Bytes),
+    /// Bulk/blob string. Also used for RESP3 verbatim strings and big numbers.
+    Data(Bytes),
+    /// Array. Also used for RESP3 sets and push frames.
+    Array(Vec<RedisValue>),
+    /// RESP3 map as list of key/value pairs
+    Map(Vec<(RedisValue, RedisValue)>),
+}
+
+/// Trait for converting RESP2/RESP3 frames to [RedisValue]
+pub trait ToRedisValue {
+    /// Returns None in case of error frames or frames without value (e.g. HELLO)
+    fn to_redis_value(&self) -> Option<RedisValue>;
+}
+
+impl ToRedisValue for Resp2Frame {
+    fn to_redis_value(&self) -> Option<RedisValue> {
+        match self {
+            Resp2Frame::SimpleString(data) => Some(RedisValue::Status(data.clone())),
+            Resp2Frame::BulkString(data) => Some(RedisValue::Data(data.clone())),
+            Resp2Frame::Integer(number) => Some(RedisValue::Int(*number)),
+            Resp2Frame::Array(frames) => to_value_array(frames),
+            Resp2Frame::Null => Some(RedisValue::Nil),
+            Resp2Frame::Error(_) => None,
+        }
+    }
+}
+
+impl ToRedisValue for Resp3Frame {
+    fn to_redis_value(&self) -> Option<RedisValue> {
+        match self {
+            Resp3Frame::SimpleString { data, .. } => Some(RedisValue::Status(data.clone())),
+            Resp3Frame::BlobString { data, .. }
+            | Resp3Frame::VerbatimString { data, .. }
+            | Resp3Frame::BigNumber { data, .. }
+            | Resp3Frame::ChunkedString(data) => Some(RedisValue::Data(data.clone())),
+            Resp3Frame::Number { data, .. } => Some(RedisValue::Int(*data)),
+            Resp3Frame::Double { data, .. } => Some(RedisValue::Double(*data)),
+            Resp3Frame::Boolean { data, .. } => Some(RedisValue::Bool(*data)),
+            Resp3Frame::Null => Some(RedisValue::Nil),
+            Resp3Frame::Array { data, .. } | Resp3Frame::Push { data, .. } => to_value_array(data),
+            Resp3Frame::Set { data, .. } => to_value_array(data),
+            Resp3Frame::Map { data, .. } => {
+                let mut pairs = Vec::with_capacity(data.len());
+                for (key, value) in data {
+                    pairs.push((key.to_redis_value()?, value.to_redis_value()?));
+                }
+                Some(RedisValue::Map(pairs))
+            }
+            Resp3Frame::BlobError { .. } | Resp3Frame::SimpleError { .. } | Resp3Frame::Hello { .. } => None,
+        }
+    }
+}
+
+/// Converts all elements, None if any element is not convertible
+fn to_value_array<'a, F: ToRedisValue + 'a>(frames: impl IntoIterator<Item = &'a F>) -> Option<RedisValue> {
+    let values: Option<Vec<RedisValue>> = frames.into_iter().map(|frame| frame.to_redis_value()).collect();
+    Some(RedisValue::Array(values?))
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Executes the given command with the given arguments and returns the response as [RedisValue].
+    /// Blocks until the response is received.
+    ///
+    /// Simplest way of executing arbitrary commands. For repeated or performance critical use,
+    /// [CommandBuilder] or command abstractions are recommended.
+    pub fn call(&'a self, name: &str, args: &[Bytes]) -> Result<RedisValue, CommandErrors>
+    where
+        <P as Protocol>::FrameType: From<CommandBuilder> + ToRedisValue,
+    {
+        let mut builder = CommandBuilder {
+            elements: vec![Bytes::copy_from_slice(name.as_bytes())],
+        };
+        for arg in args {
+            builder = builder.arg(arg);
+        }
+
+        self.send(builder.to_command().expect_value())?.wait()
+    }
+}
+
+/// Template for instantiating [CustomCommand] of the same command family with different arguments.
+///
+/// A: Input of the closure, e.g. a tuple of variable arguments
+pub struct CommandTemplate<A, G>
+where
+    G: Fn(CommandBuilder, A) -> CommandBuilder,
+{
+    /// Keyword and fixed arguments
+    prefix: CommandBuilder,
+
+    /// Appends the variable arguments
+    args: G,
+
+    input: PhantomData<fn(A)>,
+}
+
+impl<A, G> CommandTemplate<A, G>
+where
+    G: Fn(CommandBuilder, A) -> CommandBuilder,
+{
+    /// Creates a template for the given keyword. The closure appends the variable arguments.
+    pub fn new(keyword: &'static str, args: G) -> Self {
+        Self::from_builder(CommandBuilder::new(keyword), args)
+    }
+
+    /// Creates a template using the given builder, including fixed arguments, as prefix
+    pub fn from_builder(prefix: CommandBuilder, args: G) -> Self {
+        CommandTemplate {
+            prefix,
+            args,
+            input: PhantomData,
+        }
+    }
+
+    /// Creates a new command by appending the arguments of the given input to the prefix.
+    /// The response may be typed afterward, e.g. by [CustomCommand::expect_integer].
+    pub fn instantiate(&self, input: A) -> CustomCommand {
+        CustomCommand::new((self.args)(self.prefix.clone(), input))
+    }
+}
@@ -0,0 +1,187 @@
+//! Abstraction of DEL command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/del/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::del::DelCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("del_key", "example").unwrap().wait().unwrap();
+//!
+//! // Number of deleted keys
+//! let command = DelCommand::new(["del_key".into(), "not_existing".into()]);
+//! assert_eq!(1, client.send(command).unwrap().wait().unwrap());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.del) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let _ = client.del(["del_key".into()]).unwrap().wait().unwrap();
+//! ```
+//! # Deleting many keys
+//! Deleting thousands of keys by a single command blocks constrained servers and requires large buffers.
+//! [Client](Client#method.del_many_pipelined) splits the keys into DEL batches, which are sent as pipeline.
+//! The batch size is limited by [max_command_size](crate::network::ConnectionHandler::max_command_size).
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!# use bytes::Bytes;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let keys: Vec<Bytes> = (0..1000).map(|index| format!("sample_{index}").into()).collect();
+//! let deleted = client.del_many_pipelined(&keys).unwrap();
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future, Pipeline};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of DEL command
+pub struct DelCommand<const N: usize> {
+    keys: [Bytes; N],
+}
+
+impl<const N: usize> DelCommand<N> {
+    pub fn new(keys: [Bytes; N]) -> Self {
+        Self { keys }
+    }
+}
+
+impl<F, const N: usize> Command<F> for DelCommand<N>
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Number of deleted keys
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("DEL");
+
+        for key in &self.keys {
+            builder = builder.arg(key);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [DelCommand]
+    pub fn del<const L: usize>(
+        &'a self,
+        keys: [Bytes; L],
+    ) -> Result<Future<'a, N, C, P, DelCommand<L>>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(DelCommand::new(keys))
+    }
+
+    /// Deletes the given keys by DEL batches, which are sent as pipeline. Returns the total number of deleted keys.
+    /// Blocks until all responses are received.
+    ///
+    /// Each batch respects [max_command_size](crate::network::ConnectionHandler::max_command_size), except keys
+    /// exceeding the limit on their own, which are deleted by a separate command.
+    /// Returns zero without any request in case of empty key list.
+    pub fn del_many_pipelined(&'a self, keys: &[Bytes]) -> Result<usize, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        let max_size = self.network.max_command_size();
+        let mut pipeline = Pipeline::new();
+
+        let mut batch = CommandBuilder::new("DEL");
+        let mut batch_len = 0;
+        let mut batch_size = argument_size(b"DEL");
+
+        for key in keys {
+            let key_size = argument_size(key);
+
+            if batch_len > 0 && encoded_size(batch_len + 2, batch_size + key_size) > max_size {
+                pipeline = pipeline.push(batch);
+                batch = CommandBuilder::new("DEL");
+                batch_len = 0;
+                batch_size = argument_size(b"DEL");
+            }
+
+            batch = batch.arg(key);
+            batch_len += 1;
+            batch_size += key_size;
+        }
+
+        if batch_len > 0 {
+            pipeline = pipeline.push(batch);
+        }
+
+        if pipeline.is_empty() {
+            return Ok(0);
+        }
+
+        let mut deleted = 0;
+        for frame in self.pipeline(pipeline)? {
+            let count = frame.to_integer().ok_or(CommandErrors::CommandResponseViolation)?;
+            deleted += usize::try_from(count).map_err(|_| CommandErrors::CommandResponseViolation)?;
+        }
+
+        Ok(deleted)
+    }
+}
+
+/// Encoded size of a bulk string argument (`$<len>\r\n<data>\r\n`)
+fn argument_size(argument: &[u8]) -> usize {
+    1 + digits(argument.len()) + 2 + argument.len() + 2
+}
+
+/// Encoded size of a command consisting of the given number of arguments (`*<count>\r\n<arguments>`)
+fn encoded_size(count: usize, arguments_size: usize) -> usize {
+    1 + digits(count) + 2 + arguments_size
+}
+
+/// Number of decimal digits
+fn digits(value: usize) -> usize {
+    value.checked_ilog10().unwrap_or(0) as usize + 1
+}
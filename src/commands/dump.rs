@@ -0,0 +1,264 @@
+//! Abstraction of DUMP and RESTORE commands.
+//!
+//! For general information about these commands, see the Redis documentation of
+//! [DUMP](<https://redis.io/commands/dump/>) and [RESTORE](<https://redis.io/commands/restore/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::dump::{DumpCommand, RestoreCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("dump_key", "value").unwrap().wait().unwrap();
+//!
+//! // Serialized value in Redis specific format. None in case of missing key.
+//! let payload = client.send(DumpCommand::new("dump_key")).unwrap().wait().unwrap().unwrap();
+//!
+//! // Restoring with TTL of 10 seconds. Existing key is replaced.
+//! let command = RestoreCommand::new("restored_key", 10_000, payload).replace();
+//! client.send(command).unwrap().wait().unwrap();
+//!
+//! let value = client.get("restored_key").unwrap().wait().unwrap().unwrap();
+//! assert_eq!("value", value.as_str().unwrap());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.dump) and [Client](Client#method.restore) provide shorthand methods.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.set("dump_key", "value").unwrap().wait().unwrap();
+//!# client.del(["restored_key".into()]).unwrap().wait().unwrap();
+//!#
+//! let payload = client.dump("dump_key").unwrap().wait().unwrap().unwrap();
+//!
+//! // TTL of zero restores the key without expiry
+//! client.restore("restored_key", 0, payload).unwrap().wait().unwrap();
+//! ```
+//! # Migrating keys
+//! [Client](Client#method.migrate_all) copies all keys matching a pattern to another connection, e.g. another
+//! Redis instance. TTLs are preserved.
+//! ```no_run
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut source_stack = Stack::default();
+//!# let mut destination_stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//! let mut source_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let source = source_handler.connect(&mut source_stack, Some(&clock)).unwrap();
+//!
+//! let mut destination_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6380").unwrap());
+//! let destination = destination_handler.connect(&mut destination_stack, Some(&clock)).unwrap();
+//!
+//! let migrated = source.migrate_all(&destination, "sensor:*").unwrap();
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, IsNullFrame, ToInteger, ToStringBytes, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::scan::{ScanCommand, ToScanResponse};
+use crate::commands::set::ConfirmationResponse;
+use crate::commands::ttl::TtlResponse;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of DUMP command
+pub struct DumpCommand {
+    key: Bytes,
+}
+
+impl DumpCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for DumpCommand
+where
+    F: From<CommandBuilder> + IsNullFrame + ToStringBytes,
+{
+    /// Serialized value. None in case of missing key.
+    type Response = Option<Bytes>;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("DUMP").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        if frame.is_null_frame() {
+            return Ok(None);
+        }
+
+        Ok(Some(frame.to_string_bytes().ok_or(ResponseTypeError {})?))
+    }
+}
+
+/// Abstraction of RESTORE command
+pub struct RestoreCommand {
+    key: Bytes,
+
+    /// TTL in milliseconds, zero for no expiry
+    ttl: usize,
+
+    /// Serialized value as returned by DUMP
+    payload: Bytes,
+
+    /// REPLACE option
+    replace: bool,
+}
+
+impl RestoreCommand {
+    /// Restores the given payload. TTL is given in milliseconds, zero for no expiry.
+    pub fn new<K>(key: K, ttl: usize, payload: Bytes) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            key: key.into(),
+            ttl,
+            payload,
+            replace: false,
+        }
+    }
+
+    /// Replaces an existing key. Otherwise, Redis responds with a BUSYKEY error in case the key exists.
+    pub fn replace(mut self) -> Self {
+        self.replace = true;
+        self
+    }
+}
+
+impl<F> Command<F> for RestoreCommand
+where
+    F: From<CommandBuilder> + ToStringOption,
+{
+    type Response = ConfirmationResponse;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("RESTORE")
+            .arg(&self.key)
+            .arg_uint(self.ttl)
+            .arg(&self.payload)
+            .arg_static_option(self.replace.then_some("REPLACE"))
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.expect_simple_string("OK")
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [DumpCommand]
+    pub fn dump<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, DumpCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: IsNullFrame + ToStringBytes,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(DumpCommand::new(key))
+    }
+
+    /// Shorthand for [RestoreCommand]
+    /// For replacing existing keys, use [RestoreCommand] directly instead
+    pub fn restore<K>(
+        &'a self,
+        key: K,
+        ttl: usize,
+        payload: Bytes,
+    ) -> Result<Future<'a, N, C, P, RestoreCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(RestoreCommand::new(key, ttl, payload))
+    }
+
+    /// Copies all keys matching the given glob-style pattern to the destination connection and returns the number
+    /// of restored keys. Blocks until the migration is completed.
+    ///
+    /// Keys are iterated by SCAN, serialized by DUMP and restored with their remaining TTL (PTTL) on the
+    /// destination. Existing keys on the destination are replaced. Keys expiring or being deleted during the
+    /// migration are skipped.
+    ///
+    /// As SCAN may return keys multiple times, the count may exceed the number of distinct keys. The migration is
+    /// aborted on the first error, in which case some keys may already be restored.
+    pub fn migrate_all<'b, N2: TcpClientStack, C2: Clock, P2: Protocol, M>(
+        &'a self,
+        destination: &'b Client<'b, N2, C2, P2>,
+        pattern: M,
+    ) -> Result<usize, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToScanResponse + IsNullFrame + ToStringBytes + ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        AuthCommand: Command<<P2 as Protocol>::FrameType>,
+        HelloCommand: Command<<P2 as Protocol>::FrameType>,
+        <P2 as Protocol>::FrameType: ToStringOption,
+        <P2 as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<M>,
+    {
+        let pattern: Bytes = pattern.into();
+        let mut cursor = 0;
+        let mut migrated = 0;
+
+        loop {
+            let page = self.send(ScanCommand::new(cursor).pattern::<Bytes>(pattern.clone()))?.wait()?;
+
+            for key in page.keys {
+                let payload = match self.dump::<Bytes>(key.clone())?.wait()? {
+                    None => continue,
+                    Some(payload) => payload,
+                };
+
+                let ttl = match self.pttl::<Bytes>(key.clone())?.wait()? {
+                    // Zero would restore the key without expiry
+                    TtlResponse::Duration(milliseconds) => milliseconds.max(1) as usize,
+                    TtlResponse::NoExpiry => 0,
+                    TtlResponse::KeyMissing => continue,
+                };
+
+                destination
+                    .send(RestoreCommand::new::<Bytes>(key, ttl, payload).replace())?
+                    .wait()?;
+                migrated += 1;
+            }
+
+            cursor = page.cursor;
+            if cursor == 0 {
+                return Ok(migrated);
+            }
+        }
+    }
+}
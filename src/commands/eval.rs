@@ -0,0 +1,384 @@
+//! Abstraction of EVAL command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/eval/>).
+//!
+//! As the response type depends on the script, the response frame is not evaluated by default.
+//! For simple response types, the command may be converted to a typed [CustomCommand].
+//!
+//! # Basic usage
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::eval::EvalCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!# use redis_protocol::resp2::types::Resp2Frame;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!
+//! let command = EvalCommand::new("return ARGV[1]").arg("Hello World!");
+//! let response = client.send(command).unwrap().wait().unwrap();
+//! assert_eq!("Hello World!", response.to_string().unwrap());
+//! ```
+//! # Typed responses
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::eval::EvalCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let command = EvalCommand::new("return redis.call('STRLEN', KEYS[1])")
+//!     .key("eval_key")
+//!     .to_command()
+//!     .expect_integer();
+//! assert_eq!(0, client.send(command).unwrap().wait().unwrap());
+//! ```
+//! # Bounded counter
+//! [Client](Client#method.incr_bounded) provides a helper for counters wrapping back to zero,
+//! e.g. for rotating indices of ring buffers.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! client.set("ring_index", "2").unwrap().wait().unwrap();
+//!
+//! assert_eq!(3, client.incr_bounded("ring_index", 3).unwrap());
+//! assert_eq!(0, client.incr_bounded("ring_index", 3).unwrap());
+//! ```
+//! # TTL-aware set
+//! [Client](Client#method.set_if_ttl_greater) provides a helper, which only overwrites the value if the new TTL
+//! is extending the existing one. Keys without TTL are never overwritten, missing keys are always written.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.send(embedded_redis::commands::builder::CommandBuilder::new("DEL").arg_static("ttl_key").to_command()).unwrap().wait().unwrap();
+//!#
+//! assert!(client.set_if_ttl_greater("ttl_key", "first", 10_000).unwrap());
+//! assert!(!client.set_if_ttl_greater("ttl_key", "second", 5_000).unwrap());
+//! assert!(client.set_if_ttl_greater("ttl_key", "third", 20_000).unwrap());
+//! ```
+//! # Capped lists
+//! [Client](Client#method.rpush_capped) provides helpers for bounded queues, which atomically push the value and
+//! trim the list to the given max. length by removing the oldest elements.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.send(embedded_redis::commands::builder::CommandBuilder::new("DEL").arg_static("capped_list").to_command()).unwrap().wait().unwrap();
+//!#
+//! assert_eq!(1, client.rpush_capped("capped_list", "a", 2).unwrap());
+//! assert_eq!(2, client.rpush_capped("capped_list", "b", 2).unwrap());
+//!
+//! // "a" is removed
+//! assert_eq!(2, client.rpush_capped("capped_list", "c", 2).unwrap());
+//! ```
+//! # Rename with fresh TTL
+//! RENAME preserves the TTL of the source key. [Client](Client#method.rename_with_ttl) provides a helper, which
+//! atomically renames the key and sets a new TTL instead.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::ttl::TtlResponse;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["rename_target".into()]).unwrap().wait().unwrap();
+//!#
+//! client.set("rename_source", "value").unwrap().wait().unwrap();
+//! assert!(client.rename_with_ttl("rename_source", "rename_target", 60_000).unwrap());
+//!
+//! let ttl = client.pttl("rename_target").unwrap().wait().unwrap();
+//! assert!(matches!(ttl, TtlResponse::Duration(value) if value > 50_000 && value <= 60_000));
+//!
+//! // Source is gone now
+//! assert!(!client.rename_with_ttl("rename_source", "rename_target", 60_000).unwrap());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::custom::CustomCommand;
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Increments the counter and wraps back to zero when exceeding the maximum (ARGV[1])
+static INCR_BOUNDED_SCRIPT: &str = "local value = redis.call('INCR', KEYS[1]) \
+     if value > tonumber(ARGV[1]) then redis.call('SET', KEYS[1], 0) return 0 end \
+     return value";
+
+/// Sets the value (ARGV[1]) with TTL in milliseconds (ARGV[2]), if the current TTL is shorter
+static SET_IF_TTL_GREATER_SCRIPT: &str = "local ttl = redis.call('PTTL', KEYS[1]) \
+     if ttl == -1 or (ttl >= 0 and ttl >= tonumber(ARGV[2])) then return 0 end \
+     redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2]) return 1";
+
+/// Appends the value (ARGV[1]) and removes the oldest elements from head exceeding the max. length (ARGV[2])
+static RPUSH_CAPPED_SCRIPT: &str = "local length = redis.call('RPUSH', KEYS[1], ARGV[1]) \
+     local max = tonumber(ARGV[2]) if length <= max then return length end \
+     redis.call('LTRIM', KEYS[1], length - max, -1) return max";
+
+/// Prepends the value (ARGV[1]) and removes the oldest elements from tail exceeding the max. length (ARGV[2])
+static LPUSH_CAPPED_SCRIPT: &str = "local length = redis.call('LPUSH', KEYS[1], ARGV[1]) \
+     local max = tonumber(ARGV[2]) if length <= max then return length end \
+     redis.call('LTRIM', KEYS[1], 0, max - 1 - length) return max";
+
+/// Renames the key (KEYS[1] to KEYS[2]) and sets the TTL in milliseconds (ARGV[1]). Returns 0 if the source is missing.
+static RENAME_WITH_TTL_SCRIPT: &str = "if redis.call('EXISTS', KEYS[1]) == 0 then return 0 end \
+     redis.call('RENAME', KEYS[1], KEYS[2]) redis.call('PEXPIRE', KEYS[2], ARGV[1]) return 1";
+
+/// Abstraction of EVAL command
+pub struct EvalCommand {
+    /// Lua script
+    script: Bytes,
+
+    /// Key names accessed by the script (KEYS)
+    keys: Vec<Bytes>,
+
+    /// Additional arguments (ARGV)
+    args: Vec<Bytes>,
+}
+
+impl EvalCommand {
+    pub fn new<S>(script: S) -> Self
+    where
+        Bytes: From<S>,
+    {
+        Self {
+            script: script.into(),
+            keys: Vec::new(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Adds a key name, accessible by KEYS table
+    pub fn key<K>(mut self, key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        self.keys.push(key.into());
+        self
+    }
+
+    /// Adds an argument, accessible by ARGV table
+    pub fn arg<A>(mut self, arg: A) -> Self
+    where
+        Bytes: From<A>,
+    {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Converts to [CustomCommand], which supports typed responses
+    pub fn to_command(self) -> CustomCommand {
+        self.get_builder().to_command()
+    }
+
+    /// General logic for building the command
+    fn get_builder(&self) -> CommandBuilder {
+        let mut builder = CommandBuilder::new("EVAL").arg(&self.script).arg_uint(self.keys.len());
+
+        for key in &self.keys {
+            builder = builder.arg(key);
+        }
+
+        for arg in &self.args {
+            builder = builder.arg(arg);
+        }
+
+        builder
+    }
+}
+
+impl<F> Command<F> for EvalCommand
+where
+    F: From<CommandBuilder>,
+{
+    /// Response of the script
+    type Response = F;
+
+    fn encode(&self) -> F {
+        self.get_builder().into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        Ok(frame)
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Atomically increments the counter stored at key. Wraps back to 0 when the new value would exceed `max`.
+    /// Blocks until the response is received and returns the new value.
+    pub fn incr_bounded<K>(&'a self, key: K, max: usize) -> Result<i64, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        let command = EvalCommand::new::<&'static str>(INCR_BOUNDED_SCRIPT)
+            .key(key)
+            .arg::<String>(max.to_string())
+            .to_command()
+            .expect_integer();
+
+        self.send(command)?.wait()
+    }
+
+    /// Atomically sets the value with the given TTL in milliseconds, but only if the new TTL is greater
+    /// than the current TTL of the key. Keys without TTL are never overwritten, missing keys are always set.
+    /// Blocks until the response is received and returns true if the value was written.
+    pub fn set_if_ttl_greater<K, V>(
+        &'a self,
+        key: K,
+        value: V,
+        milliseconds: usize,
+    ) -> Result<bool, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        let command = EvalCommand::new::<&'static str>(SET_IF_TTL_GREATER_SCRIPT)
+            .key(key)
+            .arg(value)
+            .arg::<String>(milliseconds.to_string())
+            .to_command()
+            .expect_integer();
+
+        match self.send(command)?.wait()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(CommandErrors::CommandResponseViolation),
+        }
+    }
+
+    /// Atomically appends the value to the list and trims the list to the given max. length by removing the
+    /// oldest elements from head. Blocks until the response is received and returns the new length of the list.
+    pub fn rpush_capped<K, V>(&'a self, key: K, value: V, max_length: usize) -> Result<i64, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        self.push_capped(RPUSH_CAPPED_SCRIPT, key.into(), value.into(), max_length)
+    }
+
+    /// Atomically prepends the value to the list and trims the list to the given max. length by removing the
+    /// oldest elements from tail. Blocks until the response is received and returns the new length of the list.
+    pub fn lpush_capped<K, V>(&'a self, key: K, value: V, max_length: usize) -> Result<i64, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        self.push_capped(LPUSH_CAPPED_SCRIPT, key.into(), value.into(), max_length)
+    }
+
+    /// Atomically renames the key and sets a fresh TTL in milliseconds on the new key.
+    /// Blocks until the response is received and returns false if the source key does not exist.
+    ///
+    /// *Plain [RENAME](Client::rename) already preserves the TTL of the source key, so this is only needed for
+    /// resetting it.*
+    pub fn rename_with_ttl<S, D>(
+        &'a self,
+        source: S,
+        destination: D,
+        milliseconds: usize,
+    ) -> Result<bool, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<S>,
+        Bytes: From<D>,
+    {
+        let command = EvalCommand::new::<&'static str>(RENAME_WITH_TTL_SCRIPT)
+            .key(source)
+            .key(destination)
+            .arg::<String>(milliseconds.to_string())
+            .to_command()
+            .expect_integer();
+
+        match self.send(command)?.wait()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(CommandErrors::CommandResponseViolation),
+        }
+    }
+
+    /// General logic of capped push helpers
+    fn push_capped(
+        &'a self,
+        script: &'static str,
+        key: Bytes,
+        value: Bytes,
+        max_length: usize,
+    ) -> Result<i64, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        let command = EvalCommand::new(script)
+            .key(key)
+            .arg(value)
+            .arg(max_length.to_string())
+            .to_command()
+            .expect_integer();
+
+        self.send(command)?.wait()
+    }
+}
@@ -0,0 +1,152 @@
+//! Abstraction of EXISTS command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/exists/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::exists::ExistsCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("exists_key", "example").unwrap().wait().unwrap();
+//!
+//! // Returns the number of existing keys
+//! let command = ExistsCommand::new(["exists_key".into(), "not_existing".into()]);
+//! assert_eq!(1, client.send(command).unwrap().wait().unwrap());
+//! ```
+//! # Boolean helpers
+//! [Client](Client#method.exists_all) provides blocking helpers checking if all respectively any of the keys exist.
+//!
+//! EXISTS counts keys, which are passed multiple times, multiple times. As the helpers compare the count against
+//! the total number of passed keys, duplicates do not falsify the result.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("exists_key", "example").unwrap().wait().unwrap();
+//!
+//! assert!(!client.exists_all(["exists_key".into(), "not_existing".into()]).unwrap());
+//! assert!(client.exists_any(["exists_key".into(), "not_existing".into()]).unwrap());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.exists) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let count = client.exists(["not_existing".into()]).unwrap().wait().unwrap();
+//! assert_eq!(0, count);
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of EXISTS command
+pub struct ExistsCommand<const N: usize> {
+    keys: [Bytes; N],
+}
+
+impl<const N: usize> ExistsCommand<N> {
+    pub fn new(keys: [Bytes; N]) -> Self {
+        Self { keys }
+    }
+}
+
+impl<F, const N: usize> Command<F> for ExistsCommand<N>
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Number of existing keys. Keys passed multiple times are counted multiple times.
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("EXISTS");
+
+        for key in &self.keys {
+            builder = builder.arg(key);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [ExistsCommand]
+    pub fn exists<const L: usize>(
+        &'a self,
+        keys: [Bytes; L],
+    ) -> Result<Future<'a, N, C, P, ExistsCommand<L>>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(ExistsCommand::new(keys))
+    }
+
+    /// Returns true if all the given keys exist. Blocks until the response is received.
+    /// Returns true without any request in case of empty key list.
+    pub fn exists_all<const L: usize>(&'a self, keys: [Bytes; L]) -> Result<bool, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        if L == 0 {
+            return Ok(true);
+        }
+
+        Ok(self.exists(keys)?.wait()? == L as i64)
+    }
+
+    /// Returns true if at least one of the given keys exists. Blocks until the response is received.
+    /// Returns false without any request in case of empty key list.
+    pub fn exists_any<const L: usize>(&'a self, keys: [Bytes; L]) -> Result<bool, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        if L == 0 {
+            return Ok(false);
+        }
+
+        Ok(self.exists(keys)?.wait()? > 0)
+    }
+}
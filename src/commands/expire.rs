@@ -0,0 +1,148 @@
+//! Abstraction of EXPIRE command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/expire/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::expire::ExpireCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("expire_key", "example").unwrap().wait().unwrap();
+//!
+//! // Key expires in 60 seconds
+//! let command = ExpireCommand::new("expire_key", 60);
+//! let response = client.send(command).unwrap().wait().unwrap();
+//!
+//! // Returns true if the timeout was set, false if key is not existing
+//! assert!(response);
+//! ```
+//! # Conditions (NX, XX, GT, LT)
+//! *Requires Redis version >= 7.0*
+//!
+//! Only set the timeout if the given condition is met. Fore more details s. [ExpireCond] enum.
+//! ```no_run
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::expire::ExpireCommand;
+//!# use embedded_redis::commands::pexpire::ExpireCond;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! // Only set a timeout if the key does not have one yet
+//! let command = ExpireCommand::new("expire_key", 60).condition(ExpireCond::IfNoExpiry);
+//! let _ = client.send(command);
+//! ```
+//! # Shorthand
+//! [Client](Client#method.expire) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let response = client.expire("not_existing", 60).unwrap().wait().unwrap();
+//! assert!(!response);
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBool};
+use crate::commands::hello::HelloCommand;
+use crate::commands::pexpire::ExpireCond;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction for EXPIRE command
+pub struct ExpireCommand {
+    key: Bytes,
+
+    /// Timeout in seconds
+    seconds: usize,
+
+    condition: ExpireCond,
+}
+
+impl ExpireCommand {
+    pub fn new<K>(key: K, seconds: usize) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            key: key.into(),
+            seconds,
+            condition: ExpireCond::None,
+        }
+    }
+
+    /// Only set the timeout if the given condition is met
+    pub fn condition(mut self, condition: ExpireCond) -> Self {
+        self.condition = condition;
+        self
+    }
+}
+
+impl<F> Command<F> for ExpireCommand
+where
+    F: From<CommandBuilder> + ToBool,
+{
+    /// True if the timeout was set, false if key does not exist or condition was not met
+    type Response = bool;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("EXPIRE")
+            .arg(&self.key)
+            .arg_uint(self.seconds)
+            .arg_static_option(self.condition.option())
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bool().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [ExpireCommand]
+    /// For using conditions, use [ExpireCommand] directly instead
+    pub fn expire<K>(
+        &'a self,
+        key: K,
+        seconds: usize,
+    ) -> Result<Future<'a, N, C, P, ExpireCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToBool,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(ExpireCommand::new(key, seconds))
+    }
+}
@@ -26,7 +26,8 @@
 //! assert_eq!("test_value", response.as_str().unwrap())
 //! ```
 //! # Missing key (NIL/NULL response)
-//! In case of missing key `None` is returned
+//! In case of missing key `None` is returned. Keys holding an empty string are not affected, as Redis
+//! distinguishes both cases: `Some` with empty content is returned instead.
 //! ```
 //!# use core::str::FromStr;
 //!# use core::net::SocketAddr;
@@ -74,6 +75,31 @@
 //! let response = client.send(command).unwrap().wait().unwrap().unwrap();
 //! let _response_bytes = response.to_bytes();
 //! ```
+//! # Generic access
+//! The [RawBytes] trait provides uniform access to the inner value of single value responses.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//! use embedded_redis::commands::RawBytes;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//!# let _ = client.set("test_key", "test_value").unwrap().wait();
+//!#
+//! fn cache_size<R: RawBytes>(value: &R) -> usize {
+//!     value.bytes().len()
+//! }
+//!
+//! let response = client.get("test_key").unwrap().wait().unwrap().unwrap();
+//! assert_eq!(10, cache_size(&response));
+//! ```
 //! # Shorthand
 //! [Client](Client#method.get) provides a shorthand method for this command.
 //! ```
@@ -98,7 +124,7 @@
 use crate::commands::auth::AuthCommand;
 use crate::commands::builder::{CommandBuilder, IsNullFrame, ToStringBytes};
 use crate::commands::hello::HelloCommand;
-use crate::commands::{Command, ResponseTypeError};
+use crate::commands::{Command, RawBytes, ResponseTypeError};
 use crate::network::client::{Client, CommandErrors};
 use crate::network::future::Future;
 use crate::network::protocol::Protocol;
@@ -179,6 +205,12 @@ impl GetResponse {
     }
 }
 
+impl RawBytes for GetResponse {
+    fn bytes(&self) -> &Bytes {
+        &self.inner
+    }
+}
+
 impl<F> Command<F> for GetCommand
 where
     F: From<CommandBuilder> + IsNullFrame + ToStringBytes,
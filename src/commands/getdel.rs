@@ -0,0 +1,104 @@
+//! Abstraction of GETDEL command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/getdel/>).
+//!
+//! # Basic usage
+//! Gets the value and deletes the key atomically, e.g. for consuming one-shot flags or tokens.
+//! In case of existing key [`Some(GetResponse)`](GetResponse) is returned, otherwise `None`.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::getdel::GetDelCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("getdel_key", "token").unwrap().wait().unwrap();
+//!
+//! let response = client.send(GetDelCommand::new("getdel_key")).unwrap().wait().unwrap();
+//! assert_eq!("token", response.unwrap().as_str().unwrap());
+//!
+//! // Key got deleted
+//! let response = client.send(GetDelCommand::new("getdel_key")).unwrap().wait().unwrap();
+//! assert!(response.is_none());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.getdel) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let response = client.getdel("missing_key").unwrap().wait().unwrap();
+//! assert!(response.is_none());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, IsNullFrame, ToStringBytes};
+use crate::commands::get::GetResponse;
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::future::Future;
+use crate::network::protocol::Protocol;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of GETDEL command
+pub struct GetDelCommand {
+    key: Bytes,
+}
+
+impl GetDelCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        GetDelCommand { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for GetDelCommand
+where
+    F: From<CommandBuilder> + IsNullFrame + ToStringBytes,
+{
+    type Response = Option<GetResponse>;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("GETDEL").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        GetResponse::from_frame(frame)
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [GetDelCommand]
+    pub fn getdel<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, GetDelCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringBytes,
+        <P as Protocol>::FrameType: IsNullFrame,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(GetDelCommand::new(key))
+    }
+}
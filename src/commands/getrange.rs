@@ -0,0 +1,167 @@
+//! Abstraction of GETRANGE command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/getrange/>).
+//!
+//! # Using command object
+//! Returns the substring between the given (zero-based, inclusive) offsets. Negative offsets count from the end
+//! of the string, e.g. `-1` is the last character.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::getrange::GetRangeCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("getrange_key", "firmware-1.2.3").unwrap().wait().unwrap();
+//!
+//! let response = client.send(GetRangeCommand::new("getrange_key", -5, -1)).unwrap().wait().unwrap();
+//! assert_eq!("1.2.3", response.as_str().unwrap());
+//! ```
+//! # Missing key
+//! In case of missing key or out of range offsets, an empty string is returned.
+//!
+//! # Shorthand
+//! [Client](Client#method.getrange) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let response = client.getrange("missing_key", 0, 3).unwrap().wait().unwrap();
+//! assert_eq!("", response.as_str().unwrap());
+//! ```
+//! # Reading the tail
+//! [Client](Client#method.read_tail) fetches just the last bytes of a large value, e.g. the most recent lines of an
+//! append-only log, without transferring the whole value.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.set("device_log", "boot\nconnected\n").unwrap().wait().unwrap();
+//!#
+//! let tail = client.read_tail("device_log", 10).unwrap();
+//! assert_eq!("connected\n", tail);
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger, ToStringBytes};
+use crate::commands::get::GetResponse;
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::future::Future;
+use crate::network::protocol::Protocol;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of GETRANGE command
+pub struct GetRangeCommand {
+    key: Bytes,
+
+    /// Start offset (inclusive). Negative values count from the end.
+    start: i64,
+
+    /// End offset (inclusive). Negative values count from the end.
+    end: i64,
+}
+
+impl GetRangeCommand {
+    pub fn new<K>(key: K, start: i64, end: i64) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            key: key.into(),
+            start,
+            end,
+        }
+    }
+}
+
+impl<F> Command<F> for GetRangeCommand
+where
+    F: From<CommandBuilder> + ToStringBytes,
+{
+    /// Substring, which is empty in case of missing key or out of range offsets
+    type Response = GetResponse;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("GETRANGE")
+            .arg(&self.key)
+            .arg_int(self.start)
+            .arg_int(self.end)
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        Ok(GetResponse::new(
+            frame.to_string_bytes().ok_or(ResponseTypeError {})?,
+        ))
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [GetRangeCommand]
+    pub fn getrange<K>(
+        &'a self,
+        key: K,
+        start: i64,
+        end: i64,
+    ) -> Result<Future<'a, N, C, P, GetRangeCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringBytes,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(GetRangeCommand::new(key, start, end))
+    }
+
+    /// Returns the last `bytes` bytes of the string value, or the whole value if it is shorter.
+    /// Blocks until both responses are received.
+    ///
+    /// The length is queried by STRLEN first, so the absolute range is fetched by GETRANGE. Thus, data appended
+    /// in the meantime is not included. Returns an empty value without GETRANGE request in case of missing key.
+    pub fn read_tail<K>(&'a self, key: K, bytes: usize) -> Result<Bytes, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger + ToStringBytes,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        let key: Bytes = key.into();
+        let length = self.strlen::<Bytes>(key.clone())?.wait()?;
+
+        let count = i64::try_from(bytes).unwrap_or(i64::MAX);
+        if length <= 0 || count == 0 {
+            return Ok(Bytes::new());
+        }
+
+        let start = length.saturating_sub(count).max(0);
+        Ok(self.getrange::<Bytes>(key, start, length - 1)?.wait()?.to_bytes())
+    }
+}
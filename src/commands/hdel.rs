@@ -0,0 +1,142 @@
+//! Abstraction of HDEL command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/hdel/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//! use embedded_redis::commands::hdel::HashDeleteCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.hset("my_hash", "color", "green").unwrap().wait().unwrap();
+//!
+//! let command = HashDeleteCommand::new("my_hash", "color");
+//! let response = client.send(command).unwrap().wait().unwrap();
+//!
+//! // Returns the number of removed fields
+//! assert_eq!(1, response)
+//! ```
+//! # Removing multiple fields at once
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::hdel::HashDeleteCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.hset("my_hash", "color", "green").unwrap().wait().unwrap();
+//!# client.hset("my_hash", "material", "stone").unwrap().wait().unwrap();
+//!#
+//! let command = HashDeleteCommand::multiple("my_hash".into(), ["color".into(), "material".into()]);
+//! let response = client.send(command).unwrap().wait().unwrap();
+//!
+//! assert_eq!(2, response)
+//! ```
+//! # Shorthand
+//! [Client](Client#method.hdel) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! let _ = client.hdel("hash", "field");
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of HDEL command
+pub struct HashDeleteCommand<const N: usize> {
+    /// Hash key
+    key: Bytes,
+
+    /// Fields to remove
+    fields: [Bytes; N],
+}
+
+impl HashDeleteCommand<1> {
+    pub fn new<K, F>(key: K, field: F) -> Self
+    where
+        Bytes: From<K>,
+        Bytes: From<F>,
+    {
+        Self {
+            key: key.into(),
+            fields: [field.into()],
+        }
+    }
+}
+
+impl<const N: usize> HashDeleteCommand<N> {
+    /// Constructs a new command removing multiple fields
+    pub fn multiple(key: Bytes, fields: [Bytes; N]) -> Self {
+        Self { key, fields }
+    }
+}
+
+impl<F: From<CommandBuilder> + ToInteger, const N: usize> Command<F> for HashDeleteCommand<N> {
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("HDEL").arg(&self.key);
+
+        for field in &self.fields {
+            builder = builder.arg(field);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [HashDeleteCommand]
+    /// For removing multiple fields, use [HashDeleteCommand] directly instead
+    pub fn hdel<K, F>(
+        &'a self,
+        key: K,
+        field: F,
+    ) -> Result<Future<'a, N, C, P, HashDeleteCommand<1>>, CommandErrors>
+    where
+        Bytes: From<K>,
+        Bytes: From<F>,
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(HashDeleteCommand::new(key, field))
+    }
+}
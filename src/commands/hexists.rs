@@ -0,0 +1,107 @@
+//! Abstraction of HEXISTS command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/hexists/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//! use embedded_redis::commands::hexists::HashExistsCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.hset("my_hash", "color", "green").unwrap().wait().unwrap();
+//!
+//! let command = HashExistsCommand::new("my_hash", "color");
+//! assert!(client.send(command).unwrap().wait().unwrap());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.hexists) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! let exists = client.hexists("my_hash", "not_existing").unwrap().wait().unwrap();
+//! assert!(!exists);
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBool};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of HEXISTS command
+pub struct HashExistsCommand {
+    /// Hash key
+    key: Bytes,
+
+    field: Bytes,
+}
+
+impl HashExistsCommand {
+    pub fn new<K, F>(key: K, field: F) -> Self
+    where
+        Bytes: From<K>,
+        Bytes: From<F>,
+    {
+        Self {
+            key: key.into(),
+            field: field.into(),
+        }
+    }
+}
+
+impl<F> Command<F> for HashExistsCommand
+where
+    F: From<CommandBuilder> + ToBool,
+{
+    /// True if the field exists. False if the field or the hash does not exist.
+    type Response = bool;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("HEXISTS").arg(&self.key).arg(&self.field).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bool().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [HashExistsCommand]
+    pub fn hexists<K, F>(
+        &'a self,
+        key: K,
+        field: F,
+    ) -> Result<Future<'a, N, C, P, HashExistsCommand>, CommandErrors>
+    where
+        Bytes: From<K>,
+        Bytes: From<F>,
+        <P as Protocol>::FrameType: ToBool,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(HashExistsCommand::new(key, field))
+    }
+}
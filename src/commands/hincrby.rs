@@ -0,0 +1,117 @@
+//! Abstraction of HINCRBY command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/hincrby/>).
+//!
+//! # Using command object
+//! Missing hashes and fields are initialized with zero before the increment is applied.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//! use embedded_redis::commands::hincrby::HashIncrByCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.hset("hincrby_hash", "counter", "10").unwrap().wait().unwrap();
+//!
+//! // Returns the value after the increment
+//! let value = client.send(HashIncrByCommand::new("hincrby_hash", "counter", -3)).unwrap().wait().unwrap();
+//! assert_eq!(7, value);
+//! ```
+//! # Shorthand
+//! [Client](Client#method.hincrby) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let _ = client.hincrby("hincrby_hash", "counter", 5);
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of HINCRBY command
+pub struct HashIncrByCommand {
+    /// Hash key
+    key: Bytes,
+
+    field: Bytes,
+    increment: i64,
+}
+
+impl HashIncrByCommand {
+    /// Negative increments decrement the value
+    pub fn new<K, F>(key: K, field: F, increment: i64) -> Self
+    where
+        Bytes: From<K>,
+        Bytes: From<F>,
+    {
+        Self {
+            key: key.into(),
+            field: field.into(),
+            increment,
+        }
+    }
+}
+
+impl<F> Command<F> for HashIncrByCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Value of the field after the increment
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("HINCRBY")
+            .arg(&self.key)
+            .arg(&self.field)
+            .arg_int(self.increment)
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [HashIncrByCommand]
+    pub fn hincrby<K, F>(
+        &'a self,
+        key: K,
+        field: F,
+        increment: i64,
+    ) -> Result<Future<'a, N, C, P, HashIncrByCommand>, CommandErrors>
+    where
+        Bytes: From<K>,
+        Bytes: From<F>,
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(HashIncrByCommand::new(key, field, increment))
+    }
+}
@@ -0,0 +1,147 @@
+//! Abstraction of HKEYS and HVALS commands.
+//!
+//! For general information about these commands, see the Redis documentation of
+//! [HKEYS](<https://redis.io/commands/hkeys/>) and [HVALS](<https://redis.io/commands/hvals/>).
+//!
+//! # Using command objects
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//! use embedded_redis::commands::hkeys::{HashKeysCommand, HashValsCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.hset("hkeys_hash", "color", "green").unwrap().wait().unwrap();
+//!
+//! let fields = client.send(HashKeysCommand::new("hkeys_hash")).unwrap().wait().unwrap();
+//! assert_eq!(vec!["color"], fields);
+//!
+//! let values = client.send(HashValsCommand::new("hkeys_hash")).unwrap().wait().unwrap();
+//! assert_eq!(vec!["green"], values);
+//! ```
+//! # Missing hash
+//! An empty list is returned in case the hash does not exist.
+//!
+//! # Shorthand
+//! [Client](Client#method.hkeys) provides shorthand methods for both commands.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let fields = client.hkeys("not_existing").unwrap().wait().unwrap();
+//! let values = client.hvals("not_existing").unwrap().wait().unwrap();
+//! assert!(fields.is_empty() && values.is_empty());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBytesVec};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use alloc::vec::Vec;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of HKEYS command
+pub struct HashKeysCommand {
+    /// Hash key
+    key: Bytes,
+}
+
+impl HashKeysCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for HashKeysCommand
+where
+    F: From<CommandBuilder> + ToBytesVec,
+{
+    /// Field names of the hash
+    type Response = Vec<Bytes>;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("HKEYS").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bytes_vec().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Abstraction of HVALS command
+pub struct HashValsCommand {
+    /// Hash key
+    key: Bytes,
+}
+
+impl HashValsCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for HashValsCommand
+where
+    F: From<CommandBuilder> + ToBytesVec,
+{
+    /// Values of the hash
+    type Response = Vec<Bytes>;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("HVALS").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bytes_vec().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [HashKeysCommand]
+    pub fn hkeys<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, HashKeysCommand>, CommandErrors>
+    where
+        Bytes: From<K>,
+        <P as Protocol>::FrameType: ToBytesVec,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(HashKeysCommand::new(key))
+    }
+
+    /// Shorthand for [HashValsCommand]
+    pub fn hvals<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, HashValsCommand>, CommandErrors>
+    where
+        Bytes: From<K>,
+        <P as Protocol>::FrameType: ToBytesVec,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(HashValsCommand::new(key))
+    }
+}
@@ -0,0 +1,99 @@
+//! Abstraction of HLEN command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/hlen/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::hlen::HashLenCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.hset("hlen_hash", "color", "green").unwrap().wait().unwrap();
+//!
+//! let count = client.send(HashLenCommand::new("hlen_hash")).unwrap().wait().unwrap();
+//! assert_eq!(1, count);
+//! ```
+//! # Missing hash
+//! Zero is returned in case the hash does not exist.
+//!
+//! # Shorthand
+//! [Client](Client#method.hlen) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! assert_eq!(0, client.hlen("missing_key").unwrap().wait().unwrap());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of HLEN command
+pub struct HashLenCommand {
+    /// Hash key
+    key: Bytes,
+}
+
+impl HashLenCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for HashLenCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Number of fields in the hash. Zero in case of missing key.
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("HLEN").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [HashLenCommand]
+    pub fn hlen<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, HashLenCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(HashLenCommand::new(key))
+    }
+}
@@ -0,0 +1,133 @@
+//! Abstraction of HMGET command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/hmget/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::hmget::HashMultiGetCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.hset("my_hash", "color", "green").unwrap().wait().unwrap();
+//! client.hset("my_hash", "material", "stone").unwrap().wait().unwrap();
+//!
+//! // Values are returned in order of the fields. None in case of missing field.
+//! let command = HashMultiGetCommand::new("my_hash", ["color".into(), "size".into(), "material".into()]);
+//! let response = client.send(command).unwrap().wait().unwrap();
+//!
+//! assert_eq!("green", response[0].as_ref().unwrap().as_str().unwrap());
+//! assert!(response[1].is_none());
+//! assert_eq!("stone", response[2].as_ref().unwrap().as_str().unwrap());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.hmget) provides a shorthand method for reading two fields.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.hset("my_hash", "color", "green").unwrap().wait().unwrap();
+//!#
+//! let response = client.hmget("my_hash", "color", "size").unwrap().wait().unwrap();
+//! assert_eq!("green", response[0].as_ref().unwrap().as_str().unwrap());
+//! assert!(response[1].is_none());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToNullableBytesVec};
+use crate::commands::get::GetResponse;
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use alloc::vec::Vec;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of HMGET command
+pub struct HashMultiGetCommand<const N: usize> {
+    /// Hash key
+    key: Bytes,
+
+    /// Fields to read
+    fields: [Bytes; N],
+}
+
+impl<const N: usize> HashMultiGetCommand<N> {
+    pub fn new<K>(key: K, fields: [Bytes; N]) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            key: key.into(),
+            fields,
+        }
+    }
+}
+
+impl<F, const N: usize> Command<F> for HashMultiGetCommand<N>
+where
+    F: From<CommandBuilder> + ToNullableBytesVec,
+{
+    /// Values in order of the given fields. None in case of missing field or key.
+    type Response = Vec<Option<GetResponse>>;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("HMGET").arg(&self.key);
+
+        for field in &self.fields {
+            builder = builder.arg(field);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        let values = frame.to_nullable_bytes_vec().ok_or(ResponseTypeError {})?;
+
+        if values.len() != N {
+            return Err(ResponseTypeError {});
+        }
+
+        Ok(values.into_iter().map(|value| value.map(GetResponse::new)).collect())
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [HashMultiGetCommand] reading two fields
+    /// For reading any other number of fields, use [HashMultiGetCommand] directly instead
+    pub fn hmget<K, F1, F2>(
+        &'a self,
+        key: K,
+        first: F1,
+        second: F2,
+    ) -> Result<Future<'a, N, C, P, HashMultiGetCommand<2>>, CommandErrors>
+    where
+        Bytes: From<K>,
+        Bytes: From<F1>,
+        Bytes: From<F2>,
+        <P as Protocol>::FrameType: ToNullableBytesVec,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(HashMultiGetCommand::new(key, [first.into(), second.into()]))
+    }
+}
@@ -0,0 +1,121 @@
+//! Abstraction of HSETNX command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/hsetnx/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//! use embedded_redis::commands::hsetnx::HashSetNxCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.hdel("my_hash", "color").unwrap().wait().unwrap();
+//!
+//! // Field is set, as it does not exist yet
+//! let command = HashSetNxCommand::new("my_hash", "color", "green");
+//! assert!(client.send(command).unwrap().wait().unwrap());
+//!
+//! // Existing field is left untouched
+//! let command = HashSetNxCommand::new("my_hash", "color", "blue");
+//! assert!(!client.send(command).unwrap().wait().unwrap());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.hsetnx) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! let _ = client.hsetnx("my_hash", "color", "green");
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBool};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of HSETNX command
+pub struct HashSetNxCommand {
+    /// Hash key
+    key: Bytes,
+
+    field: Bytes,
+
+    value: Bytes,
+}
+
+impl HashSetNxCommand {
+    pub fn new<K, F, V>(key: K, field: F, value: V) -> Self
+    where
+        Bytes: From<K>,
+        Bytes: From<F>,
+        Bytes: From<V>,
+    {
+        Self {
+            key: key.into(),
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl<F> Command<F> for HashSetNxCommand
+where
+    F: From<CommandBuilder> + ToBool,
+{
+    /// True if the field has been set. False if the field already exists and no operation was performed.
+    type Response = bool;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("HSETNX")
+            .arg(&self.key)
+            .arg(&self.field)
+            .arg(&self.value)
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bool().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [HashSetNxCommand]
+    pub fn hsetnx<K, F, V>(
+        &'a self,
+        key: K,
+        field: F,
+        value: V,
+    ) -> Result<Future<'a, N, C, P, HashSetNxCommand>, CommandErrors>
+    where
+        Bytes: From<K>,
+        Bytes: From<F>,
+        Bytes: From<V>,
+        <P as Protocol>::FrameType: ToBool,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(HashSetNxCommand::new(key, field, value))
+    }
+}
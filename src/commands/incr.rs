@@ -0,0 +1,165 @@
+//! Abstraction of INCR and DECR commands.
+//!
+//! For general information about these commands, see the [Redis documentation](<https://redis.io/commands/incr/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::incr::{DecrCommand, IncrCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("incr_key", "10").unwrap().wait().unwrap();
+//!
+//! // Returns the value after the increment
+//! let response = client.send(IncrCommand::new("incr_key")).unwrap().wait().unwrap();
+//! assert_eq!(11, response);
+//!
+//! // Returns the value after the decrement
+//! let response = client.send(DecrCommand::new("incr_key")).unwrap().wait().unwrap();
+//! assert_eq!(10, response);
+//! ```
+//! Missing keys are treated as zero before the operation.
+//!
+//! # Non-integer values
+//! In case the stored value is not a string representing a 64-bit integer, Redis replies with an error.
+//! This results in [ErrorResponse](crate::network::CommandErrors::ErrorResponse).
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::{CommandErrors, ConnectionHandler};
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! client.set("text_key", "text").unwrap().wait().unwrap();
+//!
+//! let error = client.incr("text_key").unwrap().wait().unwrap_err();
+//! assert!(matches!(error, CommandErrors::ErrorResponse(_)));
+//! ```
+//! # Shorthand
+//! [Client](Client#method.incr) and [Client](Client#method.decr) provide shorthand methods.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let _ = client.incr("counter");
+//! let _ = client.decr("counter");
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::future::Future;
+use crate::network::protocol::Protocol;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction for INCR command
+pub struct IncrCommand {
+    key: Bytes,
+}
+
+impl IncrCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        IncrCommand { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for IncrCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Value after the increment
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("INCR").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Abstraction for DECR command
+pub struct DecrCommand {
+    key: Bytes,
+}
+
+impl DecrCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        DecrCommand { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for DecrCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Value after the decrement
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("DECR").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [IncrCommand]
+    pub fn incr<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, IncrCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(IncrCommand::new(key))
+    }
+
+    /// Shorthand for [DecrCommand]
+    pub fn decr<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, DecrCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(DecrCommand::new(key))
+    }
+}
@@ -0,0 +1,166 @@
+//! Abstraction of INCRBY and DECRBY commands.
+//!
+//! For general information about these commands, see the [Redis documentation](<https://redis.io/commands/incrby/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::incrby::{DecrByCommand, IncrByCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("incrby_key", "10").unwrap().wait().unwrap();
+//!
+//! // Returns the value after the increment
+//! let response = client.send(IncrByCommand::new("incrby_key", 5)).unwrap().wait().unwrap();
+//! assert_eq!(15, response);
+//!
+//! // Negative amounts are supported
+//! let response = client.send(IncrByCommand::new("incrby_key", -20)).unwrap().wait().unwrap();
+//! assert_eq!(-5, response);
+//!
+//! let response = client.send(DecrByCommand::new("incrby_key", 5)).unwrap().wait().unwrap();
+//! assert_eq!(-10, response);
+//! ```
+//! Missing keys are treated as zero before the operation. Non-integer values result in
+//! [ErrorResponse](crate::network::CommandErrors::ErrorResponse).
+//!
+//! # Shorthand
+//! [Client](Client#method.incrby) and [Client](Client#method.decrby) provide shorthand methods.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let _ = client.incrby("counter", -5);
+//! let _ = client.decrby("counter", 3);
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::future::Future;
+use crate::network::protocol::Protocol;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction for INCRBY command
+pub struct IncrByCommand {
+    key: Bytes,
+    increment: i64,
+}
+
+impl IncrByCommand {
+    /// Negative increments decrement the value
+    pub fn new<K>(key: K, increment: i64) -> Self
+    where
+        Bytes: From<K>,
+    {
+        IncrByCommand {
+            key: key.into(),
+            increment,
+        }
+    }
+}
+
+impl<F> Command<F> for IncrByCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Value after the increment
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("INCRBY").arg(&self.key).arg_int(self.increment).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Abstraction for DECRBY command
+pub struct DecrByCommand {
+    key: Bytes,
+    decrement: i64,
+}
+
+impl DecrByCommand {
+    /// Negative decrements increment the value
+    pub fn new<K>(key: K, decrement: i64) -> Self
+    where
+        Bytes: From<K>,
+    {
+        DecrByCommand {
+            key: key.into(),
+            decrement,
+        }
+    }
+}
+
+impl<F> Command<F> for DecrByCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Value after the decrement
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("DECRBY").arg(&self.key).arg_int(self.decrement).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [IncrByCommand]
+    pub fn incrby<K>(
+        &'a self,
+        key: K,
+        increment: i64,
+    ) -> Result<Future<'a, N, C, P, IncrByCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(IncrByCommand::new(key, increment))
+    }
+
+    /// Shorthand for [DecrByCommand]
+    pub fn decrby<K>(
+        &'a self,
+        key: K,
+        decrement: i64,
+    ) -> Result<Future<'a, N, C, P, DecrByCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(DecrByCommand::new(key, decrement))
+    }
+}
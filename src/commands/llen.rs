@@ -0,0 +1,100 @@
+//! Abstraction of LLEN command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/llen/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::llen::ListLenCommand;
+//!# use embedded_redis::commands::lpush::{ListPushCommand, Side};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["llen_list".into()]).unwrap().wait().unwrap();
+//! client.send(ListPushCommand::new(Side::Right, "llen_list", ["a".into(), "b".into()])).unwrap().wait().unwrap();
+//!
+//! let length = client.send(ListLenCommand::new("llen_list")).unwrap().wait().unwrap();
+//! assert_eq!(2, length);
+//! ```
+//! # Missing key
+//! Zero is returned in case of missing key.
+//!
+//! # Shorthand
+//! [Client](Client#method.llen) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! assert_eq!(0, client.llen("missing_list").unwrap().wait().unwrap());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of LLEN command
+pub struct ListLenCommand {
+    key: Bytes,
+}
+
+impl ListLenCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for ListLenCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Length of the list. Zero in case of missing key.
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("LLEN").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [ListLenCommand]
+    pub fn llen<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, ListLenCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(ListLenCommand::new(key))
+    }
+}
@@ -0,0 +1,222 @@
+//! Abstraction of LPOP and RPOP commands.
+//!
+//! For general information about these commands, see the Redis documentation of
+//! [LPOP](<https://redis.io/commands/lpop/>) and [RPOP](<https://redis.io/commands/rpop/>).
+//!
+//! # Popping a single element
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//! use embedded_redis::commands::lpop::ListPopCommand;
+//! use embedded_redis::commands::lpush::Side;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["pop_list".into()]).unwrap().wait().unwrap();
+//! client.rpush("pop_list", "first").unwrap().wait().unwrap();
+//!
+//! let command = ListPopCommand::new(Side::Left, "pop_list");
+//! let response = client.send(command).unwrap().wait().unwrap().unwrap();
+//! assert_eq!("first", response.as_str().unwrap());
+//!
+//! // None in case of empty list
+//! let command = ListPopCommand::new(Side::Left, "pop_list");
+//! assert!(client.send(command).unwrap().wait().unwrap().is_none());
+//! ```
+//! # Popping multiple elements
+//! The COUNT argument requires Redis version >= 6.2.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::lpop::ListPopCommand;
+//!# use embedded_redis::commands::lpush::{ListPushCommand, Side};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["pop_list".into()]).unwrap().wait().unwrap();
+//!# client.send(ListPushCommand::new(Side::Right, "pop_list", ["a".into(), "b".into(), "c".into()])).unwrap().wait().unwrap();
+//!#
+//! let command = ListPopCommand::new(Side::Right, "pop_list").count(2);
+//! let response = client.send(command).unwrap().wait().unwrap();
+//!
+//! assert_eq!(2, response.len());
+//! assert_eq!("c", response[0]);
+//! assert_eq!("b", response[1]);
+//! ```
+//! # Shorthand
+//! [Client](Client#method.lpop) provides shorthand methods for popping a single element.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! let _ = client.lpop("my_list");
+//! let _ = client.rpop("my_list");
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, IsNullFrame, ToBytesVec, ToStringBytes};
+use crate::commands::get::GetResponse;
+use crate::commands::hello::HelloCommand;
+use crate::commands::lpush::Side;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use alloc::vec::Vec;
+use bytes::Bytes;
+use core::marker::PhantomData;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of LPOP/RPOP command
+pub struct ListPopCommand<R> {
+    side: Side,
+
+    /// List key
+    key: Bytes,
+
+    /// COUNT argument
+    count: Option<usize>,
+
+    response_type: PhantomData<R>,
+}
+
+/// Popped element. None in case of empty list.
+pub type SinglePopResponse = Option<GetResponse>;
+
+/// Popped elements in order of removal. Empty in case of empty list.
+pub type MultiPopResponse = Vec<Bytes>;
+
+impl ListPopCommand<SinglePopResponse> {
+    pub fn new<K>(side: Side, key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            side,
+            key: key.into(),
+            count: None,
+            response_type: PhantomData,
+        }
+    }
+
+    /// Pops up to the given number of elements
+    /// *Requires Redis version >= 6.2*
+    pub fn count(self, count: usize) -> ListPopCommand<MultiPopResponse> {
+        ListPopCommand {
+            side: self.side,
+            key: self.key,
+            count: Some(count),
+            response_type: PhantomData,
+        }
+    }
+}
+
+impl<R> ListPopCommand<R> {
+    /// General logic for building the command
+    fn get_builder(&self) -> CommandBuilder {
+        let keyword = match self.side {
+            Side::Left => "LPOP",
+            Side::Right => "RPOP",
+        };
+
+        let mut builder = CommandBuilder::new(keyword).arg(&self.key);
+        if let Some(count) = self.count {
+            builder = builder.arg_uint(count);
+        }
+
+        builder
+    }
+}
+
+impl<F> Command<F> for ListPopCommand<SinglePopResponse>
+where
+    F: From<CommandBuilder> + IsNullFrame + ToStringBytes,
+{
+    type Response = SinglePopResponse;
+
+    fn encode(&self) -> F {
+        self.get_builder().into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        if frame.is_null_frame() {
+            return Ok(None);
+        }
+
+        Ok(Some(GetResponse::new(
+            frame.to_string_bytes().ok_or(ResponseTypeError {})?,
+        )))
+    }
+}
+
+impl<F> Command<F> for ListPopCommand<MultiPopResponse>
+where
+    F: From<CommandBuilder> + IsNullFrame + ToBytesVec,
+{
+    type Response = MultiPopResponse;
+
+    fn encode(&self) -> F {
+        self.get_builder().into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        if frame.is_null_frame() {
+            return Ok(Vec::new());
+        }
+
+        frame.to_bytes_vec().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [ListPopCommand] removing the first element
+    /// For popping multiple elements, use [ListPopCommand] directly instead
+    pub fn lpop<K>(
+        &'a self,
+        key: K,
+    ) -> Result<Future<'a, N, C, P, ListPopCommand<SinglePopResponse>>, CommandErrors>
+    where
+        Bytes: From<K>,
+        <P as Protocol>::FrameType: IsNullFrame + ToStringBytes,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(ListPopCommand::new(Side::Left, key))
+    }
+
+    /// Shorthand for [ListPopCommand] removing the last element
+    /// For popping multiple elements, use [ListPopCommand] directly instead
+    pub fn rpop<K>(
+        &'a self,
+        key: K,
+    ) -> Result<Future<'a, N, C, P, ListPopCommand<SinglePopResponse>>, CommandErrors>
+    where
+        Bytes: From<K>,
+        <P as Protocol>::FrameType: IsNullFrame + ToStringBytes,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(ListPopCommand::new(Side::Right, key))
+    }
+}
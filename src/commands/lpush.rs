@@ -0,0 +1,154 @@
+//! Abstraction of LPUSH and RPUSH commands.
+//!
+//! For general information about these commands, see the Redis documentation of
+//! [LPUSH](<https://redis.io/commands/lpush/>) and [RPUSH](<https://redis.io/commands/rpush/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//! use embedded_redis::commands::lpush::{ListPushCommand, Side};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["my_list".into()]).unwrap().wait().unwrap();
+//!
+//! // Values are appended one after another, so the list is [first, second]
+//! let command = ListPushCommand::new(Side::Right, "my_list", ["first".into(), "second".into()]);
+//! let length = client.send(command).unwrap().wait().unwrap();
+//!
+//! // Returns the length of the list after the operation
+//! assert_eq!(2, length);
+//! ```
+//! # Shorthand
+//! [Client](Client#method.lpush) provides shorthand methods for pushing a single value.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! let _ = client.lpush("my_list", "head");
+//! let _ = client.rpush("my_list", "tail");
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// End of the list the values are pushed to
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Side {
+    /// LPUSH: Values are inserted at the head
+    Left,
+    /// RPUSH: Values are appended at the tail
+    Right,
+}
+
+impl Side {
+    /// Returns the command keyword
+    fn command(&self) -> &'static str {
+        match self {
+            Side::Left => "LPUSH",
+            Side::Right => "RPUSH",
+        }
+    }
+}
+
+/// Abstraction of LPUSH/RPUSH command
+pub struct ListPushCommand<const N: usize> {
+    side: Side,
+
+    /// List key
+    key: Bytes,
+
+    /// Values to push, in order of insertion
+    values: [Bytes; N],
+}
+
+impl<const N: usize> ListPushCommand<N> {
+    pub fn new<K>(side: Side, key: K, values: [Bytes; N]) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            side,
+            key: key.into(),
+            values,
+        }
+    }
+}
+
+impl<F: From<CommandBuilder> + ToInteger, const N: usize> Command<F> for ListPushCommand<N> {
+    /// Length of the list after the push operation
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new(self.side.command()).arg(&self.key);
+
+        for value in &self.values {
+            builder = builder.arg(value);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [ListPushCommand] inserting a single value at the head
+    /// For pushing multiple values, use [ListPushCommand] directly instead
+    pub fn lpush<K, V>(
+        &'a self,
+        key: K,
+        value: V,
+    ) -> Result<Future<'a, N, C, P, ListPushCommand<1>>, CommandErrors>
+    where
+        Bytes: From<K>,
+        Bytes: From<V>,
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(ListPushCommand::new(Side::Left, key, [value.into()]))
+    }
+
+    /// Shorthand for [ListPushCommand] appending a single value at the tail
+    /// For pushing multiple values, use [ListPushCommand] directly instead
+    pub fn rpush<K, V>(
+        &'a self,
+        key: K,
+        value: V,
+    ) -> Result<Future<'a, N, C, P, ListPushCommand<1>>, CommandErrors>
+    where
+        Bytes: From<K>,
+        Bytes: From<V>,
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(ListPushCommand::new(Side::Right, key, [value.into()]))
+    }
+}
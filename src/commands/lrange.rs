@@ -0,0 +1,127 @@
+//! Abstraction of LRANGE command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/lrange/>).
+//!
+//! # Using command object
+//! Returns the elements between the given (zero-based, inclusive) indices. Negative indices count from the end
+//! of the list, e.g. `-1` is the last element.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::lpush::{ListPushCommand, Side};
+//!# use embedded_redis::commands::lrange::ListRangeCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["lrange_list".into()]).unwrap().wait().unwrap();
+//!# client.send(ListPushCommand::new(Side::Right, "lrange_list", ["a".into(), "b".into(), "c".into()])).unwrap().wait().unwrap();
+//!
+//! let response = client.send(ListRangeCommand::new("lrange_list", -2, -1)).unwrap().wait().unwrap();
+//! assert_eq!(2, response.len());
+//! assert_eq!("b", response[0]);
+//! assert_eq!("c", response[1]);
+//! ```
+//! # Missing key
+//! In case of missing key or out of range indices, an empty list is returned.
+//!
+//! # Shorthand
+//! [Client](Client#method.lrange) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! // Reads the whole list
+//! let response = client.lrange("missing_list", 0, -1).unwrap().wait().unwrap();
+//! assert!(response.is_empty());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBytesVec};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::future::Future;
+use crate::network::protocol::Protocol;
+use alloc::vec::Vec;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of LRANGE command
+pub struct ListRangeCommand {
+    key: Bytes,
+
+    /// Start index (inclusive). Negative values count from the end.
+    start: i64,
+
+    /// Stop index (inclusive). Negative values count from the end.
+    stop: i64,
+}
+
+impl ListRangeCommand {
+    pub fn new<K>(key: K, start: i64, stop: i64) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            key: key.into(),
+            start,
+            stop,
+        }
+    }
+}
+
+impl<F> Command<F> for ListRangeCommand
+where
+    F: From<CommandBuilder> + ToBytesVec,
+{
+    /// Elements of the range, which is empty in case of missing key or out of range indices
+    type Response = Vec<Bytes>;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("LRANGE")
+            .arg(&self.key)
+            .arg_int(self.start)
+            .arg_int(self.stop)
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bytes_vec().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [ListRangeCommand]
+    pub fn lrange<K>(
+        &'a self,
+        key: K,
+        start: i64,
+        stop: i64,
+    ) -> Result<Future<'a, N, C, P, ListRangeCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToBytesVec,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(ListRangeCommand::new(key, start, stop))
+    }
+}
@@ -0,0 +1,113 @@
+//! Abstraction of MGET command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/mget/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::mget::MGetCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("mget_key", "example").unwrap().wait().unwrap();
+//!
+//! // Values are returned in order of the keys. None in case of missing key.
+//! let command = MGetCommand::new(["mget_key".into(), "not_existing".into()]);
+//! let response = client.send(command).unwrap().wait().unwrap();
+//!
+//! assert_eq!("example", response[0].as_ref().unwrap().as_str().unwrap());
+//! assert!(response[1].is_none());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.mget) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let response = client.mget(["not_existing".into()]).unwrap().wait().unwrap();
+//! assert!(response[0].is_none());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToNullableBytesVec};
+use crate::commands::get::GetResponse;
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use alloc::vec::Vec;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of MGET command
+pub struct MGetCommand<const N: usize> {
+    keys: [Bytes; N],
+}
+
+impl<const N: usize> MGetCommand<N> {
+    pub fn new(keys: [Bytes; N]) -> Self {
+        Self { keys }
+    }
+}
+
+impl<F, const N: usize> Command<F> for MGetCommand<N>
+where
+    F: From<CommandBuilder> + ToNullableBytesVec,
+{
+    /// Values in order of the given keys. None in case of missing key or non-string value.
+    type Response = Vec<Option<GetResponse>>;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("MGET");
+
+        for key in &self.keys {
+            builder = builder.arg(key);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        let values = frame.to_nullable_bytes_vec().ok_or(ResponseTypeError {})?;
+
+        if values.len() != N {
+            return Err(ResponseTypeError {});
+        }
+
+        Ok(values.into_iter().map(|value| value.map(GetResponse::new)).collect())
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [MGetCommand]
+    pub fn mget<const L: usize>(
+        &'a self,
+        keys: [Bytes; L],
+    ) -> Result<Future<'a, N, C, P, MGetCommand<L>>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToNullableBytesVec,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(MGetCommand::new(keys))
+    }
+}
@@ -1,23 +1,87 @@
+pub mod append;
 pub mod auth;
 pub mod bgsave;
+pub mod bitfield;
 pub mod builder;
+pub mod client_flags;
+pub mod client_list;
 pub mod custom;
+pub mod del;
+pub mod dump;
+pub mod eval;
+pub mod exists;
+pub mod expire;
 pub mod get;
+pub mod getdel;
+pub mod getrange;
+pub mod hdel;
 pub mod hello;
 pub mod helpers;
+pub mod hexists;
 pub mod hget;
 pub mod hgetall;
+pub mod hincrby;
+pub mod hkeys;
+pub mod hlen;
+pub mod hmget;
 pub mod hset;
+pub mod hsetnx;
+pub mod incr;
+pub mod incrby;
+pub mod llen;
+pub mod lpop;
+pub mod lpush;
+pub mod lrange;
+pub mod mget;
+pub mod mset;
+pub mod multi_reply;
+pub mod persist;
+pub mod pexpire;
 pub mod ping;
 pub mod publish;
+pub mod punsubscribe;
+pub mod rename;
+pub mod scan;
 pub mod set;
+pub mod set_store;
+pub mod setrange;
+pub mod setx;
+pub mod sismember;
+pub mod smembers;
+pub mod sorted_index;
+pub mod stream_groups;
+pub mod strlen;
+pub mod swapdb;
 #[cfg(test)]
 pub(crate) mod tests;
+pub mod ttl;
+pub mod wait;
+pub mod waitaof;
+pub mod xadd;
+pub mod zadd;
+pub mod zrange_ext;
+pub mod zscore;
+
+use bytes::Bytes;
 
 /// Error in case Redis response type does not match specification
 #[derive(Debug)]
 pub struct ResponseTypeError {}
 
+/// Uniform access to the raw value of responses wrapping a single string value, e.g.
+/// [GetResponse](get::GetResponse). Allows generic code (e.g. caching or serialization) to handle such
+/// responses without knowing the concrete type.
+pub trait RawBytes {
+    /// Returns a reference to the inner value
+    fn bytes(&self) -> &Bytes;
+}
+
+impl RawBytes for Bytes {
+    fn bytes(&self) -> &Bytes {
+        self
+    }
+}
+
 /// Generic command structure. F is either [Resp2Frame](redis_protocol::resp2::types::BytesFrame) or
 /// [Resp3Frame](redis_protocol::resp3::types::BytesFrame)
 pub trait Command<F> {
@@ -31,7 +95,8 @@ pub trait Command<F> {
     /// response from that.
     /// Its also possible to just return 1:1 the RESP2 frame.
     ///
-    /// Error responses are captured upfront and converted to CommandErrors::ErrorResponse.
+    /// Error responses are captured upfront and converted to CommandErrors::ErrorResponse
+    /// (respectively CommandErrors::ErrorResponseAttributes for RESP3 errors carrying attributes).
     /// So error responses never reach that method.
     ///
     /// Returns Error only in case of protocol violation (e.g. received an array for an command
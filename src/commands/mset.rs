@@ -0,0 +1,101 @@
+//! Abstraction of MSET command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/mset/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::mset::MSetCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!
+//! // All keys are set atomically
+//! let command = MSetCommand::new([("mset_a".into(), "1".into()), ("mset_b".into(), "2".into())]);
+//! client.send(command).unwrap().wait().unwrap();
+//! ```
+//! # Shorthand
+//! [Client](Client#method.mset) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! client.mset([("mset_a".into(), "1".into()), ("mset_b".into(), "2".into())]).unwrap().wait().unwrap();
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::set::ConfirmationResponse;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of MSET command
+pub struct MSetCommand<const N: usize> {
+    /// Key/value pairs
+    pairs: [(Bytes, Bytes); N],
+}
+
+impl<const N: usize> MSetCommand<N> {
+    pub fn new(pairs: [(Bytes, Bytes); N]) -> Self {
+        Self { pairs }
+    }
+}
+
+impl<F, const N: usize> Command<F> for MSetCommand<N>
+where
+    F: From<CommandBuilder> + ToStringOption,
+{
+    type Response = ConfirmationResponse;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("MSET");
+
+        for (key, value) in &self.pairs {
+            builder = builder.arg(key).arg(value);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.expect_simple_string("OK")
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [MSetCommand]
+    pub fn mset<const L: usize>(
+        &'a self,
+        pairs: [(Bytes, Bytes); L],
+    ) -> Result<Future<'a, N, C, P, MSetCommand<L>>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(MSetCommand::new(pairs))
+    }
+}
@@ -0,0 +1,45 @@
+//! Pattern for commands returning multiple entries within a single text reply.
+//!
+//! Some commands (e.g. [CLIENT LIST](crate::commands::client_list)) reply with a single bulk string (RESP2)
+//! respectively verbatim string (RESP3) containing one entry per line.
+//! [MultiReplyCommand] splits such replies into lines and parses each line separately.
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use bytes::Bytes;
+
+/// Command replying with newline separated entries
+pub trait MultiReplyCommand {
+    /// Parsed entry type
+    type Entry;
+
+    /// Parses a single (non-empty) line of the reply. Returns None in case of invalid format.
+    fn parse_entry(&self, line: Bytes) -> Option<Self::Entry>;
+
+    /// Splits the reply into lines and parses each line. Empty lines are skipped.
+    /// Returns None if any line could not be parsed.
+    fn parse_reply(&self, reply: &Bytes) -> Option<Vec<Self::Entry>> {
+        reply
+            .split(|byte| *byte == b'\n')
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .filter(|line| !line.is_empty())
+            .map(|line| self.parse_entry(reply.slice_ref(line)))
+            .collect()
+    }
+}
+
+/// Parses a line of space separated `key=value` properties.
+/// Values may be empty. Returns None if a property is missing the `=` separator.
+pub fn parse_properties(line: &Bytes) -> Option<BTreeMap<Bytes, Bytes>> {
+    let mut map = BTreeMap::new();
+
+    for property in line.split(|byte| *byte == b' ').filter(|property| !property.is_empty()) {
+        let separator = property.iter().position(|byte| *byte == b'=')?;
+
+        map.insert(
+            line.slice_ref(&property[..separator]),
+            line.slice_ref(&property[separator + 1..]),
+        );
+    }
+
+    Some(map)
+}
@@ -0,0 +1,101 @@
+//! Abstraction of PERSIST command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/persist/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::persist::PersistCommand;
+//!# use embedded_redis::commands::set::{ExpirationPolicy, SetCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!
+//! let command = SetCommand::new("persist_key", "value").expires(ExpirationPolicy::Seconds(60));
+//! client.send(command).unwrap().wait().unwrap();
+//!
+//! // Returns true if the timeout was removed
+//! let response = client.send(PersistCommand::new("persist_key")).unwrap().wait().unwrap();
+//! assert!(response);
+//! ```
+//! # Shorthand
+//! [Client](Client#method.persist) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! // False for missing keys and keys without timeout
+//! let response = client.persist("not_existing").unwrap().wait().unwrap();
+//! assert!(!response);
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBool};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction for PERSIST command
+pub struct PersistCommand {
+    key: Bytes,
+}
+
+impl PersistCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for PersistCommand
+where
+    F: From<CommandBuilder> + ToBool,
+{
+    /// True if the timeout was removed, false if key does not exist or has no associated timeout
+    type Response = bool;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("PERSIST").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bool().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [PersistCommand]
+    pub fn persist<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, PersistCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToBool,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(PersistCommand::new(key))
+    }
+}
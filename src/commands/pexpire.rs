@@ -0,0 +1,208 @@
+//! Abstraction of PEXPIRE command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/pexpire/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::pexpire::PExpireCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("pexpire_key", "example").unwrap().wait().unwrap();
+//!
+//! // Key expires in 1.5 seconds
+//! let command = PExpireCommand::new("pexpire_key", 1500);
+//! let response = client.send(command).unwrap().wait().unwrap();
+//!
+//! // Returns true if the timeout was set, false if key is not existing
+//! assert!(response);
+//! ```
+//! # Conditions (NX, XX, GT, LT)
+//! *Requires Redis version >= 7.0*
+//!
+//! Only set the timeout if the given condition is met. Fore more details s. [ExpireCond] enum.
+//! ```no_run
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::pexpire::{ExpireCond, PExpireCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! // Just extend the timeout, but never reduce it
+//! let command = PExpireCommand::new("pexpire_key", 1500).condition(ExpireCond::IfGreater);
+//! let _ = client.send(command);
+//! ```
+//! # Shorthand
+//! [Client](Client#method.pexpire) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let response = client.pexpire("not_existing", 1500).unwrap().wait().unwrap();
+//! assert!(!response);
+//! ```
+//! # Refreshing TTL
+//! [Client](Client#method.touch_ttl) provides a blocking helper for resetting the TTL without transferring the value,
+//! e.g. on cache hits.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! client.set("cached_key", "value").unwrap().wait().unwrap();
+//!
+//! assert!(client.touch_ttl("cached_key", 60_000).unwrap());
+//! assert!(!client.touch_ttl("not_existing", 60_000).unwrap());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBool};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Condition for setting the timeout of EXPIRE command family
+/// *Requires Redis version >= 7.0*
+pub enum ExpireCond {
+    /// Timeout is set unconditionally
+    None,
+    /// NX option: Set expiry only when the key has no expiry
+    IfNoExpiry,
+    /// XX option: Set expiry only when the key has an existing expiry
+    IfHasExpiry,
+    /// GT option: Set expiry only when the new expiry is greater than current one
+    IfGreater,
+    /// LT option: Set expiry only when the new expiry is less than current one
+    IfLess,
+}
+
+impl ExpireCond {
+    /// Returns the argument of the condition
+    pub(crate) fn option(&self) -> Option<&'static str> {
+        match self {
+            ExpireCond::None => None,
+            ExpireCond::IfNoExpiry => Some("NX"),
+            ExpireCond::IfHasExpiry => Some("XX"),
+            ExpireCond::IfGreater => Some("GT"),
+            ExpireCond::IfLess => Some("LT"),
+        }
+    }
+}
+
+/// Abstraction for PEXPIRE command
+pub struct PExpireCommand {
+    key: Bytes,
+
+    /// Timeout in milliseconds
+    milliseconds: usize,
+
+    condition: ExpireCond,
+}
+
+impl PExpireCommand {
+    pub fn new<K>(key: K, milliseconds: usize) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            key: key.into(),
+            milliseconds,
+            condition: ExpireCond::None,
+        }
+    }
+
+    /// Only set the timeout if the given condition is met
+    pub fn condition(mut self, condition: ExpireCond) -> Self {
+        self.condition = condition;
+        self
+    }
+}
+
+impl<F> Command<F> for PExpireCommand
+where
+    F: From<CommandBuilder> + ToBool,
+{
+    /// True if the timeout was set, false if key does not exist or condition was not met
+    type Response = bool;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("PEXPIRE")
+            .arg(&self.key)
+            .arg_uint(self.milliseconds)
+            .arg_static_option(self.condition.option())
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bool().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [PExpireCommand]
+    /// For using conditions, use [PExpireCommand] directly instead
+    pub fn pexpire<K>(
+        &'a self,
+        key: K,
+        milliseconds: usize,
+    ) -> Result<Future<'a, N, C, P, PExpireCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToBool,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(PExpireCommand::new(key, milliseconds))
+    }
+
+    /// Resets the TTL of the key in milliseconds without retrieving its value, e.g. for refreshing
+    /// cache entries on access. Blocks until the response is received.
+    ///
+    /// Returns true if the key exists, false otherwise.
+    pub fn touch_ttl<K>(&'a self, key: K, milliseconds: usize) -> Result<bool, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToBool,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.pexpire(key, milliseconds)?.wait()
+    }
+}
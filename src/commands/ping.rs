@@ -64,8 +64,11 @@ use crate::commands::builder::{CommandBuilder, ToStringOption};
 use crate::commands::hello::HelloCommand;
 use crate::commands::{Command, ResponseTypeError};
 use crate::network::protocol::Protocol;
+use crate::network::timeout::Timeout;
 use crate::network::{Client, CommandErrors, Future};
+use alloc::format;
 use bytes::Bytes;
+use core::sync::atomic::{AtomicU32, Ordering};
 use embedded_nal::TcpClientStack;
 use embedded_time::Clock;
 
@@ -118,4 +121,66 @@ where
     {
         self.send(PingCommand::new(None))
     }
+
+    /// Realigns the response stream after a desync, e.g. caused by a flaky link.
+    ///
+    /// All pending futures are invalidated. A PING with a unique payload is sent and all received frames are
+    /// discarded until the payload is echoed back. Afterward, responses are mapped correctly to new futures again.
+    /// Malformed data is skipped up to the next decodable position, so an echo following garbage within the same
+    /// chunk is still detected.
+    ///
+    /// Blocks until the echo is received. [Timeout](CommandErrors::Timeout) is returned in case the client timeout
+    /// expired, so it's recommended to configure a timeout. The timeout is reported to the
+    /// [timeout callback](crate::network::ConnectionHandler::on_timeout) like timeouts of regular commands.
+    pub fn resync_to_ping(&'a self) -> Result<(), CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.network.reset();
+
+        let payload = Bytes::from(format!("resync:{}", next_resync_nonce()));
+        let id = self.network.send(CommandBuilder::new("PING").arg(&payload).into())?;
+
+        let timeout = Timeout::new(self.clock, self.timeout_duration)?;
+        loop {
+            while let Some(frame) = self.network.take_next_frame() {
+                if frame.to_string_option().is_some_and(|echo| echo.as_bytes() == payload.as_ref()) {
+                    self.network.reset();
+                    return Ok(());
+                }
+            }
+
+            // Frame boundaries of garbage data are unknown, so parsing is resumed at the following bytes
+            if self.network.skip_faulty() {
+                continue;
+            }
+
+            if timeout.expired()? {
+                self.network.notify_timeout(&id);
+                self.network.invalidate_futures();
+                return Err(CommandErrors::Timeout);
+            }
+
+            match self.network.receive_chunk() {
+                Ok(_) => {}
+                Err(nb::Error::WouldBlock) => self.network.yield_wait(),
+                Err(nb::Error::Other(_)) => return Err(CommandErrors::TcpError),
+            }
+        }
+    }
+}
+
+/// Last nonce used for resync payloads. Shared by all clients, so a stale echo of a previous (e.g. timed out)
+/// resync on the same connection is never mistaken for the current one.
+static RESYNC_NONCE: AtomicU32 = AtomicU32::new(0);
+
+/// Returns a process-wide increasing nonce
+///
+/// Load and store instead of `fetch_add`, as atomic read-modify-write operations are not available on all targets.
+/// Concurrent calls may return the same nonce, which is harmless as they belong to different connections.
+fn next_resync_nonce() -> u32 {
+    let nonce = RESYNC_NONCE.load(Ordering::Relaxed).wrapping_add(1);
+    RESYNC_NONCE.store(nonce, Ordering::Relaxed);
+    nonce
 }
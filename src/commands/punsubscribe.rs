@@ -0,0 +1,72 @@
+//! Abstraction of PUNSUBSCRIBE command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/punsubscribe/>).
+//!
+//! Redis confirms each pattern by a separate `punsubscribe` push message. So the command is intended to be sent
+//! on pattern subscriptions by [Subscription::punsubscribe](crate::subscription::Subscription::punsubscribe), which
+//! waits for all confirmations.
+//!
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let server_address = SocketAddr::from_str("127.0.0.1:6379").unwrap();
+//!# let mut connection_handler = ConnectionHandler::resp3(server_address);
+//! let mut subscription = connection_handler
+//!                 .connect(&mut stack, Some(&clock)).unwrap()
+//!                 .psubscribe(["sensor.*".into(), "alarm.*".into()])
+//!                 .unwrap();
+//!
+//! subscription.punsubscribe(["sensor.*".into()]).unwrap();
+//! ```
+use crate::commands::builder::CommandBuilder;
+use crate::commands::{Command, ResponseTypeError};
+use crate::subscription::messages::{Message, ToPushMessage};
+use bytes::Bytes;
+
+/// Abstraction of PUNSUBSCRIBE command
+pub struct PUnsubscribeCommand<const N: usize> {
+    patterns: [Bytes; N],
+}
+
+impl<const N: usize> PUnsubscribeCommand<N> {
+    pub fn new(patterns: [Bytes; N]) -> Self {
+        Self { patterns }
+    }
+
+    /// Returns the patterns to unsubscribe from
+    pub fn patterns(&self) -> &[Bytes] {
+        &self.patterns
+    }
+}
+
+impl<F, const N: usize> Command<F> for PUnsubscribeCommand<N>
+where
+    F: From<CommandBuilder> + ToPushMessage,
+{
+    /// Number of channels and patterns still subscribed to, as stated by a single confirmation
+    type Response = usize;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("PUNSUBSCRIBE");
+
+        for pattern in &self.patterns {
+            builder = builder.arg(pattern);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        match frame.decode_push() {
+            Ok(Message::PatternUnSubConfirmation(_, count)) => Ok(count),
+            _ => Err(ResponseTypeError {}),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Abstraction of RENAME and RENAMENX commands.
+//!
+//! For general information about these commands, see the Redis documentation of
+//! [RENAME](<https://redis.io/commands/rename/>) and [RENAMENX](<https://redis.io/commands/renamenx/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::rename::{RenameCommand, RenameNxCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["rename_new".into(), "rename_other".into()]).unwrap().wait().unwrap();
+//! client.set("rename_old", "value").unwrap().wait().unwrap();
+//!
+//! // Existing destination keys are overwritten
+//! client.send(RenameCommand::new("rename_old", "rename_new")).unwrap().wait().unwrap();
+//!
+//! // Only renamed if the destination does not exist yet
+//! client.set("rename_other", "value").unwrap().wait().unwrap();
+//! let renamed = client.send(RenameNxCommand::new("rename_new", "rename_other")).unwrap().wait().unwrap();
+//! assert!(!renamed);
+//! ```
+//! # Missing source key
+//! Redis responds with an error in case the source key does not exist, which is returned as
+//! [ErrorResponse](CommandErrors::ErrorResponse). This applies to both commands.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::rename::RenameCommand;
+//!# use embedded_redis::network::{CommandErrors, ConnectionHandler};
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let error = client.send(RenameCommand::new("missing_key", "new_key")).unwrap().wait().unwrap_err();
+//! assert!(matches!(error, CommandErrors::ErrorResponse(_)));
+//! ```
+//! # Shorthand
+//! [Client](Client#method.rename) and [Client](Client#method.renamenx) provide shorthand methods.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.set("shorthand_old", "value").unwrap().wait().unwrap();
+//!#
+//! client.rename("shorthand_old", "shorthand_new").unwrap().wait().unwrap();
+//! let _ = client.renamenx("shorthand_new", "shorthand_other").unwrap().wait().unwrap();
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBool, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::set::ConfirmationResponse;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of RENAME command
+pub struct RenameCommand {
+    source: Bytes,
+
+    destination: Bytes,
+}
+
+impl RenameCommand {
+    pub fn new<S, D>(source: S, destination: D) -> Self
+    where
+        Bytes: From<S>,
+        Bytes: From<D>,
+    {
+        Self {
+            source: source.into(),
+            destination: destination.into(),
+        }
+    }
+}
+
+impl<F> Command<F> for RenameCommand
+where
+    F: From<CommandBuilder> + ToStringOption,
+{
+    type Response = ConfirmationResponse;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("RENAME").arg(&self.source).arg(&self.destination).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.expect_simple_string("OK")
+    }
+}
+
+/// Abstraction of RENAMENX command
+pub struct RenameNxCommand {
+    source: Bytes,
+
+    destination: Bytes,
+}
+
+impl RenameNxCommand {
+    pub fn new<S, D>(source: S, destination: D) -> Self
+    where
+        Bytes: From<S>,
+        Bytes: From<D>,
+    {
+        Self {
+            source: source.into(),
+            destination: destination.into(),
+        }
+    }
+}
+
+impl<F> Command<F> for RenameNxCommand
+where
+    F: From<CommandBuilder> + ToBool,
+{
+    /// True if the key has been renamed. False if the destination key already exists.
+    type Response = bool;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("RENAMENX").arg(&self.source).arg(&self.destination).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bool().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [RenameCommand]
+    pub fn rename<S, D>(
+        &'a self,
+        source: S,
+        destination: D,
+    ) -> Result<Future<'a, N, C, P, RenameCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<S>,
+        Bytes: From<D>,
+    {
+        self.send(RenameCommand::new(source, destination))
+    }
+
+    /// Shorthand for [RenameNxCommand]
+    pub fn renamenx<S, D>(
+        &'a self,
+        source: S,
+        destination: D,
+    ) -> Result<Future<'a, N, C, P, RenameNxCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToBool,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<S>,
+        Bytes: From<D>,
+    {
+        self.send(RenameNxCommand::new(source, destination))
+    }
+}
@@ -0,0 +1,230 @@
+//! Abstraction of SCAN command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/scan/>).
+//!
+//! # Iterating keys
+//! SCAN iterates the keyspace incrementally by a cursor. Each call returns a page of keys and the cursor for the
+//! next call. The iteration starts and ends with cursor 0.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::scan::ScanCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("scan_key", "value").unwrap().wait().unwrap();
+//!
+//! let mut cursor = 0;
+//! let mut keys = Vec::new();
+//! loop {
+//!     let command = ScanCommand::new(cursor).pattern("scan_*").count(100);
+//!     let page = client.send(command).unwrap().wait().unwrap();
+//!     keys.extend(page.keys);
+//!
+//!     cursor = page.cursor;
+//!     if cursor == 0 {
+//!         break;
+//!     }
+//! }
+//!
+//! assert!(keys.contains(&"scan_key".into()));
+//! ```
+//! Keys may be returned multiple times, e.g. in case of rehashing during the iteration.
+//!
+//! # Shorthand
+//! [Client](Client#method.scan) provides a shorthand method.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let page = client.scan(0).unwrap().wait().unwrap();
+//! ```
+//! # Estimating the key count
+//! [Client](Client#method.estimate_key_count) extrapolates the total number of keys from a bounded number of
+//! SCAN iterations, as non-blocking approximation of DBSIZE.
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBytesVec, ToStringBytes};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::future::Future;
+use crate::network::protocol::Protocol;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use bytes::Bytes;
+use core::str::from_utf8;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+use redis_protocol::resp2::types::BytesFrame as Resp2Frame;
+use redis_protocol::resp3::types::BytesFrame as Resp3Frame;
+
+/// Abstraction for SCAN command
+pub struct ScanCommand {
+    cursor: u64,
+
+    /// MATCH option
+    pattern: Option<Bytes>,
+
+    /// COUNT option
+    count: Option<usize>,
+}
+
+impl ScanCommand {
+    /// Starts (cursor 0) or continues the iteration at the given cursor
+    pub fn new(cursor: u64) -> Self {
+        Self {
+            cursor,
+            pattern: None,
+            count: None,
+        }
+    }
+
+    /// Only returns keys matching the given glob-style pattern. The pattern is applied after retrieving the
+    /// elements, so pages may be empty while the iteration is not finished yet.
+    pub fn pattern<M>(mut self, pattern: M) -> Self
+    where
+        Bytes: From<M>,
+    {
+        self.pattern = Some(pattern.into());
+        self
+    }
+
+    /// Hint for the amount of work done per call. Redis default is 10.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+}
+
+/// Page of a SCAN iteration
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ScanResponse {
+    /// Cursor for the next call. 0 if the iteration is complete.
+    pub cursor: u64,
+
+    /// Keys of this page
+    pub keys: Vec<Bytes>,
+}
+
+/// Trait for converting cursor based responses (e.g. SCAN) of RESP2/RESP3 frames
+pub trait ToScanResponse {
+    /// Converts a `[cursor, [element, ...]]` array. None in case of protocol violation.
+    fn to_scan_response(&self) -> Option<ScanResponse>;
+}
+
+impl ToScanResponse for Resp2Frame {
+    fn to_scan_response(&self) -> Option<ScanResponse> {
+        match self {
+            Resp2Frame::Array(array) if array.len() == 2 => scan_response(&array[0], &array[1]),
+            _ => None,
+        }
+    }
+}
+
+impl ToScanResponse for Resp3Frame {
+    fn to_scan_response(&self) -> Option<ScanResponse> {
+        match self {
+            Resp3Frame::Array { data, attributes: _ } if data.len() == 2 => scan_response(&data[0], &data[1]),
+            _ => None,
+        }
+    }
+}
+
+/// Parses the cursor and element frames
+fn scan_response<F: ToStringBytes + ToBytesVec>(cursor: &F, keys: &F) -> Option<ScanResponse> {
+    let cursor = cursor.to_string_bytes()?;
+
+    Some(ScanResponse {
+        cursor: from_utf8(&cursor).ok()?.parse().ok()?,
+        keys: keys.to_bytes_vec()?,
+    })
+}
+
+impl<F> Command<F> for ScanCommand
+where
+    F: From<CommandBuilder> + ToScanResponse,
+{
+    type Response = ScanResponse;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("SCAN").arg(&Bytes::from(self.cursor.to_string()));
+
+        if let Some(pattern) = &self.pattern {
+            builder = builder.arg_static("MATCH").arg(pattern);
+        }
+
+        if let Some(count) = self.count {
+            builder = builder.arg_static("COUNT").arg_uint(count);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_scan_response().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [ScanCommand]
+    pub fn scan(&'a self, cursor: u64) -> Result<Future<'a, N, C, P, ScanCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToScanResponse,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(ScanCommand::new(cursor))
+    }
+
+    /// Estimates the number of keys by executing up to the given number of SCAN iterations.
+    /// Blocks until all iterations are completed.
+    ///
+    /// SCAN visits the hash table buckets in reverse binary order, so the bit-reversed cursor corresponds to the
+    /// visited share of the keyspace. The number of keys seen is extrapolated by this share.
+    /// In case the iteration completes within the given number of iterations, the number of keys seen is returned.
+    ///
+    /// The estimate assumes an even distribution of keys across buckets and may be inaccurate during rehashing.
+    /// At least one iteration is executed.
+    pub fn estimate_key_count(&'a self, sample_iterations: usize) -> Result<usize, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToScanResponse,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        let mut cursor = 0;
+        let mut seen = 0;
+
+        for _ in 0..sample_iterations.max(1) {
+            let page = self.scan(cursor)?.wait()?;
+            seen += page.keys.len();
+            cursor = page.cursor;
+
+            if cursor == 0 {
+                return Ok(seen);
+            }
+        }
+
+        // Visited share of the keyspace in units of 2^-64
+        let progress = cursor.reverse_bits() as u128;
+        let estimate = ((seen as u128) << 64) / progress;
+
+        Ok(estimate.try_into().unwrap_or(usize::MAX))
+    }
+}
@@ -42,6 +42,17 @@
 //!      .expires(ExpirationPolicy::Seconds(120));
 //!# let _ = client.send(command);
 //! ```
+//! ## Jitter
+//! Keys set by many devices with identical TTL expire simultaneously. [ExpirationPolicy::SecondsJittered]
+//! adds a deterministic jitter derived from a caller provided seed (e.g. device ID), spreading the expiry.
+//! ```
+//!# use embedded_redis::commands::set::{SetCommand, ExpirationPolicy};
+//!#
+//!  // Expires in 120 to 150 seconds, depending on the seed
+//!  let device_id = 4711;
+//!  let command = SetCommand::new("key", "value")
+//!      .expires(ExpirationPolicy::SecondsJittered(120, 30, device_id));
+//! ```
 //! # Exclusive condition (NX/XX)
 //! Manage set condition. Fore more details s. [Exclusivity] enum.
 //!
@@ -88,6 +99,27 @@
 //!      .return_previous();
 //!# let _ = client.send(command);
 //! ```
+//! # Swapping values
+//! [Client](Client#method.swap_value) provides a blocking helper, which atomically sets the new value
+//! and returns the previous one.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! client.set("swap_key", "first").unwrap().wait().unwrap();
+//!
+//! let previous = client.swap_value("swap_key", "second").unwrap();
+//! assert_eq!("first", previous.unwrap());
+//! ```
 //! # Shorthand
 //! [Client](Client#method.set) provides a shorthand method for this command.
 //! ```
@@ -119,7 +151,8 @@
 
 use crate::commands::auth::AuthCommand;
 use crate::commands::builder::{CommandBuilder, IsNullFrame, ToStringBytes, ToStringOption};
-use crate::commands::hello::HelloCommand;
+use crate::commands::eval::EvalCommand;
+use crate::commands::hello::{HelloCommand, HelloResponse};
 use crate::commands::{Command, ResponseTypeError};
 use crate::network::client::{Client, CommandErrors};
 use crate::network::future::Future;
@@ -130,11 +163,20 @@ use core::marker::PhantomData;
 use embedded_nal::TcpClientStack;
 use embedded_time::Clock;
 
+/// Returns the value (KEYS[1]) if existing, otherwise sets the default (ARGV[1]) with optional TTL in
+/// milliseconds (ARGV[2]) and returns nil. Fallback for servers not supporting SET with NX and GET.
+static GET_OR_SET_SCRIPT: &str = "local value = redis.call('GET', KEYS[1]) if value then return value end \
+     if ARGV[2] then redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2]) \
+     else redis.call('SET', KEYS[1], ARGV[1]) end return false";
+
 pub enum ExpirationPolicy {
     /// Does not set and expiration option
     Never,
     /// EX option
     Seconds(usize),
+    /// EX option with base TTL, max. jitter and seed. The TTL is in range `[base, base + jitter]` and is
+    /// derived deterministically from the seed, so the same seed always results in the same TTL.
+    SecondsJittered(usize, usize, u64),
     /// PX option
     Milliseconds(usize),
     /// EXAT option
@@ -241,11 +283,7 @@ where
     }
 
     fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
-        if frame.to_string_option().ok_or(ResponseTypeError {})? != "OK" {
-            return Err(ResponseTypeError {});
-        }
-
-        Ok(())
+        frame.expect_simple_string("OK")
     }
 }
 
@@ -264,11 +302,8 @@ where
             return Ok(None);
         }
 
-        if frame.to_string_option().ok_or(ResponseTypeError {})? == "OK" {
-            return Ok(Some(()));
-        }
-
-        Err(ResponseTypeError {})
+        frame.expect_simple_string("OK")?;
+        Ok(Some(()))
     }
 }
 
@@ -307,7 +342,7 @@ impl<R> SetCommand<R> {
     fn expiration_unit(&self) -> Option<&'static str> {
         match self.expiration {
             ExpirationPolicy::Never => None,
-            ExpirationPolicy::Seconds(_) => Some("EX"),
+            ExpirationPolicy::Seconds(_) | ExpirationPolicy::SecondsJittered(..) => Some("EX"),
             ExpirationPolicy::Milliseconds(_) => Some("PX"),
             ExpirationPolicy::TimestampSeconds(_) => Some("EXAT"),
             ExpirationPolicy::TimestampMilliseconds(_) => Some("PXAT"),
@@ -323,6 +358,9 @@ impl<R> SetCommand<R> {
             | ExpirationPolicy::Milliseconds(seconds)
             | ExpirationPolicy::TimestampSeconds(seconds)
             | ExpirationPolicy::TimestampMilliseconds(seconds) => Some(seconds.to_string().into()),
+            ExpirationPolicy::SecondsJittered(base, jitter, seed) => {
+                Some(base.saturating_add(jitter_offset(jitter, seed)).to_string().into())
+            }
             ExpirationPolicy::Keep => None,
         }
     }
@@ -345,6 +383,16 @@ impl<R> SetCommand<R> {
     }
 }
 
+/// Returns a pseudo random offset in range `[0, jitter]` derived from the seed (SplitMix64)
+fn jitter_offset(jitter: usize, seed: u64) -> usize {
+    let mut value = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^= value >> 31;
+
+    (value % (jitter as u64).saturating_add(1)) as usize
+}
+
 impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
 where
     AuthCommand: Command<<P as Protocol>::FrameType>,
@@ -367,4 +415,85 @@ where
     {
         self.send(SetCommand::new(key, value))
     }
+
+    /// Atomically sets the new value and returns the previous one by using SET command with GET option.
+    /// Blocks until the response is received.
+    ///
+    /// Some => The previous string value stored at key.
+    /// None => The key did not exist.
+    ///
+    /// If the server version is known to be older than 6.2 (s. [Client::server_version]), the deprecated
+    /// GETSET command is used instead.
+    pub fn swap_value<K, V>(&'a self, key: K, new_value: V) -> Result<Option<Bytes>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringBytes,
+        <P as Protocol>::FrameType: IsNullFrame,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        HelloCommand: Command<<P as Protocol>::FrameType, Response = HelloResponse>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        if self.server_version().is_some_and(|version| !version.at_least(6, 2)) {
+            let command = CommandBuilder::new("GETSET")
+                .arg(&key.into())
+                .arg(&new_value.into())
+                .to_command()
+                .expect_string();
+            return self.send(command)?.wait();
+        }
+
+        self.send(SetCommand::new(key, new_value).return_previous())?.wait()
+    }
+
+    /// Atomically returns the value stored at key or sets and returns the given default if the key is missing.
+    /// The TTL in milliseconds is only applied to a newly created default. Blocks until the response is received.
+    ///
+    /// Returns the value and true if the default was created, false if the value was already existing.
+    ///
+    /// Uses SET command with NX and GET option. If the server version is known to be older than 7.0
+    /// (s. [Client::server_version]), a Lua script is executed by EVAL instead.
+    pub fn get_or_set<K, V>(
+        &'a self,
+        key: K,
+        default: V,
+        ttl: Option<usize>,
+    ) -> Result<(Bytes, bool), CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringBytes,
+        <P as Protocol>::FrameType: IsNullFrame,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        HelloCommand: Command<<P as Protocol>::FrameType, Response = HelloResponse>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        let key: Bytes = key.into();
+        let default: Bytes = default.into();
+
+        let existing = if self.server_version().is_some_and(|version| !version.at_least(7, 0)) {
+            let mut command = EvalCommand::new::<&'static str>(GET_OR_SET_SCRIPT)
+                .key(key)
+                .arg(default.clone());
+            if let Some(milliseconds) = ttl {
+                command = command.arg(milliseconds.to_string());
+            }
+
+            self.send(command.to_command().expect_string())?.wait()?
+        } else {
+            let expiration = match ttl {
+                None => ExpirationPolicy::Never,
+                Some(milliseconds) => ExpirationPolicy::Milliseconds(milliseconds),
+            };
+            let command = SetCommand::new(key, default.clone())
+                .expires(expiration)
+                .set_exclusive(Exclusivity::SetIfMissing)
+                .return_previous();
+
+            self.send(command)?.wait()?
+        };
+
+        match existing {
+            Some(value) => Ok((value, false)),
+            None => Ok((default, true)),
+        }
+    }
 }
@@ -0,0 +1,227 @@
+//! Abstraction of SINTERSTORE, SUNIONSTORE and SDIFFSTORE commands.
+//!
+//! For general information about these commands, see the Redis documentation of
+//! [SINTERSTORE](<https://redis.io/commands/sinterstore/>),
+//! [SUNIONSTORE](<https://redis.io/commands/sunionstore/>) and
+//! [SDIFFSTORE](<https://redis.io/commands/sdiffstore/>).
+//!
+//! The resulting set is stored at the destination key, so the set members are not transferred to the client.
+//! Only the cardinality of the resulting set is returned.
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::commands::set_store::{SDiffStoreCommand, SInterStoreCommand, SUnionStoreCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.send(CommandBuilder::new("DEL").arg_static("set_a").arg_static("set_b").to_command()).unwrap().wait().unwrap();
+//!# client.send(CommandBuilder::new("SADD").arg_static("set_a").arg_static("1").arg_static("2").to_command()).unwrap().wait().unwrap();
+//!# client.send(CommandBuilder::new("SADD").arg_static("set_b").arg_static("2").arg_static("3").to_command()).unwrap().wait().unwrap();
+//!
+//! let command = SInterStoreCommand::new("set_inter", ["set_a".into(), "set_b".into()]);
+//! assert_eq!(1, client.send(command).unwrap().wait().unwrap());
+//!
+//! let command = SUnionStoreCommand::new("set_union", ["set_a".into(), "set_b".into()]);
+//! assert_eq!(3, client.send(command).unwrap().wait().unwrap());
+//!
+//! let command = SDiffStoreCommand::new("set_diff", ["set_a".into(), "set_b".into()]);
+//! assert_eq!(1, client.send(command).unwrap().wait().unwrap());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.sinterstore) provides shorthand methods for these commands.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let _ = client.sinterstore("set_inter", ["set_a".into(), "set_b".into()]);
+//! let _ = client.sunionstore("set_union", ["set_a".into(), "set_b".into()]);
+//! let _ = client.sdiffstore("set_diff", ["set_a".into(), "set_b".into()]);
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of SINTERSTORE command
+pub struct SInterStoreCommand<const N: usize> {
+    /// Key the resulting set is stored at
+    destination: Bytes,
+
+    /// Keys of source sets
+    keys: [Bytes; N],
+}
+
+impl<const N: usize> SInterStoreCommand<N> {
+    pub fn new<D>(destination: D, keys: [Bytes; N]) -> Self
+    where
+        Bytes: From<D>,
+    {
+        Self {
+            destination: destination.into(),
+            keys,
+        }
+    }
+}
+
+impl<F: From<CommandBuilder> + ToInteger, const N: usize> Command<F> for SInterStoreCommand<N> {
+    /// Number of elements in the resulting set
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        store_builder("SINTERSTORE", &self.destination, &self.keys).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Abstraction of SUNIONSTORE command
+pub struct SUnionStoreCommand<const N: usize> {
+    /// Key the resulting set is stored at
+    destination: Bytes,
+
+    /// Keys of source sets
+    keys: [Bytes; N],
+}
+
+impl<const N: usize> SUnionStoreCommand<N> {
+    pub fn new<D>(destination: D, keys: [Bytes; N]) -> Self
+    where
+        Bytes: From<D>,
+    {
+        Self {
+            destination: destination.into(),
+            keys,
+        }
+    }
+}
+
+impl<F: From<CommandBuilder> + ToInteger, const N: usize> Command<F> for SUnionStoreCommand<N> {
+    /// Number of elements in the resulting set
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        store_builder("SUNIONSTORE", &self.destination, &self.keys).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Abstraction of SDIFFSTORE command
+pub struct SDiffStoreCommand<const N: usize> {
+    /// Key the resulting set is stored at
+    destination: Bytes,
+
+    /// Keys of source sets. Members of the first set, which are not existing in any other set, are stored.
+    keys: [Bytes; N],
+}
+
+impl<const N: usize> SDiffStoreCommand<N> {
+    pub fn new<D>(destination: D, keys: [Bytes; N]) -> Self
+    where
+        Bytes: From<D>,
+    {
+        Self {
+            destination: destination.into(),
+            keys,
+        }
+    }
+}
+
+impl<F: From<CommandBuilder> + ToInteger, const N: usize> Command<F> for SDiffStoreCommand<N> {
+    /// Number of elements in the resulting set
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        store_builder("SDIFFSTORE", &self.destination, &self.keys).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+/// General logic for building the commands. Destination key is always the first argument.
+fn store_builder(keyword: &'static str, destination: &Bytes, keys: &[Bytes]) -> CommandBuilder {
+    let mut builder = CommandBuilder::new(keyword).arg(destination);
+
+    for key in keys {
+        builder = builder.arg(key);
+    }
+
+    builder
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [SInterStoreCommand]
+    pub fn sinterstore<D, const L: usize>(
+        &'a self,
+        destination: D,
+        keys: [Bytes; L],
+    ) -> Result<Future<'a, N, C, P, SInterStoreCommand<L>>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<D>,
+    {
+        self.send(SInterStoreCommand::new(destination, keys))
+    }
+
+    /// Shorthand for [SUnionStoreCommand]
+    pub fn sunionstore<D, const L: usize>(
+        &'a self,
+        destination: D,
+        keys: [Bytes; L],
+    ) -> Result<Future<'a, N, C, P, SUnionStoreCommand<L>>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<D>,
+    {
+        self.send(SUnionStoreCommand::new(destination, keys))
+    }
+
+    /// Shorthand for [SDiffStoreCommand]
+    pub fn sdiffstore<D, const L: usize>(
+        &'a self,
+        destination: D,
+        keys: [Bytes; L],
+    ) -> Result<Future<'a, N, C, P, SDiffStoreCommand<L>>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<D>,
+    {
+        self.send(SDiffStoreCommand::new(destination, keys))
+    }
+}
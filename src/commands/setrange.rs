@@ -0,0 +1,118 @@
+//! Abstraction of SETRANGE command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/setrange/>).
+//!
+//! # Using command object
+//! Overwrites part of the string starting at the given offset. Missing keys are treated as empty string,
+//! and the string is padded with zero bytes if the offset exceeds its length.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::setrange::SetRangeCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("setrange_key", "Hello World").unwrap().wait().unwrap();
+//!
+//! // Returns the new length of the string
+//! let length = client.send(SetRangeCommand::new("setrange_key", 6, "Redis")).unwrap().wait().unwrap();
+//! assert_eq!(11, length);
+//! ```
+//! # Shorthand
+//! [Client](Client#method.setrange) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let _ = client.setrange("setrange_key", 0, "J").unwrap().wait().unwrap();
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of SETRANGE command
+pub struct SetRangeCommand {
+    key: Bytes,
+
+    /// Zero-based byte offset
+    offset: usize,
+
+    value: Bytes,
+}
+
+impl SetRangeCommand {
+    pub fn new<K, V>(key: K, offset: usize, value: V) -> Self
+    where
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        Self {
+            key: key.into(),
+            offset,
+            value: value.into(),
+        }
+    }
+}
+
+impl<F> Command<F> for SetRangeCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Length of the string after modification
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("SETRANGE")
+            .arg(&self.key)
+            .arg_uint(self.offset)
+            .arg(&self.value)
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [SetRangeCommand]
+    pub fn setrange<K, V>(
+        &'a self,
+        key: K,
+        offset: usize,
+        value: V,
+    ) -> Result<Future<'a, N, C, P, SetRangeCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        self.send(SetRangeCommand::new(key, offset, value))
+    }
+}
@@ -0,0 +1,186 @@
+//! Abstraction of SETNX and SETEX commands.
+//!
+//! For general information about these commands, see the Redis documentation of
+//! [SETNX](<https://redis.io/commands/setnx/>) and [SETEX](<https://redis.io/commands/setex/>).
+//!
+//! Both commands are lightweight alternatives to [SetCommand](crate::commands::set::SetCommand) with
+//! NX or EX option.
+//!
+//! # SETNX
+//! Sets the key only if not existing. Returns true if the key was set.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::commands::setx::SetNxCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# let _ = client.send(CommandBuilder::new("DEL").arg_static("setnx_key").to_command()).unwrap().wait();
+//!
+//! assert!(client.send(SetNxCommand::new("setnx_key", "first")).unwrap().wait().unwrap());
+//! assert!(!client.send(SetNxCommand::new("setnx_key", "second")).unwrap().wait().unwrap());
+//! ```
+//! # SETEX
+//! Sets the key with an expiration in seconds.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::setx::SetExCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! client.send(SetExCommand::new("setex_key", 60, "value")).unwrap().wait().unwrap();
+//! ```
+//! # Shorthand
+//! [Client](Client#method.setnx) and [Client](Client#method.setex) provide shorthand methods.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let _ = client.setnx("setnx_shorthand", "value").unwrap().wait().unwrap();
+//! client.setex("setex_shorthand", 60, "value").unwrap().wait().unwrap();
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBool, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::set::ConfirmationResponse;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of SETNX command
+pub struct SetNxCommand {
+    key: Bytes,
+    value: Bytes,
+}
+
+impl SetNxCommand {
+    pub fn new<K, V>(key: K, value: V) -> Self
+    where
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl<F> Command<F> for SetNxCommand
+where
+    F: From<CommandBuilder> + ToBool,
+{
+    /// True if the key was set, false if the key already exists
+    type Response = bool;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("SETNX").arg(&self.key).arg(&self.value).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bool().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Abstraction of SETEX command
+pub struct SetExCommand {
+    key: Bytes,
+
+    /// Expiration in seconds
+    seconds: usize,
+
+    value: Bytes,
+}
+
+impl SetExCommand {
+    pub fn new<K, V>(key: K, seconds: usize, value: V) -> Self
+    where
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        Self {
+            key: key.into(),
+            seconds,
+            value: value.into(),
+        }
+    }
+}
+
+impl<F> Command<F> for SetExCommand
+where
+    F: From<CommandBuilder> + ToStringOption,
+{
+    type Response = ConfirmationResponse;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("SETEX")
+            .arg(&self.key)
+            .arg_uint(self.seconds)
+            .arg(&self.value)
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.expect_simple_string("OK")
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [SetNxCommand]
+    pub fn setnx<K, V>(&'a self, key: K, value: V) -> Result<Future<'a, N, C, P, SetNxCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToBool,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        self.send(SetNxCommand::new(key, value))
+    }
+
+    /// Shorthand for [SetExCommand]
+    pub fn setex<K, V>(
+        &'a self,
+        key: K,
+        seconds: usize,
+        value: V,
+    ) -> Result<Future<'a, N, C, P, SetExCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        self.send(SetExCommand::new(key, seconds, value))
+    }
+}
@@ -0,0 +1,174 @@
+//! Abstraction of SISMEMBER and SCARD commands.
+//!
+//! For general information about these commands, see the [Redis documentation](<https://redis.io/commands/sismember/>)
+//! and [SCARD](<https://redis.io/commands/scard/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//! use embedded_redis::commands::sismember::SetIsMemberCommand;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.send(CommandBuilder::new("SADD").arg_static("my_set").arg_static("green").to_command()).unwrap().wait().unwrap();
+//!
+//! let command = SetIsMemberCommand::new("my_set", "green");
+//! assert!(client.send(command).unwrap().wait().unwrap());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.sismember) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! let is_member = client.sismember("my_set", "not_existing").unwrap().wait().unwrap();
+//! assert!(!is_member);
+//! ```
+//! # Set size
+//! [SetCardCommand] returns the number of members. Zero is returned in case of missing key.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::commands::sismember::SetCardCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["scard_set".into()]).unwrap().wait().unwrap();
+//!# client.send(CommandBuilder::new("SADD").arg_static("scard_set").arg_static("red").arg_static("green").to_command()).unwrap().wait().unwrap();
+//!#
+//! assert_eq!(2, client.send(SetCardCommand::new("scard_set")).unwrap().wait().unwrap());
+//!
+//! // Shorthand
+//! assert_eq!(0, client.scard("missing_set").unwrap().wait().unwrap());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBool, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of SISMEMBER command
+pub struct SetIsMemberCommand {
+    /// Set key
+    key: Bytes,
+
+    member: Bytes,
+}
+
+impl SetIsMemberCommand {
+    pub fn new<K, M>(key: K, member: M) -> Self
+    where
+        Bytes: From<K>,
+        Bytes: From<M>,
+    {
+        Self {
+            key: key.into(),
+            member: member.into(),
+        }
+    }
+}
+
+impl<F> Command<F> for SetIsMemberCommand
+where
+    F: From<CommandBuilder> + ToBool,
+{
+    /// True if the member is part of the set. False if the member or the set does not exist.
+    type Response = bool;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("SISMEMBER").arg(&self.key).arg(&self.member).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bool().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Abstraction of SCARD command
+pub struct SetCardCommand {
+    /// Set key
+    key: Bytes,
+}
+
+impl SetCardCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for SetCardCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Number of members. Zero in case of missing key.
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("SCARD").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [SetIsMemberCommand]
+    pub fn sismember<K, M>(
+        &'a self,
+        key: K,
+        member: M,
+    ) -> Result<Future<'a, N, C, P, SetIsMemberCommand>, CommandErrors>
+    where
+        Bytes: From<K>,
+        Bytes: From<M>,
+        <P as Protocol>::FrameType: ToBool,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(SetIsMemberCommand::new(key, member))
+    }
+
+    /// Shorthand for [SetCardCommand]
+    pub fn scard<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, SetCardCommand>, CommandErrors>
+    where
+        Bytes: From<K>,
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(SetCardCommand::new(key))
+    }
+}
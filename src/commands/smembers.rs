@@ -0,0 +1,109 @@
+//! Abstraction of SMEMBERS command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/smembers/>).
+//!
+//! # Using command object
+//! Returns all members of the set. *As sets are unordered, the order of the returned members is undefined.*
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::commands::smembers::SetMembersCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["smembers_set".into()]).unwrap().wait().unwrap();
+//!# client.send(CommandBuilder::new("SADD").arg_static("smembers_set").arg_static("red").arg_static("green").to_command()).unwrap().wait().unwrap();
+//!
+//! let mut members = client.send(SetMembersCommand::new("smembers_set")).unwrap().wait().unwrap();
+//! members.sort();
+//!
+//! assert_eq!(2, members.len());
+//! assert_eq!("green", members[0]);
+//! assert_eq!("red", members[1]);
+//! ```
+//! # Missing key
+//! In case of missing key, an empty list is returned.
+//!
+//! # Shorthand
+//! [Client](Client#method.smembers) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let members = client.smembers("missing_set").unwrap().wait().unwrap();
+//! assert!(members.is_empty());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBytesVec};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::future::Future;
+use crate::network::protocol::Protocol;
+use alloc::vec::Vec;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of SMEMBERS command
+pub struct SetMembersCommand {
+    /// Set key
+    key: Bytes,
+}
+
+impl SetMembersCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for SetMembersCommand
+where
+    F: From<CommandBuilder> + ToBytesVec,
+{
+    /// Members of the set in undefined order. Empty in case of missing key.
+    type Response = Vec<Bytes>;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("SMEMBERS").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bytes_vec().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [SetMembersCommand]
+    pub fn smembers<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, SetMembersCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToBytesVec,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(SetMembersCommand::new(key))
+    }
+}
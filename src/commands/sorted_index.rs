@@ -0,0 +1,171 @@
+//! Lexicographical secondary index based on ZADD, ZREM and ZRANGEBYLEX commands.
+//!
+//! For general information about this pattern, see the [Redis documentation](<https://redis.io/docs/manual/patterns/indexes/#lexicographical-indexes>).
+//!
+//! # Sorted string index
+//! [SortedStringIndex] maintains a sorted set, in which all members have score 0. Thus, members are ordered
+//! lexicographically, which allows efficient prefix and range queries, e.g. over composite `sensor:id` keys.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::commands::sorted_index::SortedStringIndex;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.send(CommandBuilder::new("DEL").arg_static("sensor_index").to_command()).unwrap().wait().unwrap();
+//!
+//! let index = SortedStringIndex::new("sensor_index");
+//! index.add(&client, "temp:1").unwrap();
+//! index.add(&client, "temp:2").unwrap();
+//! index.add(&client, "humidity:1").unwrap();
+//!
+//! assert_eq!(vec!["temp:1", "temp:2"], index.range_prefix(&client, "temp:").unwrap());
+//!
+//! index.remove(&client, "temp:1").unwrap();
+//! assert_eq!(vec!["temp:2"], index.range_prefix(&client, "temp:").unwrap());
+//! ```
+//! # Prefix range
+//! The prefix range is queried by the bounds `[prefix` and `[prefix\xff`. Members containing the byte `0xff`
+//! directly after the prefix are therefore not matched.
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBool, ToBytesVec};
+use crate::commands::hello::HelloCommand;
+use crate::commands::zrange_ext::{LexBound, ZRangeByLexCommand};
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::protocol::Protocol;
+use alloc::vec::Vec;
+use bytes::{BufMut, Bytes, BytesMut};
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Lexicographically sorted set of strings (all scores 0) for prefix and range queries
+#[derive(Debug, Clone)]
+pub struct SortedStringIndex {
+    key: Bytes,
+}
+
+impl SortedStringIndex {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self { key: key.into() }
+    }
+
+    /// Adds the given member. Returns false if the member was already part of the index.
+    pub fn add<'a, N, C, P, M>(
+        &self,
+        client: &'a Client<'a, N, C, P>,
+        member: M,
+    ) -> Result<bool, CommandErrors>
+    where
+        N: TcpClientStack,
+        C: Clock,
+        P: Protocol,
+        AuthCommand: Command<<P as Protocol>::FrameType>,
+        HelloCommand: Command<<P as Protocol>::FrameType>,
+        <P as Protocol>::FrameType: From<CommandBuilder> + ToBool,
+        Bytes: From<M>,
+    {
+        let builder = CommandBuilder::new("ZADD").arg(&self.key).arg_static("0").arg(&member.into());
+        client.send(IndexCommand { builder })?.wait()
+    }
+
+    /// Removes the given member. Returns false if the member was not part of the index.
+    pub fn remove<'a, N, C, P, M>(
+        &self,
+        client: &'a Client<'a, N, C, P>,
+        member: M,
+    ) -> Result<bool, CommandErrors>
+    where
+        N: TcpClientStack,
+        C: Clock,
+        P: Protocol,
+        AuthCommand: Command<<P as Protocol>::FrameType>,
+        HelloCommand: Command<<P as Protocol>::FrameType>,
+        <P as Protocol>::FrameType: From<CommandBuilder> + ToBool,
+        Bytes: From<M>,
+    {
+        let builder = CommandBuilder::new("ZREM").arg(&self.key).arg(&member.into());
+        client.send(IndexCommand { builder })?.wait()
+    }
+
+    /// Returns all members starting with the given prefix in lexicographical order.
+    /// An empty prefix returns all members.
+    pub fn range_prefix<'a, N, C, P, M>(
+        &self,
+        client: &'a Client<'a, N, C, P>,
+        prefix: M,
+    ) -> Result<Vec<Bytes>, CommandErrors>
+    where
+        N: TcpClientStack,
+        C: Clock,
+        P: Protocol,
+        AuthCommand: Command<<P as Protocol>::FrameType>,
+        HelloCommand: Command<<P as Protocol>::FrameType>,
+        <P as Protocol>::FrameType: From<CommandBuilder> + ToBytesVec,
+        Bytes: From<M>,
+    {
+        let prefix: Bytes = prefix.into();
+        if prefix.is_empty() {
+            return self.range(client, LexBound::Min, LexBound::Max);
+        }
+
+        let mut upper = BytesMut::with_capacity(prefix.len() + 1);
+        upper.put_slice(&prefix);
+        upper.put_u8(0xff);
+
+        self.range(
+            client,
+            LexBound::Inclusive(prefix),
+            LexBound::Inclusive(upper.freeze()),
+        )
+    }
+
+    /// Returns all members in the given lexicographical range
+    pub fn range<'a, N, C, P>(
+        &self,
+        client: &'a Client<'a, N, C, P>,
+        min: LexBound,
+        max: LexBound,
+    ) -> Result<Vec<Bytes>, CommandErrors>
+    where
+        N: TcpClientStack,
+        C: Clock,
+        P: Protocol,
+        AuthCommand: Command<<P as Protocol>::FrameType>,
+        HelloCommand: Command<<P as Protocol>::FrameType>,
+        <P as Protocol>::FrameType: From<CommandBuilder> + ToBytesVec,
+    {
+        client.send(ZRangeByLexCommand::new(self.key.clone(), min, max))?.wait()
+    }
+}
+
+/// ZADD or ZREM command of a single member
+struct IndexCommand {
+    builder: CommandBuilder,
+}
+
+impl<F> Command<F> for IndexCommand
+where
+    F: From<CommandBuilder> + ToBool,
+{
+    /// True if the index was modified
+    type Response = bool;
+
+    fn encode(&self) -> F {
+        self.builder.clone().into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bool().ok_or(ResponseTypeError {})
+    }
+}
@@ -0,0 +1,493 @@
+//! Abstraction of stream consumer group commands (XGROUP CREATE, XREADGROUP and XACK).
+//!
+//! For general information about consumer groups, see the [Redis documentation](<https://redis.io/docs/data-types/streams/#consumer-groups>).
+//!
+//! Consumer groups allow reliable at-least-once consumption of streams: Entries delivered to a consumer stay
+//! pending until they are acknowledged. After a restart, the consumer may read its pending entries again by
+//! using an explicit ID (e.g. `0`) instead of `>`.
+//!
+//! # Creating a group
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::stream_groups::XGroupCreateCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# let _ = client.send(embedded_redis::commands::builder::CommandBuilder::new("DEL").arg_static("sensor_stream").to_command()).unwrap().wait();
+//!
+//! // Group receives entries added afterward ($), stream is created if not existing
+//! let command = XGroupCreateCommand::new("sensor_stream", "workers", "$").mkstream();
+//! client.send(command).unwrap().wait().unwrap();
+//! ```
+//! An already existing group results in an error response with `BUSYGROUP` [error code](crate::network::CommandErrors::error_code).
+//!
+//! # Reading and acknowledging
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::commands::stream_groups::{XAckCommand, XGroupCreateCommand, XReadGroupCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# let _ = client.send(CommandBuilder::new("DEL").arg_static("group_stream").to_command()).unwrap().wait();
+//!# client.send(XGroupCreateCommand::new("group_stream", "workers", "$").mkstream()).unwrap().wait().unwrap();
+//!# client.send(CommandBuilder::new("XADD").arg_static("group_stream").arg_static("*")
+//!#     .arg_static("temp").arg_static("21").to_command()).unwrap().wait().unwrap();
+//!#
+//! // Reading entries never delivered to any consumer of the group
+//! let command = XReadGroupCommand::new("workers", "consumer_1", "group_stream").count(10);
+//! let entries = client.send(command).unwrap().wait().unwrap();
+//! assert_eq!(1, entries.len());
+//!
+//! let ack = XAckCommand::new("group_stream", "workers", entries[0].id.clone());
+//! assert_eq!(1, client.send(ack).unwrap().wait().unwrap());
+//! ```
+//! # Blocking
+//! Like other blocking commands, the server side BLOCK timeout should be shorter than the client timeout, as
+//! otherwise the response is not awaited.
+//!
+//! # Shorthand
+//! [Client](Client#method.xgroup_create), [Client](Client#method.xreadgroup) and [Client](Client#method.xack)
+//! provide shorthand methods.
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::set::ConfirmationResponse;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use alloc::vec;
+use alloc::vec::Vec;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+use redis_protocol::resp2::types::BytesFrame as Resp2Frame;
+use redis_protocol::resp3::types::BytesFrame as Resp3Frame;
+
+/// Abstraction of XGROUP CREATE command
+pub struct XGroupCreateCommand {
+    stream: Bytes,
+    group: Bytes,
+
+    /// Last delivered ID of the group. `$` for only new entries, `0` for the whole stream.
+    id: Bytes,
+
+    /// Creates the stream if not existing
+    mkstream: bool,
+}
+
+impl XGroupCreateCommand {
+    pub fn new<S, G, I>(stream: S, group: G, id: I) -> Self
+    where
+        Bytes: From<S>,
+        Bytes: From<G>,
+        Bytes: From<I>,
+    {
+        Self {
+            stream: stream.into(),
+            group: group.into(),
+            id: id.into(),
+            mkstream: false,
+        }
+    }
+
+    /// Creates an empty stream if not existing
+    pub fn mkstream(mut self) -> Self {
+        self.mkstream = true;
+        self
+    }
+}
+
+impl<F> Command<F> for XGroupCreateCommand
+where
+    F: From<CommandBuilder> + ToStringOption,
+{
+    type Response = ConfirmationResponse;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("XGROUP")
+            .arg_static("CREATE")
+            .arg(&self.stream)
+            .arg(&self.group)
+            .arg(&self.id)
+            .arg_static_option(self.mkstream.then_some("MKSTREAM"))
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.expect_simple_string("OK")
+    }
+}
+
+/// Stream entry delivered by XREADGROUP
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StreamEntry {
+    /// Entry ID
+    pub id: Bytes,
+
+    /// Field/value pairs of the entry. None in case the entry got deleted while pending.
+    pub fields: Option<Vec<(Bytes, Bytes)>>,
+}
+
+/// Trait for converting XREAD/XREADGROUP responses of RESP2/RESP3 frames
+pub trait ToStreamEntries {
+    /// Converts the frame to the list of entries of all contained streams.
+    /// A null response (e.g. BLOCK timeout) results in an empty list.
+    /// Returns None in case of protocol violation
+    fn to_stream_entries(&self) -> Option<Vec<StreamEntry>>;
+}
+
+impl ToStreamEntries for Resp2Frame {
+    fn to_stream_entries(&self) -> Option<Vec<StreamEntry>> {
+        let streams = match self {
+            Resp2Frame::Null => return Some(vec![]),
+            Resp2Frame::Array(streams) => streams,
+            _ => return None,
+        };
+
+        let mut entries = Vec::new();
+        for stream in streams {
+            match stream {
+                Resp2Frame::Array(pair) if pair.len() == 2 => entries.extend(resp2_entries(&pair[1])?),
+                _ => return None,
+            }
+        }
+
+        Some(entries)
+    }
+}
+
+/// Converts the entry list of a single stream
+fn resp2_entries(frame: &Resp2Frame) -> Option<Vec<StreamEntry>> {
+    let Resp2Frame::Array(entries) = frame else {
+        return None;
+    };
+
+    entries
+        .iter()
+        .map(|entry| {
+            let Resp2Frame::Array(entry) = entry else {
+                return None;
+            };
+
+            match &entry[..] {
+                [Resp2Frame::BulkString(id), Resp2Frame::Null] => Some(StreamEntry {
+                    id: id.clone(),
+                    fields: None,
+                }),
+                [Resp2Frame::BulkString(id), Resp2Frame::Array(fields)] if fields.len() % 2 == 0 => {
+                    let fields = fields
+                        .chunks(2)
+                        .map(|pair| match pair {
+                            [Resp2Frame::BulkString(field), Resp2Frame::BulkString(value)] => {
+                                Some((field.clone(), value.clone()))
+                            }
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+
+                    Some(StreamEntry {
+                        id: id.clone(),
+                        fields: Some(fields),
+                    })
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+impl ToStreamEntries for Resp3Frame {
+    fn to_stream_entries(&self) -> Option<Vec<StreamEntry>> {
+        let mut entries = Vec::new();
+
+        match self {
+            Resp3Frame::Null => {}
+            Resp3Frame::Map { data, attributes: _ } => {
+                for stream in data.values() {
+                    entries.extend(resp3_entries(stream)?);
+                }
+            }
+            Resp3Frame::Array { data, attributes: _ } => {
+                for stream in data {
+                    match stream {
+                        Resp3Frame::Array { data: pair, .. } if pair.len() == 2 => {
+                            entries.extend(resp3_entries(&pair[1])?)
+                        }
+                        _ => return None,
+                    }
+                }
+            }
+            _ => return None,
+        }
+
+        Some(entries)
+    }
+}
+
+/// Converts the entry list of a single stream
+fn resp3_entries(frame: &Resp3Frame) -> Option<Vec<StreamEntry>> {
+    let Resp3Frame::Array { data: entries, .. } = frame else {
+        return None;
+    };
+
+    entries
+        .iter()
+        .map(|entry| {
+            let Resp3Frame::Array { data: entry, .. } = entry else {
+                return None;
+            };
+
+            match &entry[..] {
+                [Resp3Frame::BlobString { data: id, .. }, Resp3Frame::Null] => Some(StreamEntry {
+                    id: id.clone(),
+                    fields: None,
+                }),
+                [Resp3Frame::BlobString { data: id, .. }, Resp3Frame::Array { data: fields, .. }]
+                    if fields.len() % 2 == 0 =>
+                {
+                    let fields = fields
+                        .chunks(2)
+                        .map(|pair| match pair {
+                            [Resp3Frame::BlobString { data: field, .. }, Resp3Frame::BlobString { data: value, .. }] => {
+                                Some((field.clone(), value.clone()))
+                            }
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+
+                    Some(StreamEntry {
+                        id: id.clone(),
+                        fields: Some(fields),
+                    })
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Abstraction of XREADGROUP command (single stream)
+pub struct XReadGroupCommand {
+    group: Bytes,
+    consumer: Bytes,
+    stream: Bytes,
+
+    /// `>` for new entries, otherwise pending entries of the consumer after the given ID are returned
+    id: Bytes,
+
+    /// Max. number of entries
+    count: Option<usize>,
+
+    /// Server side blocking timeout in milliseconds
+    block: Option<usize>,
+
+    /// Entries are acknowledged implicitly
+    noack: bool,
+}
+
+impl XReadGroupCommand {
+    /// Reads entries never delivered to any other consumer of the group (ID `>`)
+    pub fn new<G, C, S>(group: G, consumer: C, stream: S) -> Self
+    where
+        Bytes: From<G>,
+        Bytes: From<C>,
+        Bytes: From<S>,
+    {
+        Self {
+            group: group.into(),
+            consumer: consumer.into(),
+            stream: stream.into(),
+            id: Bytes::from_static(b">"),
+            count: None,
+            block: None,
+            noack: false,
+        }
+    }
+
+    /// Reads the pending entries of this consumer (delivered, but not acknowledged) after the given ID instead
+    /// of new entries. `0` returns all pending entries, e.g. for recovering after a restart.
+    pub fn pending<I>(mut self, id: I) -> Self
+    where
+        Bytes: From<I>,
+    {
+        self.id = id.into();
+        self
+    }
+
+    /// Limits the number of returned entries
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Blocks on server side up to the given milliseconds in case no entries are available. 0 blocks forever.
+    pub fn block(mut self, milliseconds: usize) -> Self {
+        self.block = Some(milliseconds);
+        self
+    }
+
+    /// Entries are not added to the pending list, so no acknowledgment is required (at-most-once delivery)
+    pub fn noack(mut self) -> Self {
+        self.noack = true;
+        self
+    }
+}
+
+impl<F> Command<F> for XReadGroupCommand
+where
+    F: From<CommandBuilder> + ToStreamEntries,
+{
+    /// Entries of the stream. Empty in case no entries are available.
+    type Response = Vec<StreamEntry>;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("XREADGROUP")
+            .arg_static("GROUP")
+            .arg(&self.group)
+            .arg(&self.consumer);
+
+        if let Some(count) = self.count {
+            builder = builder.arg_static("COUNT").arg_uint(count);
+        }
+
+        if let Some(block) = self.block {
+            builder = builder.arg_static("BLOCK").arg_uint(block);
+        }
+
+        builder
+            .arg_static_option(self.noack.then_some("NOACK"))
+            .arg_static("STREAMS")
+            .arg(&self.stream)
+            .arg(&self.id)
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_stream_entries().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Abstraction of XACK command
+pub struct XAckCommand {
+    stream: Bytes,
+    group: Bytes,
+    ids: Vec<Bytes>,
+}
+
+impl XAckCommand {
+    pub fn new<S, G, I>(stream: S, group: G, id: I) -> Self
+    where
+        Bytes: From<S>,
+        Bytes: From<G>,
+        Bytes: From<I>,
+    {
+        Self {
+            stream: stream.into(),
+            group: group.into(),
+            ids: vec![id.into()],
+        }
+    }
+
+    /// Acknowledges an additional entry
+    pub fn id<I>(mut self, id: I) -> Self
+    where
+        Bytes: From<I>,
+    {
+        self.ids.push(id.into());
+        self
+    }
+}
+
+impl<F> Command<F> for XAckCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Number of successfully acknowledged entries
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("XACK").arg(&self.stream).arg(&self.group);
+        for id in &self.ids {
+            builder = builder.arg(id);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [XGroupCreateCommand]. The stream is created if not existing.
+    pub fn xgroup_create<S, G, I>(
+        &'a self,
+        stream: S,
+        group: G,
+        id: I,
+    ) -> Result<Future<'a, N, C, P, XGroupCreateCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<S>,
+        Bytes: From<G>,
+        Bytes: From<I>,
+    {
+        self.send(XGroupCreateCommand::new(stream, group, id).mkstream())
+    }
+
+    /// Shorthand for [XReadGroupCommand], reading up to the given number of new entries non-blocking
+    pub fn xreadgroup<G, Co, S>(
+        &'a self,
+        group: G,
+        consumer: Co,
+        stream: S,
+        count: usize,
+    ) -> Result<Future<'a, N, C, P, XReadGroupCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStreamEntries,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<G>,
+        Bytes: From<Co>,
+        Bytes: From<S>,
+    {
+        self.send(XReadGroupCommand::new(group, consumer, stream).count(count))
+    }
+
+    /// Shorthand for [XAckCommand]
+    pub fn xack<S, G, I>(
+        &'a self,
+        stream: S,
+        group: G,
+        id: I,
+    ) -> Result<Future<'a, N, C, P, XAckCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<S>,
+        Bytes: From<G>,
+        Bytes: From<I>,
+    {
+        self.send(XAckCommand::new(stream, group, id))
+    }
+}
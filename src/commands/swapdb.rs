@@ -0,0 +1,99 @@
+//! Abstraction of SWAPDB command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/swapdb/>).
+//!
+//! # Basic usage
+//! Swaps the two logical databases, so that all connected clients immediately see the data of the other database.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::swapdb::SwapDbCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!
+//! let command = SwapDbCommand::new(0, 1);
+//! client.send(command).unwrap().wait().unwrap();
+//!# client.send(SwapDbCommand::new(0, 1)).unwrap().wait().unwrap();
+//! ```
+//! # Shorthand
+//! [Client](Client#method.swapdb) provides a shorthand method for this command.
+//! ```no_run
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! client.swapdb(0, 1).unwrap().wait().unwrap();
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::set::ConfirmationResponse;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of SWAPDB command
+pub struct SwapDbCommand {
+    /// Index of first database
+    first: usize,
+
+    /// Index of second database
+    second: usize,
+}
+
+impl SwapDbCommand {
+    pub fn new(first: usize, second: usize) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<F> Command<F> for SwapDbCommand
+where
+    F: From<CommandBuilder> + ToStringOption,
+{
+    type Response = ConfirmationResponse;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("SWAPDB").arg_uint(self.first).arg_uint(self.second).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.expect_simple_string("OK")
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [SwapDbCommand]
+    pub fn swapdb(
+        &'a self,
+        first: usize,
+        second: usize,
+    ) -> Result<Future<'a, N, C, P, SwapDbCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(SwapDbCommand::new(first, second))
+    }
+}
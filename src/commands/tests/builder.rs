@@ -1,4 +1,8 @@
-use crate::commands::builder::ToBytesMap;
+use crate::commands::builder::{
+    CommandBuilder, ToBigNumber, ToBool, ToBytesMap, ToBytesVec, ToDouble, ToNullableBytesVec,
+    ToNullableIntegerVec, ToStringOption,
+};
+use crate::network::protocol::{Protocol, Resp2, Resp3};
 use bytes::Bytes;
 use redis_protocol::resp2::types::BytesFrame as Resp2Frame;
 use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, FrameMap};
@@ -182,3 +186,344 @@ fn to_bytes_map_resp3_value_not_string() {
     };
     assert!(frame.to_map().is_none());
 }
+
+#[test]
+fn to_big_number_resp3_decoded_frame() {
+    let data = Bytes::from_static(b"(3492890328409238509324850943850943825024385\r\n");
+    let frame = Resp3 {}.decode(&data).unwrap().unwrap().0;
+
+    assert_eq!(
+        Bytes::from_static(b"3492890328409238509324850943850943825024385"),
+        frame.to_big_number().unwrap()
+    );
+}
+
+#[test]
+fn to_big_number_resp3_decoded_negative_frame() {
+    let data = Bytes::from_static(b"(-3492890328409238509324850943850943825024385\r\n");
+    let frame = Resp3 {}.decode(&data).unwrap().unwrap().0;
+
+    assert_eq!(
+        Bytes::from_static(b"-3492890328409238509324850943850943825024385"),
+        frame.to_big_number().unwrap()
+    );
+}
+
+#[test]
+fn to_big_number_resp3_other_type() {
+    let frame = Resp3Frame::BlobString {
+        data: "3492890328409238509324850943850943825024385".into(),
+        attributes: None,
+    };
+
+    assert!(frame.to_big_number().is_none());
+}
+
+#[test]
+fn to_big_number_resp2_bulk_string() {
+    let data = Bytes::from_static(b"$43\r\n3492890328409238509324850943850943825024385\r\n");
+    let frame = Resp2 {}.decode(&data).unwrap().unwrap().0;
+
+    assert_eq!(
+        Bytes::from_static(b"3492890328409238509324850943850943825024385"),
+        frame.to_big_number().unwrap()
+    );
+}
+
+#[test]
+fn to_big_number_resp2_no_number() {
+    assert!(Resp2Frame::BulkString("12a4".into()).to_big_number().is_none());
+    assert!(Resp2Frame::BulkString("-".into()).to_big_number().is_none());
+    assert!(Resp2Frame::BulkString("".into()).to_big_number().is_none());
+}
+
+#[test]
+fn to_big_number_resp2_other_type() {
+    assert!(Resp2Frame::Integer(12).to_big_number().is_none());
+}
+
+#[test]
+fn to_double_resp2_bulk_string() {
+    assert_eq!(Some(3.5), Resp2Frame::BulkString("3.5".into()).to_double());
+    assert_eq!(Some(-2.0), Resp2Frame::BulkString("-2".into()).to_double());
+    assert_eq!(
+        Some(f64::INFINITY),
+        Resp2Frame::BulkString("inf".into()).to_double()
+    );
+}
+
+#[test]
+fn to_double_resp2_no_number() {
+    assert!(Resp2Frame::BulkString("3.5a".into()).to_double().is_none());
+    assert!(Resp2Frame::BulkString("".into()).to_double().is_none());
+    assert!(Resp2Frame::Integer(3).to_double().is_none());
+}
+
+#[test]
+fn to_double_resp3_decoded_frame() {
+    let frame = Resp3 {}.decode(&Bytes::from_static(b",3.5\r\n")).unwrap().unwrap().0;
+    assert_eq!(Some(3.5), frame.to_double());
+
+    let frame = Resp3 {}.decode(&Bytes::from_static(b"$4\r\n-0.5\r\n")).unwrap().unwrap().0;
+    assert_eq!(Some(-0.5), frame.to_double());
+}
+
+#[test]
+fn to_double_resp3_other_type() {
+    let frame = Resp3Frame::Number {
+        data: 3,
+        attributes: None,
+    };
+
+    assert!(frame.to_double().is_none());
+}
+
+#[test]
+fn to_bytes_vec_resp2() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("first".into()),
+        Resp2Frame::SimpleString("second".into()),
+    ]);
+    let list = frame.to_bytes_vec().unwrap();
+
+    assert_eq!(2, list.len());
+    assert_eq!("first", list[0]);
+    assert_eq!("second", list[1]);
+}
+
+#[test]
+fn to_bytes_vec_resp2_invalid_element() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("first".into()),
+        Resp2Frame::Integer(1),
+    ]);
+    assert!(frame.to_bytes_vec().is_none());
+}
+
+#[test]
+fn to_bytes_vec_resp2_no_array() {
+    let frame = Resp2Frame::BulkString("first".into());
+    assert!(frame.to_bytes_vec().is_none());
+}
+
+#[test]
+fn to_bytes_vec_resp3() {
+    let frame = Resp3Frame::Array {
+        data: vec![
+            Resp3Frame::BlobString {
+                data: "first".into(),
+                attributes: None,
+            },
+            Resp3Frame::SimpleString {
+                data: "second".into(),
+                attributes: None,
+            },
+        ],
+        attributes: None,
+    };
+    let list = frame.to_bytes_vec().unwrap();
+
+    assert_eq!(2, list.len());
+    assert_eq!("first", list[0]);
+    assert_eq!("second", list[1]);
+}
+
+#[test]
+fn to_bytes_vec_resp3_invalid_element() {
+    let frame = Resp3Frame::Array {
+        data: vec![Resp3Frame::Number {
+            data: 1,
+            attributes: None,
+        }],
+        attributes: None,
+    };
+    assert!(frame.to_bytes_vec().is_none());
+}
+
+#[test]
+fn to_bytes_vec_resp3_set() {
+    let frame = Resp3 {}
+        .decode(&Bytes::from_static(b"~2\r\n$1\r\na\r\n+b\r\n"))
+        .unwrap()
+        .unwrap()
+        .0;
+    let mut list = frame.to_bytes_vec().unwrap();
+    list.sort();
+
+    assert_eq!(vec![Bytes::from_static(b"a"), Bytes::from_static(b"b")], list);
+}
+
+#[test]
+fn to_nullable_bytes_vec_resp2_element_null_not_collapsed() {
+    let frame = Resp2 {}
+        .decode(&Bytes::from_static(b"*3\r\n$1\r\nv\r\n$-1\r\n$1\r\nv\r\n"))
+        .unwrap()
+        .unwrap()
+        .0;
+    let list = frame.to_nullable_bytes_vec().unwrap();
+
+    assert_eq!(
+        vec![
+            Some(Bytes::from_static(b"v")),
+            None,
+            Some(Bytes::from_static(b"v"))
+        ],
+        list
+    );
+}
+
+#[test]
+fn to_nullable_bytes_vec_resp2_all_null() {
+    let frame = Resp2 {}
+        .decode(&Bytes::from_static(b"*2\r\n$-1\r\n$-1\r\n"))
+        .unwrap()
+        .unwrap()
+        .0;
+    assert_eq!(vec![None, None], frame.to_nullable_bytes_vec().unwrap());
+}
+
+#[test]
+fn to_nullable_bytes_vec_resp2_empty_array() {
+    let frame = Resp2 {}.decode(&Bytes::from_static(b"*0\r\n")).unwrap().unwrap().0;
+    assert!(frame.to_nullable_bytes_vec().unwrap().is_empty());
+}
+
+#[test]
+fn to_nullable_bytes_vec_resp2_top_level_null() {
+    let frame = Resp2 {}.decode(&Bytes::from_static(b"*-1\r\n")).unwrap().unwrap().0;
+    assert!(frame.to_nullable_bytes_vec().is_none());
+}
+
+#[test]
+fn to_nullable_bytes_vec_resp2_invalid_element() {
+    let frame = Resp2Frame::Array(vec![Resp2Frame::Null, Resp2Frame::Integer(1)]);
+    assert!(frame.to_nullable_bytes_vec().is_none());
+}
+
+#[test]
+fn to_nullable_bytes_vec_resp3_element_null_not_collapsed() {
+    let frame = Resp3 {}
+        .decode(&Bytes::from_static(b"*3\r\n$1\r\nv\r\n_\r\n$1\r\nv\r\n"))
+        .unwrap()
+        .unwrap()
+        .0;
+    let list = frame.to_nullable_bytes_vec().unwrap();
+
+    assert_eq!(
+        vec![
+            Some(Bytes::from_static(b"v")),
+            None,
+            Some(Bytes::from_static(b"v"))
+        ],
+        list
+    );
+}
+
+#[test]
+fn to_nullable_bytes_vec_resp3_top_level_null() {
+    assert!(Resp3Frame::Null.to_nullable_bytes_vec().is_none());
+}
+
+#[test]
+fn arg_int_negative() {
+    let builder = CommandBuilder::new("LRANGE").arg_int(-1);
+    assert_eq!("-1", builder.elements[1]);
+}
+
+#[test]
+fn expect_simple_string_resp2_matching() {
+    assert!(Resp2Frame::SimpleString("OK".into()).expect_simple_string("OK").is_ok());
+    assert!(Resp2Frame::SimpleString("QUEUED".into()).expect_simple_string("QUEUED").is_ok());
+}
+
+#[test]
+fn expect_simple_string_resp2_mismatching() {
+    assert!(Resp2Frame::SimpleString("QUEUED".into()).expect_simple_string("OK").is_err());
+    assert!(Resp2Frame::SimpleString("ok".into()).expect_simple_string("OK").is_err());
+}
+
+#[test]
+fn expect_simple_string_resp2_no_string() {
+    assert!(Resp2Frame::Array(vec![]).expect_simple_string("OK").is_err());
+}
+
+#[test]
+fn expect_simple_string_resp3_matching() {
+    let frame = Resp3Frame::SimpleString {
+        data: "PONG".into(),
+        attributes: None,
+    };
+    assert!(frame.expect_simple_string("PONG").is_ok());
+}
+
+#[test]
+fn expect_simple_string_resp3_mismatching() {
+    let frame = Resp3Frame::SimpleString {
+        data: "PONG".into(),
+        attributes: None,
+    };
+    assert!(frame.expect_simple_string("OK").is_err());
+}
+
+#[test]
+fn expect_simple_string_resp3_no_string() {
+    let frame = Resp3Frame::Number {
+        data: 1,
+        attributes: None,
+    };
+    assert!(frame.expect_simple_string("OK").is_err());
+}
+
+#[test]
+fn to_nullable_integer_vec_resp2() {
+    let frame = Resp2 {}
+        .decode(&Bytes::from_static(b"*3\r\n:1\r\n$-1\r\n:3\r\n"))
+        .unwrap()
+        .unwrap()
+        .0;
+    assert_eq!(
+        vec![Some(1), None, Some(3)],
+        frame.to_nullable_integer_vec().unwrap()
+    );
+}
+
+#[test]
+fn to_nullable_integer_vec_resp3() {
+    let frame = Resp3 {}.decode(&Bytes::from_static(b"*2\r\n_\r\n:5\r\n")).unwrap().unwrap().0;
+    assert_eq!(vec![None, Some(5)], frame.to_nullable_integer_vec().unwrap());
+}
+
+#[test]
+fn to_nullable_integer_vec_invalid_element() {
+    let frame = Resp2 {}.decode(&Bytes::from_static(b"*1\r\n$1\r\n1\r\n")).unwrap().unwrap().0;
+    assert!(frame.to_nullable_integer_vec().is_none());
+}
+
+#[test]
+fn to_bool_resp2_integer() {
+    assert_eq!(Some(true), Resp2Frame::Integer(1).to_bool());
+    assert_eq!(Some(false), Resp2Frame::Integer(0).to_bool());
+    assert_eq!(None, Resp2Frame::Integer(2).to_bool());
+    assert_eq!(None, Resp2Frame::SimpleString("OK".into()).to_bool());
+}
+
+#[test]
+fn to_bool_resp3_boolean_and_integer() {
+    let boolean = Resp3Frame::Boolean {
+        data: true,
+        attributes: None,
+    };
+    let integer = Resp3Frame::Number {
+        data: 0,
+        attributes: None,
+    };
+    let invalid = Resp3Frame::Number {
+        data: -1,
+        attributes: None,
+    };
+
+    assert_eq!(Some(true), boolean.to_bool());
+    assert_eq!(Some(false), integer.to_bool());
+    assert_eq!(None, invalid.to_bool());
+    assert_eq!(None, Resp3Frame::Null.to_bool());
+}
@@ -0,0 +1,77 @@
+use crate::commands::client_flags::{ClientNoEvictCommand, ClientNoTouchCommand};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_no_evict_encode_on() {
+    let frame: Resp2Frame = ClientNoEvictCommand::new(true).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("CLIENT", array[0].to_string().unwrap());
+        assert_eq!("NO-EVICT", array[1].to_string().unwrap());
+        assert_eq!("ON", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_no_evict_encode_off() {
+    let frame: Resp3Frame = ClientNoEvictCommand::new(false).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("CLIENT", data[0].to_string().unwrap());
+        assert_eq!("NO-EVICT", data[1].to_string().unwrap());
+        assert_eq!("OFF", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_no_touch_encode_on() {
+    let frame: Resp3Frame = ClientNoTouchCommand::new(true).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("CLIENT", data[0].to_string().unwrap());
+        assert_eq!("NO-TOUCH", data[1].to_string().unwrap());
+        assert_eq!("ON", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_no_touch_encode_off() {
+    let frame: Resp2Frame = ClientNoTouchCommand::new(false).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("CLIENT", array[0].to_string().unwrap());
+        assert_eq!("NO-TOUCH", array[1].to_string().unwrap());
+        assert_eq!("OFF", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_no_evict_eval_response() {
+    let command = ClientNoEvictCommand::new(true);
+
+    assert!(command.eval_response(Resp2Frame::SimpleString("OK".into())).is_ok());
+    assert!(command.eval_response(Resp2Frame::SimpleString("QUEUED".into())).is_err());
+}
+
+#[test]
+fn test_no_touch_eval_response() {
+    let command = ClientNoTouchCommand::new(false);
+
+    assert!(command
+        .eval_response(Resp3Frame::SimpleString {
+            data: "OK".into(),
+            attributes: None
+        })
+        .is_ok());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+}
@@ -0,0 +1,90 @@
+use crate::commands::client_list::ClientListCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _, VerbatimStringFormat};
+
+static CLIENT_LIST_REPLY: &str =
+    "id=3 addr=127.0.0.1:50188 laddr=127.0.0.1:6379 fd=8 name= db=0 cmd=client|list\n\
+    id=5 addr=127.0.0.1:50190 laddr=127.0.0.1:6379 fd=9 name=sensor db=2 cmd=get\n";
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = ClientListCommand::default().encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(2, array.len());
+        assert_eq!("CLIENT", array[0].to_string().unwrap());
+        assert_eq!("LIST", array[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = ClientListCommand::default().encode();
+
+    matches!(frame, Resp3Frame::Array { .. });
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("CLIENT", data[0].to_string().unwrap());
+        assert_eq!("LIST", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2_two_clients() {
+    let clients = ClientListCommand::default()
+        .eval_response(Resp2Frame::BulkString(CLIENT_LIST_REPLY.into()))
+        .unwrap();
+
+    assert_eq!(2, clients.len());
+
+    assert_eq!(3, clients[0].id().unwrap());
+    assert_eq!("127.0.0.1:50188", clients[0].addr().unwrap());
+    assert!(clients[0].name().is_none());
+    assert_eq!("0", clients[0].get_str("db").unwrap());
+    assert_eq!("client|list", clients[0].get_str("cmd").unwrap());
+
+    assert_eq!(5, clients[1].id().unwrap());
+    assert_eq!("127.0.0.1:50190", clients[1].addr().unwrap());
+    assert_eq!("sensor", clients[1].name().unwrap());
+    assert_eq!("2", clients[1].get_str("db").unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3_verbatim_string() {
+    let clients = ClientListCommand::default()
+        .eval_response(Resp3Frame::VerbatimString {
+            data: CLIENT_LIST_REPLY.into(),
+            format: VerbatimStringFormat::Text,
+            attributes: None,
+        })
+        .unwrap();
+
+    assert_eq!(2, clients.len());
+    assert_eq!(3, clients[0].id().unwrap());
+    assert_eq!("sensor", clients[1].name().unwrap());
+}
+
+#[test]
+fn test_eval_response_crlf_line_endings() {
+    let clients = ClientListCommand::default()
+        .eval_response(Resp2Frame::BulkString("id=1 addr=a\r\nid=2 addr=b\r\n".into()))
+        .unwrap();
+
+    assert_eq!(2, clients.len());
+    assert_eq!("a", clients[0].addr().unwrap());
+    assert_eq!("b", clients[1].addr().unwrap());
+}
+
+#[test]
+fn test_eval_response_invalid_property() {
+    let result = ClientListCommand::default().eval_response(Resp2Frame::BulkString("id=1 invalid\n".into()));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_eval_response_invalid_frame() {
+    let result = ClientListCommand::default().eval_response(Resp2Frame::Integer(1));
+    assert!(result.is_err());
+}
@@ -1,8 +1,9 @@
 use crate::commands::builder::CommandBuilder;
+use crate::commands::custom::{CommandTemplate, RedisValue};
 use crate::commands::Command;
 use bytes::Bytes;
 use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
-use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, FrameMap, Resp3Frame as _};
 
 #[test]
 fn test_encode_resp2() {
@@ -52,3 +53,220 @@ fn test_eval_response_resp3() {
     assert!(matches!(result, Resp3Frame::BlobString { .. }));
     assert_eq!("correct_response", result.to_string().unwrap());
 }
+
+#[test]
+fn test_eval_response_integer_resp2() {
+    let command = CommandBuilder::new("INCR").arg_static("key").to_command().expect_integer();
+
+    assert_eq!(5, command.eval_response(Resp2Frame::Integer(5)).unwrap());
+}
+
+#[test]
+fn test_eval_response_integer_resp3() {
+    let command = CommandBuilder::new("INCR").arg_static("key").to_command().expect_integer();
+    let frame = Resp3Frame::Number {
+        data: 5,
+        attributes: None,
+    };
+
+    assert_eq!(5, command.eval_response(frame).unwrap());
+}
+
+#[test]
+fn test_eval_response_integer_invalid_response() {
+    let command = CommandBuilder::new("INCR").arg_static("key").to_command().expect_integer();
+
+    assert!(command.eval_response(Resp2Frame::BulkString("5".into())).is_err());
+}
+
+#[test]
+fn test_eval_response_string_resp2() {
+    let command = CommandBuilder::new("ECHO").arg_static("Hello").to_command().expect_string();
+    let response = command.eval_response(Resp2Frame::BulkString("Hello".into())).unwrap();
+
+    assert_eq!(Bytes::from_static(b"Hello"), response.unwrap());
+}
+
+#[test]
+fn test_eval_response_string_resp3() {
+    let command = CommandBuilder::new("ECHO").arg_static("Hello").to_command().expect_string();
+    let frame = Resp3Frame::BlobString {
+        data: "Hello".into(),
+        attributes: None,
+    };
+
+    assert_eq!(
+        Bytes::from_static(b"Hello"),
+        command.eval_response(frame).unwrap().unwrap()
+    );
+}
+
+#[test]
+fn test_eval_response_string_null() {
+    let command = CommandBuilder::new("GET").arg_static("key").to_command().expect_string();
+
+    assert!(command.eval_response(Resp2Frame::Null).unwrap().is_none());
+}
+
+#[test]
+fn test_eval_response_string_invalid_response() {
+    let command = CommandBuilder::new("GET").arg_static("key").to_command().expect_string();
+
+    assert!(command.eval_response(Resp2Frame::Integer(1)).is_err());
+}
+
+#[test]
+fn test_eval_response_ok_resp2() {
+    let command = CommandBuilder::new("SET")
+        .arg_static("key")
+        .arg_static("value")
+        .to_command()
+        .expect_ok();
+
+    command.eval_response(Resp2Frame::SimpleString("OK".into())).unwrap();
+}
+
+#[test]
+fn test_eval_response_ok_resp3() {
+    let command = CommandBuilder::new("SET")
+        .arg_static("key")
+        .arg_static("value")
+        .to_command()
+        .expect_ok();
+    let frame = Resp3Frame::SimpleString {
+        data: "OK".into(),
+        attributes: None,
+    };
+
+    command.eval_response(frame).unwrap();
+}
+
+#[test]
+fn test_eval_response_ok_invalid_response() {
+    let command = CommandBuilder::new("SET")
+        .arg_static("key")
+        .arg_static("value")
+        .to_command()
+        .expect_ok();
+
+    assert!(command.eval_response(Resp2Frame::SimpleString("QUEUED".into())).is_err());
+}
+
+#[test]
+fn test_template_instantiate_different_args() {
+    let template = CommandTemplate::from_builder(
+        CommandBuilder::new("GEORADIUS").arg_static("sensors"),
+        |builder, (longitude, radius): (&'static str, usize)| builder.arg_static(longitude).arg_uint(radius),
+    );
+
+    let first: Resp2Frame = template.instantiate(("13.36", 10)).encode();
+    let second: Resp2Frame = template.instantiate(("15.08", 200)).encode();
+
+    assert_eq!(
+        Resp2Frame::Array(vec![
+            Resp2Frame::BulkString("GEORADIUS".into()),
+            Resp2Frame::BulkString("sensors".into()),
+            Resp2Frame::BulkString("13.36".into()),
+            Resp2Frame::BulkString("10".into()),
+        ]),
+        first
+    );
+    assert_eq!(
+        Resp2Frame::Array(vec![
+            Resp2Frame::BulkString("GEORADIUS".into()),
+            Resp2Frame::BulkString("sensors".into()),
+            Resp2Frame::BulkString("15.08".into()),
+            Resp2Frame::BulkString("200".into()),
+        ]),
+        second
+    );
+}
+
+#[test]
+fn test_template_typed_response() {
+    let template = CommandTemplate::new("INCRBY", |builder, (key, amount): (&'static str, i64)| {
+        builder.arg_static(key).arg_int(amount)
+    });
+    let command = template.instantiate(("counter", -3)).expect_integer();
+
+    let frame: Resp3Frame = command.encode();
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("INCRBY", data[0].to_string().unwrap());
+        assert_eq!("-3", data[2].to_string().unwrap());
+    }
+
+    assert_eq!(-3, command.eval_response(Resp2Frame::Integer(-3)).unwrap());
+}
+
+#[test]
+fn test_eval_response_value_resp2() {
+    let command = CommandBuilder::new("ECHO").to_command().expect_value();
+
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::SimpleString("OK".into()),
+        Resp2Frame::BulkString("data".into()),
+        Resp2Frame::Integer(5),
+        Resp2Frame::Null,
+    ]);
+
+    assert_eq!(
+        RedisValue::Array(vec![
+            RedisValue::Status("OK".into()),
+            RedisValue::Data("data".into()),
+            RedisValue::Int(5),
+            RedisValue::Nil,
+        ]),
+        command.eval_response(frame).unwrap()
+    );
+}
+
+#[test]
+fn test_eval_response_value_resp3() {
+    let command = CommandBuilder::new("ECHO").to_command().expect_value();
+
+    let frame = Resp3Frame::Map {
+        data: FrameMap::from([(
+            Resp3Frame::BlobString {
+                data: "key".into(),
+                attributes: None,
+            },
+            Resp3Frame::Set {
+                data: [Resp3Frame::Boolean {
+                    data: true,
+                    attributes: None,
+                }]
+                .into_iter()
+                .collect(),
+                attributes: None,
+            },
+        )]),
+        attributes: None,
+    };
+
+    assert_eq!(
+        RedisValue::Map(vec![(
+            RedisValue::Data("key".into()),
+            RedisValue::Array(vec![RedisValue::Bool(true)])
+        )]),
+        command.eval_response(frame).unwrap()
+    );
+    assert_eq!(
+        RedisValue::Double(1.5),
+        command
+            .eval_response(Resp3Frame::Double {
+                data: 1.5,
+                attributes: None
+            })
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_eval_response_value_invalid_response() {
+    let command = CommandBuilder::new("ECHO").to_command().expect_value();
+
+    assert!(command
+        .eval_response(Resp2Frame::Array(vec![Resp2Frame::Error("ERR".into())]))
+        .is_err());
+}
@@ -0,0 +1,46 @@
+use crate::commands::del::DelCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = DelCommand::new(["key1".into(), "key2".into()]).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("DEL", array[0].to_string().unwrap());
+        assert_eq!("key1", array[1].to_string().unwrap());
+        assert_eq!("key2", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = DelCommand::new(["key1".into()]).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("DEL", data[0].to_string().unwrap());
+        assert_eq!("key1", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response() {
+    let command = DelCommand::new(["key1".into(), "key2".into()]);
+
+    assert_eq!(2, command.eval_response(Resp2Frame::Integer(2)).unwrap());
+    assert_eq!(
+        0,
+        command
+            .eval_response(Resp3Frame::Number {
+                data: 0,
+                attributes: None
+            })
+            .unwrap()
+    );
+    assert!(command.eval_response(Resp2Frame::SimpleString("OK".into())).is_err());
+}
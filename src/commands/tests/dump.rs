@@ -0,0 +1,96 @@
+use crate::commands::dump::{DumpCommand, RestoreCommand};
+use crate::commands::helpers::CmdStr;
+use crate::commands::Command;
+use alloc::vec;
+use bytes::Bytes;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_dump_encode_resp2() {
+    let frame: Resp2Frame = DumpCommand::new("my_key").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(2, array.len());
+        assert_eq!("DUMP", array[0].to_string().unwrap());
+        assert_eq!("my_key", array[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_dump_encode_resp3() {
+    let frame: Resp3Frame = DumpCommand::new("my_key").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("DUMP", data[0].to_string().unwrap());
+        assert_eq!("my_key", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_dump_eval_response_payload() {
+    let command = DumpCommand::new("my_key");
+
+    let response = command.eval_response(CmdStr::new("payload").to_bulk()).unwrap();
+    assert_eq!(Some(Bytes::from_static(b"payload")), response);
+
+    let response = command.eval_response(CmdStr::new("payload").to_blob()).unwrap();
+    assert_eq!(Some(Bytes::from_static(b"payload")), response);
+}
+
+#[test]
+fn test_dump_eval_response_missing_key() {
+    let command = DumpCommand::new("my_key");
+
+    assert_eq!(None, command.eval_response(Resp2Frame::Null).unwrap());
+    assert_eq!(None, command.eval_response(Resp3Frame::Null).unwrap());
+}
+
+#[test]
+fn test_dump_eval_response_invalid_response() {
+    let response = DumpCommand::new("my_key").eval_response(Resp2Frame::Array(vec![]));
+
+    assert!(response.is_err());
+}
+
+#[test]
+fn test_restore_encode_resp2() {
+    let frame: Resp2Frame = RestoreCommand::new("my_key", 5000, Bytes::from_static(b"payload")).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("RESTORE", array[0].to_string().unwrap());
+        assert_eq!("my_key", array[1].to_string().unwrap());
+        assert_eq!("5000", array[2].to_string().unwrap());
+        assert_eq!("payload", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_restore_encode_resp3_replace() {
+    let frame: Resp3Frame = RestoreCommand::new("my_key", 0, Bytes::from_static(b"payload"))
+        .replace()
+        .encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(5, data.len());
+        assert_eq!("RESTORE", data[0].to_string().unwrap());
+        assert_eq!("my_key", data[1].to_string().unwrap());
+        assert_eq!("0", data[2].to_string().unwrap());
+        assert_eq!("payload", data[3].to_string().unwrap());
+        assert_eq!("REPLACE", data[4].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_restore_eval_response() {
+    let command = RestoreCommand::new("my_key", 0, Bytes::from_static(b"payload"));
+
+    command.eval_response(CmdStr::new("OK").to_simple()).unwrap();
+    assert!(command.eval_response(Resp2Frame::Integer(1)).is_err());
+}
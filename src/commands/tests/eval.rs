@@ -0,0 +1,60 @@
+use crate::commands::eval::EvalCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_no_keys_resp2() {
+    let frame: Resp2Frame = EvalCommand::new("return 1").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("EVAL", array[0].to_string().unwrap());
+        assert_eq!("return 1", array[1].to_string().unwrap());
+        assert_eq!("0", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_keys_and_args_resp3() {
+    let frame: Resp3Frame = EvalCommand::new("script")
+        .key("first_key")
+        .arg("first_arg")
+        .key("second_key")
+        .arg("second_arg")
+        .encode();
+
+    matches!(frame, Resp3Frame::Array { .. });
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(7, data.len());
+        assert_eq!("EVAL", data[0].to_string().unwrap());
+        assert_eq!("script", data[1].to_string().unwrap());
+        assert_eq!("2", data[2].to_string().unwrap());
+        assert_eq!("first_key", data[3].to_string().unwrap());
+        assert_eq!("second_key", data[4].to_string().unwrap());
+        assert_eq!("first_arg", data[5].to_string().unwrap());
+        assert_eq!("second_arg", data[6].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_to_command_typed_response() {
+    let command = EvalCommand::new("return 5").to_command().expect_integer();
+
+    let frame: Resp2Frame = command.encode();
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!("EVAL", array[0].to_string().unwrap());
+        assert_eq!("return 5", array[1].to_string().unwrap());
+    }
+
+    assert_eq!(5, command.eval_response(Resp2Frame::Integer(5)).unwrap());
+}
+
+#[test]
+fn test_eval_response_raw() {
+    let command = EvalCommand::new("return 'test'");
+    let response = command.eval_response(Resp2Frame::BulkString("test".into())).unwrap();
+
+    assert_eq!(Resp2Frame::BulkString("test".into()), response);
+}
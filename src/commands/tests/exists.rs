@@ -0,0 +1,50 @@
+use crate::commands::exists::ExistsCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = ExistsCommand::new(["first".into(), "second".into()]).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("EXISTS", array[0].to_string().unwrap());
+        assert_eq!("first", array[1].to_string().unwrap());
+        assert_eq!("second", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = ExistsCommand::new(["first".into()]).encode();
+
+    matches!(frame, Resp3Frame::Array { .. });
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("EXISTS", data[0].to_string().unwrap());
+        assert_eq!("first", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2() {
+    let response = ExistsCommand::new(["first".into()]).eval_response(Resp2Frame::Integer(1));
+    assert_eq!(1, response.unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3() {
+    let response = ExistsCommand::new(["first".into()]).eval_response(Resp3Frame::Number {
+        data: 0,
+        attributes: None,
+    });
+    assert_eq!(0, response.unwrap());
+}
+
+#[test]
+fn test_eval_response_invalid() {
+    let response = ExistsCommand::new(["first".into()]).eval_response(Resp2Frame::BulkString("1".into()));
+    assert!(response.is_err());
+}
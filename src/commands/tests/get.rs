@@ -1,6 +1,7 @@
 use crate::commands::get::{GetCommand, GetResponse};
 use crate::commands::helpers::CmdStr;
-use crate::commands::Command;
+use crate::commands::{Command, RawBytes};
+use crate::network::protocol::{Protocol, Resp2, Resp3};
 use alloc::string::ToString;
 use alloc::vec;
 use bytes::Bytes;
@@ -63,6 +64,30 @@ fn test_eval_response_resp3_key_missing() {
     assert!(response.is_none());
 }
 
+#[test]
+fn test_eval_response_resp2_empty_string_not_null() {
+    let empty = Resp2 {}.decode(&Bytes::from_static(b"$0\r\n\r\n")).unwrap().unwrap().0;
+    let null = Resp2 {}.decode(&Bytes::from_static(b"$-1\r\n")).unwrap().unwrap().0;
+
+    let response = GetCommand::new("test_key").eval_response(empty).unwrap().unwrap();
+    assert!(response.bytes().is_empty());
+    assert_eq!("", response.as_str().unwrap());
+
+    assert!(GetCommand::new("test_key").eval_response(null).unwrap().is_none());
+}
+
+#[test]
+fn test_eval_response_resp3_empty_string_not_null() {
+    let empty = Resp3 {}.decode(&Bytes::from_static(b"$0\r\n\r\n")).unwrap().unwrap().0;
+    let null = Resp3 {}.decode(&Bytes::from_static(b"_\r\n")).unwrap().unwrap().0;
+
+    let response = GetCommand::new("test_key").eval_response(empty).unwrap().unwrap();
+    assert!(response.bytes().is_empty());
+    assert_eq!("", response.as_str().unwrap());
+
+    assert!(GetCommand::new("test_key").eval_response(null).unwrap().is_none());
+}
+
 #[test]
 fn test_eval_response_resp2_invalid_response() {
     let response = GetCommand::new("test_key").eval_response(Resp2Frame::Array(vec![]));
@@ -91,6 +116,16 @@ fn test_response_to_bytes() {
     );
 }
 
+#[test]
+fn test_response_raw_bytes() {
+    let response = GetCommand::new("test_key")
+        .eval_response(CmdStr::new("test response").to_bulk())
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(&Bytes::from_static(b"test response"), response.bytes());
+}
+
 #[test]
 fn test_response_as_string_success() {
     let inner = Bytes::from_static("test response".as_bytes());
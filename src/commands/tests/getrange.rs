@@ -0,0 +1,69 @@
+use crate::commands::getrange::GetRangeCommand;
+use crate::commands::helpers::CmdStr;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2_negative_range() {
+    let frame: Resp2Frame = GetRangeCommand::new("key", -3, -1).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("GETRANGE", array[0].to_string().unwrap());
+        assert_eq!("key", array[1].to_string().unwrap());
+        assert_eq!("-3", array[2].to_string().unwrap());
+        assert_eq!("-1", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3_positive_range() {
+    let frame: Resp3Frame = GetRangeCommand::new("key", 0, 10).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("GETRANGE", data[0].to_string().unwrap());
+        assert_eq!("key", data[1].to_string().unwrap());
+        assert_eq!("0", data[2].to_string().unwrap());
+        assert_eq!("10", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2() {
+    let response = GetRangeCommand::new("key", 0, 2)
+        .eval_response(CmdStr::new("abc").to_bulk())
+        .unwrap();
+
+    assert_eq!("abc", response.as_str().unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3() {
+    let response = GetRangeCommand::new("key", 0, 2)
+        .eval_response(CmdStr::new("abc").to_blob())
+        .unwrap();
+
+    assert_eq!("abc", response.as_str().unwrap());
+}
+
+#[test]
+fn test_eval_response_empty_string() {
+    let response = GetRangeCommand::new("key", 5, 10)
+        .eval_response(Resp2Frame::BulkString("".into()))
+        .unwrap();
+
+    assert!(response.to_bytes().is_empty());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = GetRangeCommand::new("key", 0, 2);
+
+    assert!(command.eval_response(Resp2Frame::Null).is_err());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+    assert!(command.eval_response(Resp2Frame::Integer(1)).is_err());
+}
@@ -0,0 +1,71 @@
+use crate::commands::hdel::HashDeleteCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_single_field_resp2() {
+    let frame: Resp2Frame = HashDeleteCommand::new("my_hash", "color").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("HDEL", array[0].to_string().unwrap());
+        assert_eq!("my_hash", array[1].to_string().unwrap());
+        assert_eq!("color", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_multiple_fields_resp2() {
+    let frame: Resp2Frame =
+        HashDeleteCommand::multiple("my_hash".into(), ["field1".into(), "field2".into()]).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("HDEL", array[0].to_string().unwrap());
+        assert_eq!("my_hash", array[1].to_string().unwrap());
+        assert_eq!("field1", array[2].to_string().unwrap());
+        assert_eq!("field2", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_multiple_fields_resp3() {
+    let frame: Resp3Frame =
+        HashDeleteCommand::multiple("my_hash".into(), ["field1".into(), "field2".into()]).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("HDEL", data[0].to_string().unwrap());
+        assert_eq!("my_hash", data[1].to_string().unwrap());
+        assert_eq!("field1", data[2].to_string().unwrap());
+        assert_eq!("field2", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response() {
+    let command = HashDeleteCommand::new("my_hash", "color");
+
+    assert_eq!(1, command.eval_response(Resp2Frame::Integer(1)).unwrap());
+    assert_eq!(
+        0,
+        command
+            .eval_response(Resp3Frame::Number {
+                data: 0,
+                attributes: None
+            })
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = HashDeleteCommand::new("my_hash", "color");
+
+    assert!(command.eval_response(Resp2Frame::SimpleString("OK".into())).is_err());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+}
@@ -1,6 +1,7 @@
 use crate::commands::helpers::CmdStr;
 use crate::commands::hget::HashGetCommand;
-use crate::commands::Command;
+use crate::commands::{Command, RawBytes};
+use bytes::Bytes;
 use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
 use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
 
@@ -48,6 +49,16 @@ fn test_eval_response_resp3_key_existing() {
     assert_eq!("correct", response.unwrap().as_str().unwrap());
 }
 
+#[test]
+fn test_eval_response_raw_bytes() {
+    let response = HashGetCommand::new("my_hash", "color")
+        .eval_response(CmdStr::new("blue").to_blob())
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(&Bytes::from_static(b"blue"), response.bytes());
+}
+
 #[test]
 fn test_eval_response_resp2_key_missing() {
     let response = HashGetCommand::new("my_hash", "color").eval_response(Resp2Frame::Null).unwrap();
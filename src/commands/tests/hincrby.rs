@@ -0,0 +1,56 @@
+use crate::commands::hincrby::HashIncrByCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = HashIncrByCommand::new("my_hash", "counter", 5).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("HINCRBY", array[0].to_string().unwrap());
+        assert_eq!("my_hash", array[1].to_string().unwrap());
+        assert_eq!("counter", array[2].to_string().unwrap());
+        assert_eq!("5", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_negative_increment_resp3() {
+    let frame: Resp3Frame = HashIncrByCommand::new("my_hash", "counter", -12).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("HINCRBY", data[0].to_string().unwrap());
+        assert_eq!("my_hash", data[1].to_string().unwrap());
+        assert_eq!("counter", data[2].to_string().unwrap());
+        assert_eq!("-12", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response() {
+    let command = HashIncrByCommand::new("my_hash", "counter", -12);
+
+    assert_eq!(-2, command.eval_response(Resp2Frame::Integer(-2)).unwrap());
+    assert_eq!(
+        8,
+        command
+            .eval_response(Resp3Frame::Number {
+                data: 8,
+                attributes: None
+            })
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = HashIncrByCommand::new("my_hash", "counter", 1);
+
+    assert!(command.eval_response(Resp2Frame::BulkString("1".into())).is_err());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+}
@@ -0,0 +1,81 @@
+use crate::commands::hkeys::{HashKeysCommand, HashValsCommand};
+use crate::commands::Command;
+use bytes::Bytes;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_keys_encode_resp2() {
+    let frame: Resp2Frame = HashKeysCommand::new("my_hash").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(2, array.len());
+        assert_eq!("HKEYS", array[0].to_string().unwrap());
+        assert_eq!("my_hash", array[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_vals_encode_resp3() {
+    let frame: Resp3Frame = HashValsCommand::new("my_hash").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("HVALS", data[0].to_string().unwrap());
+        assert_eq!("my_hash", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_keys_eval_response_resp2() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("color".into()),
+        Resp2Frame::BulkString("material".into()),
+    ]);
+
+    let response = HashKeysCommand::new("my_hash").eval_response(frame).unwrap();
+    assert_eq!(
+        vec![Bytes::from_static(b"color"), Bytes::from_static(b"material")],
+        response
+    );
+}
+
+#[test]
+fn test_vals_eval_response_resp3() {
+    let frame = Resp3Frame::Array {
+        data: vec![Resp3Frame::BlobString {
+            data: "green".into(),
+            attributes: None,
+        }],
+        attributes: None,
+    };
+
+    let response = HashValsCommand::new("my_hash").eval_response(frame).unwrap();
+    assert_eq!(vec![Bytes::from_static(b"green")], response);
+}
+
+#[test]
+fn test_eval_response_missing_hash() {
+    let response = HashKeysCommand::new("my_hash")
+        .eval_response(Resp2Frame::Array(vec![]))
+        .unwrap();
+    assert!(response.is_empty());
+
+    let response = HashValsCommand::new("my_hash")
+        .eval_response(Resp3Frame::Array {
+            data: vec![],
+            attributes: None,
+        })
+        .unwrap();
+    assert!(response.is_empty());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = HashKeysCommand::new("my_hash");
+
+    assert!(command.eval_response(Resp2Frame::Null).is_err());
+    assert!(command.eval_response(Resp2Frame::Array(vec![Resp2Frame::Null])).is_err());
+}
@@ -0,0 +1,53 @@
+use crate::commands::hlen::HashLenCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = HashLenCommand::new("my_hash").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(2, array.len());
+        assert_eq!("HLEN", array[0].to_string().unwrap());
+        assert_eq!("my_hash", array[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = HashLenCommand::new("my_hash").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("HLEN", data[0].to_string().unwrap());
+        assert_eq!("my_hash", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2() {
+    let response = HashLenCommand::new("my_hash").eval_response(Resp2Frame::Integer(3));
+
+    assert_eq!(3, response.unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3_missing_key() {
+    let response = HashLenCommand::new("my_hash").eval_response(Resp3Frame::Number {
+        data: 0,
+        attributes: None,
+    });
+
+    assert_eq!(0, response.unwrap());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = HashLenCommand::new("my_hash");
+
+    assert!(command.eval_response(Resp2Frame::BulkString("3".into())).is_err());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+}
@@ -0,0 +1,90 @@
+use crate::commands::hmget::HashMultiGetCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = HashMultiGetCommand::new("my_hash", ["color".into(), "size".into()]).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("HMGET", array[0].to_string().unwrap());
+        assert_eq!("my_hash", array[1].to_string().unwrap());
+        assert_eq!("color", array[2].to_string().unwrap());
+        assert_eq!("size", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = HashMultiGetCommand::new("my_hash", ["color".into()]).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("HMGET", data[0].to_string().unwrap());
+        assert_eq!("my_hash", data[1].to_string().unwrap());
+        assert_eq!("color", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2_mixed() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("green".into()),
+        Resp2Frame::Null,
+        Resp2Frame::BulkString("stone".into()),
+    ]);
+    let response = HashMultiGetCommand::new("my_hash", ["color".into(), "size".into(), "material".into()])
+        .eval_response(frame)
+        .unwrap();
+
+    assert_eq!(3, response.len());
+    assert_eq!("green", response[0].as_ref().unwrap().as_str().unwrap());
+    assert!(response[1].is_none());
+    assert_eq!("stone", response[2].as_ref().unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3_mixed() {
+    let frame = Resp3Frame::Array {
+        data: vec![
+            Resp3Frame::BlobString {
+                data: "green".into(),
+                attributes: None,
+            },
+            Resp3Frame::Null,
+            Resp3Frame::BlobString {
+                data: "stone".into(),
+                attributes: None,
+            },
+        ],
+        attributes: None,
+    };
+    let response = HashMultiGetCommand::new("my_hash", ["color".into(), "size".into(), "material".into()])
+        .eval_response(frame)
+        .unwrap();
+
+    assert_eq!(3, response.len());
+    assert_eq!("green", response[0].as_ref().unwrap().as_str().unwrap());
+    assert!(response[1].is_none());
+    assert_eq!("stone", response[2].as_ref().unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_eval_response_length_mismatch() {
+    let frame = Resp2Frame::Array(vec![Resp2Frame::Null]);
+
+    assert!(HashMultiGetCommand::new("my_hash", ["a".into(), "b".into()])
+        .eval_response(frame)
+        .is_err());
+}
+
+#[test]
+fn test_eval_response_invalid() {
+    assert!(HashMultiGetCommand::new("my_hash", ["a".into()])
+        .eval_response(Resp2Frame::Integer(1))
+        .is_err());
+}
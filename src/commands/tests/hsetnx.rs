@@ -0,0 +1,66 @@
+use crate::commands::hsetnx::HashSetNxCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = HashSetNxCommand::new("my_hash", "color", "green").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("HSETNX", array[0].to_string().unwrap());
+        assert_eq!("my_hash", array[1].to_string().unwrap());
+        assert_eq!("color", array[2].to_string().unwrap());
+        assert_eq!("green", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = HashSetNxCommand::new("my_hash", "color", "green").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("HSETNX", data[0].to_string().unwrap());
+        assert_eq!("my_hash", data[1].to_string().unwrap());
+        assert_eq!("color", data[2].to_string().unwrap());
+        assert_eq!("green", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_inserted() {
+    let command = HashSetNxCommand::new("my_hash", "color", "green");
+
+    assert!(command.eval_response(Resp2Frame::Integer(1)).unwrap());
+    assert!(command
+        .eval_response(Resp3Frame::Number {
+            data: 1,
+            attributes: None
+        })
+        .unwrap());
+}
+
+#[test]
+fn test_eval_response_not_inserted() {
+    let command = HashSetNxCommand::new("my_hash", "color", "green");
+
+    assert!(!command.eval_response(Resp2Frame::Integer(0)).unwrap());
+    assert!(!command
+        .eval_response(Resp3Frame::Number {
+            data: 0,
+            attributes: None
+        })
+        .unwrap());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = HashSetNxCommand::new("my_hash", "color", "green");
+
+    assert!(command.eval_response(Resp2Frame::Integer(2)).is_err());
+    assert!(command.eval_response(Resp2Frame::SimpleString("OK".into())).is_err());
+}
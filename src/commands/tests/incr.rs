@@ -0,0 +1,58 @@
+use crate::commands::incr::{DecrCommand, IncrCommand};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_incr_encode_resp2() {
+    let frame: Resp2Frame = IncrCommand::new("key").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(2, array.len());
+        assert_eq!("INCR", array[0].to_string().unwrap());
+        assert_eq!("key", array[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_decr_encode_resp3() {
+    let frame: Resp3Frame = DecrCommand::new("key").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("DECR", data[0].to_string().unwrap());
+        assert_eq!("key", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2_success() {
+    assert_eq!(
+        5,
+        IncrCommand::new("key").eval_response(Resp2Frame::Integer(5)).unwrap()
+    );
+    assert_eq!(
+        -1,
+        DecrCommand::new("key").eval_response(Resp2Frame::Integer(-1)).unwrap()
+    );
+}
+
+#[test]
+fn test_eval_response_resp3_success() {
+    let frame = Resp3Frame::Number {
+        data: 7,
+        attributes: None,
+    };
+
+    assert_eq!(7, IncrCommand::new("key").eval_response(frame).unwrap());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    assert!(IncrCommand::new("key")
+        .eval_response(Resp2Frame::BulkString("3".into()))
+        .is_err());
+    assert!(DecrCommand::new("key").eval_response(Resp2Frame::Null).is_err());
+}
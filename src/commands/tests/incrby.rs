@@ -0,0 +1,66 @@
+use crate::commands::incrby::{DecrByCommand, IncrByCommand};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_incrby_encode_resp2() {
+    let frame: Resp2Frame = IncrByCommand::new("key", 5).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("INCRBY", array[0].to_string().unwrap());
+        assert_eq!("key", array[1].to_string().unwrap());
+        assert_eq!("5", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_incrby_encode_negative_resp3() {
+    let frame: Resp3Frame = IncrByCommand::new("key", -5).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("INCRBY", data[0].to_string().unwrap());
+        assert_eq!("-5", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_decrby_encode_resp2() {
+    let frame: Resp2Frame = DecrByCommand::new("key", i64::MIN).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("DECRBY", array[0].to_string().unwrap());
+        assert_eq!("-9223372036854775808", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2_success() {
+    assert_eq!(
+        -3,
+        IncrByCommand::new("key", -5).eval_response(Resp2Frame::Integer(-3)).unwrap()
+    );
+}
+
+#[test]
+fn test_eval_response_resp3_success() {
+    let frame = Resp3Frame::Number {
+        data: 12,
+        attributes: None,
+    };
+
+    assert_eq!(12, DecrByCommand::new("key", 3).eval_response(frame).unwrap());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let response = IncrByCommand::new("key", 1).eval_response(Resp2Frame::SimpleString("OK".into()));
+
+    assert!(response.is_err());
+}
@@ -0,0 +1,93 @@
+use crate::commands::lpop::ListPopCommand;
+use crate::commands::lpush::Side;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_single_resp2() {
+    let frame: Resp2Frame = ListPopCommand::new(Side::Left, "my_list").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(2, array.len());
+        assert_eq!("LPOP", array[0].to_string().unwrap());
+        assert_eq!("my_list", array[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_count_resp3() {
+    let frame: Resp3Frame = ListPopCommand::new(Side::Right, "my_list").count(3).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("RPOP", data[0].to_string().unwrap());
+        assert_eq!("my_list", data[1].to_string().unwrap());
+        assert_eq!("3", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_single_element() {
+    let command = ListPopCommand::new(Side::Left, "my_list");
+
+    let response = command.eval_response(Resp2Frame::BulkString("first".into())).unwrap();
+    assert_eq!("first", response.unwrap().as_str().unwrap());
+
+    let response = command
+        .eval_response(Resp3Frame::BlobString {
+            data: "first".into(),
+            attributes: None,
+        })
+        .unwrap();
+    assert_eq!("first", response.unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_eval_response_single_null() {
+    let command = ListPopCommand::new(Side::Left, "my_list");
+
+    assert!(command.eval_response(Resp2Frame::Null).unwrap().is_none());
+    assert!(command.eval_response(Resp3Frame::Null).unwrap().is_none());
+}
+
+#[test]
+fn test_eval_response_count_array() {
+    let command = ListPopCommand::new(Side::Left, "my_list").count(2);
+
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("first".into()),
+        Resp2Frame::BulkString("second".into()),
+    ]);
+    assert_eq!(vec!["first", "second"], command.eval_response(frame).unwrap());
+
+    let frame = Resp3Frame::Array {
+        data: vec![Resp3Frame::BlobString {
+            data: "first".into(),
+            attributes: None,
+        }],
+        attributes: None,
+    };
+    assert_eq!(vec!["first"], command.eval_response(frame).unwrap());
+}
+
+#[test]
+fn test_eval_response_count_null() {
+    let command = ListPopCommand::new(Side::Right, "my_list").count(2);
+
+    assert!(command.eval_response(Resp2Frame::Null).unwrap().is_empty());
+    assert!(command.eval_response(Resp3Frame::Null).unwrap().is_empty());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    assert!(ListPopCommand::new(Side::Left, "my_list")
+        .eval_response(Resp2Frame::Integer(1))
+        .is_err());
+    assert!(ListPopCommand::new(Side::Left, "my_list")
+        .count(2)
+        .eval_response(Resp2Frame::BulkString("first".into()))
+        .is_err());
+}
@@ -0,0 +1,63 @@
+use crate::commands::lpush::{ListPushCommand, Side};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_lpush_multiple_values_resp2() {
+    let frame: Resp2Frame = ListPushCommand::new(
+        Side::Left,
+        "my_list",
+        ["first".into(), "second".into(), "third".into()],
+    )
+    .encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(5, array.len());
+        assert_eq!("LPUSH", array[0].to_string().unwrap());
+        assert_eq!("my_list", array[1].to_string().unwrap());
+        assert_eq!("first", array[2].to_string().unwrap());
+        assert_eq!("second", array[3].to_string().unwrap());
+        assert_eq!("third", array[4].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_rpush_multiple_values_resp3() {
+    let frame: Resp3Frame =
+        ListPushCommand::new(Side::Right, "my_list", ["first".into(), "second".into()]).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("RPUSH", data[0].to_string().unwrap());
+        assert_eq!("my_list", data[1].to_string().unwrap());
+        assert_eq!("first", data[2].to_string().unwrap());
+        assert_eq!("second", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_length() {
+    let command = ListPushCommand::new(Side::Left, "my_list", ["first".into()]);
+
+    assert_eq!(3, command.eval_response(Resp2Frame::Integer(3)).unwrap());
+    assert_eq!(
+        1,
+        command
+            .eval_response(Resp3Frame::Number {
+                data: 1,
+                attributes: None
+            })
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = ListPushCommand::new(Side::Right, "my_list", ["first".into()]);
+
+    assert!(command.eval_response(Resp2Frame::SimpleString("OK".into())).is_err());
+    assert!(command.eval_response(Resp2Frame::Null).is_err());
+}
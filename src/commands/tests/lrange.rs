@@ -0,0 +1,69 @@
+use crate::commands::lrange::ListRangeCommand;
+use crate::commands::Command;
+use bytes::Bytes;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = ListRangeCommand::new("my_list", 0, -1).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("LRANGE", array[0].to_string().unwrap());
+        assert_eq!("my_list", array[1].to_string().unwrap());
+        assert_eq!("0", array[2].to_string().unwrap());
+        assert_eq!("-1", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = ListRangeCommand::new("my_list", -10, 5).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("LRANGE", data[0].to_string().unwrap());
+        assert_eq!("my_list", data[1].to_string().unwrap());
+        assert_eq!("-10", data[2].to_string().unwrap());
+        assert_eq!("5", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_elements() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("first".into()),
+        Resp2Frame::BulkString("second".into()),
+    ]);
+
+    let response = ListRangeCommand::new("my_list", 0, -1).eval_response(frame).unwrap();
+    assert_eq!(
+        vec![Bytes::from_static(b"first"), Bytes::from_static(b"second")],
+        response
+    );
+}
+
+#[test]
+fn test_eval_response_empty_range() {
+    let command = ListRangeCommand::new("my_list", 0, -1);
+
+    assert!(command.eval_response(Resp2Frame::Array(vec![])).unwrap().is_empty());
+    assert!(command
+        .eval_response(Resp3Frame::Array {
+            data: vec![],
+            attributes: None
+        })
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = ListRangeCommand::new("my_list", 0, -1);
+
+    assert!(command.eval_response(Resp2Frame::Integer(1)).is_err());
+    assert!(command.eval_response(Resp2Frame::BulkString("first".into())).is_err());
+}
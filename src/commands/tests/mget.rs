@@ -0,0 +1,70 @@
+use crate::commands::mget::MGetCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = MGetCommand::new(["first".into(), "second".into()]).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("MGET", array[0].to_string().unwrap());
+        assert_eq!("first", array[1].to_string().unwrap());
+        assert_eq!("second", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = MGetCommand::new(["first".into()]).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("MGET", data[0].to_string().unwrap());
+        assert_eq!("first", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2_mixed() {
+    let frame = Resp2Frame::Array(vec![Resp2Frame::BulkString("foo".into()), Resp2Frame::Null]);
+    let response = MGetCommand::new(["a".into(), "b".into()]).eval_response(frame).unwrap();
+
+    assert_eq!(2, response.len());
+    assert_eq!("foo", response[0].as_ref().unwrap().as_str().unwrap());
+    assert!(response[1].is_none());
+}
+
+#[test]
+fn test_eval_response_resp3_mixed() {
+    let frame = Resp3Frame::Array {
+        data: vec![
+            Resp3Frame::BlobString {
+                data: "foo".into(),
+                attributes: None,
+            },
+            Resp3Frame::Null,
+        ],
+        attributes: None,
+    };
+    let response = MGetCommand::new(["a".into(), "b".into()]).eval_response(frame).unwrap();
+
+    assert_eq!(2, response.len());
+    assert_eq!("foo", response[0].as_ref().unwrap().as_str().unwrap());
+    assert!(response[1].is_none());
+}
+
+#[test]
+fn test_eval_response_length_mismatch() {
+    let frame = Resp2Frame::Array(vec![Resp2Frame::Null]);
+
+    assert!(MGetCommand::new(["a".into(), "b".into()]).eval_response(frame).is_err());
+}
+
+#[test]
+fn test_eval_response_invalid() {
+    assert!(MGetCommand::new(["a".into()]).eval_response(Resp2Frame::Null).is_err());
+}
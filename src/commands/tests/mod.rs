@@ -1,12 +1,57 @@
+mod append;
 mod auth;
 mod bgsave;
 mod builder;
+mod client_flags;
+mod client_list;
 mod custom;
+mod del;
+mod dump;
+mod eval;
+mod exists;
+mod expire;
 mod get;
+mod getdel;
+mod getrange;
+mod hdel;
 pub(crate) mod hello;
+mod hexists;
 mod hget;
 mod hgetall;
+mod hincrby;
+mod hkeys;
+mod hlen;
+mod hmget;
 mod hset;
+mod hsetnx;
+mod incr;
+mod incrby;
+mod llen;
+mod lpop;
+mod lpush;
+mod lrange;
+mod mget;
+mod mset;
+mod multi_reply;
+mod persist;
+mod pexpire;
 mod ping;
 mod publish;
+mod punsubscribe;
+mod rename;
+mod scan;
 mod set;
+mod set_store;
+mod setrange;
+mod setx;
+mod sismember;
+mod smembers;
+mod stream_groups;
+mod strlen;
+mod swapdb;
+mod ttl;
+mod waitaof;
+mod xadd;
+mod zadd;
+mod zrange_ext;
+mod zscore;
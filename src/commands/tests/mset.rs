@@ -0,0 +1,63 @@
+use crate::commands::mset::MSetCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame =
+        MSetCommand::new([("k1".into(), "v1".into()), ("k2".into(), "v2".into())]).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(5, array.len());
+        assert_eq!("MSET", array[0].to_string().unwrap());
+        assert_eq!("k1", array[1].to_string().unwrap());
+        assert_eq!("v1", array[2].to_string().unwrap());
+        assert_eq!("k2", array[3].to_string().unwrap());
+        assert_eq!("v2", array[4].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame =
+        MSetCommand::new([("k1".into(), "v1".into()), ("k2".into(), "v2".into())]).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(5, data.len());
+        assert_eq!("MSET", data[0].to_string().unwrap());
+        assert_eq!("k1", data[1].to_string().unwrap());
+        assert_eq!("v1", data[2].to_string().unwrap());
+        assert_eq!("k2", data[3].to_string().unwrap());
+        assert_eq!("v2", data[4].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_ok() {
+    let command = MSetCommand::new([("k1".into(), "v1".into())]);
+
+    assert!(command.eval_response(Resp2Frame::SimpleString("OK".into())).is_ok());
+    assert!(command
+        .eval_response(Resp3Frame::SimpleString {
+            data: "OK".into(),
+            attributes: None
+        })
+        .is_ok());
+}
+
+#[test]
+fn test_eval_response_not_ok() {
+    let command = MSetCommand::new([("k1".into(), "v1".into())]);
+
+    assert!(command.eval_response(Resp2Frame::SimpleString("QUEUED".into())).is_err());
+    assert!(command.eval_response(Resp2Frame::Integer(1)).is_err());
+    assert!(command
+        .eval_response(Resp3Frame::SimpleString {
+            data: "FAIL".into(),
+            attributes: None
+        })
+        .is_err());
+}
@@ -0,0 +1,53 @@
+use crate::commands::multi_reply::{parse_properties, MultiReplyCommand};
+use bytes::Bytes;
+
+struct LineCommand {}
+
+impl MultiReplyCommand for LineCommand {
+    type Entry = Bytes;
+
+    fn parse_entry(&self, line: Bytes) -> Option<Self::Entry> {
+        if line.as_ref() == b"invalid" {
+            return None;
+        }
+
+        Some(line)
+    }
+}
+
+#[test]
+fn test_parse_reply_splits_lines() {
+    let entries = LineCommand {}.parse_reply(&"first\nsecond\r\n\nthird".into()).unwrap();
+    assert_eq!(vec!["first", "second", "third"], entries);
+}
+
+#[test]
+fn test_parse_reply_empty() {
+    assert!(LineCommand {}.parse_reply(&Bytes::new()).unwrap().is_empty());
+}
+
+#[test]
+fn test_parse_reply_invalid_line() {
+    assert!(LineCommand {}.parse_reply(&"first\ninvalid\n".into()).is_none());
+}
+
+#[test]
+fn test_parse_properties() {
+    let map = parse_properties(&"id=1  name= flags=N".into()).unwrap();
+
+    assert_eq!(3, map.len());
+    assert_eq!("1", map.get(&Bytes::from_static(b"id")).unwrap());
+    assert_eq!("", map.get(&Bytes::from_static(b"name")).unwrap());
+    assert_eq!("N", map.get(&Bytes::from_static(b"flags")).unwrap());
+}
+
+#[test]
+fn test_parse_properties_value_containing_separator() {
+    let map = parse_properties(&"cmd=a=b".into()).unwrap();
+    assert_eq!("a=b", map.get(&Bytes::from_static(b"cmd")).unwrap());
+}
+
+#[test]
+fn test_parse_properties_missing_separator() {
+    assert!(parse_properties(&"id=1 invalid".into()).is_none());
+}
@@ -0,0 +1,59 @@
+use crate::commands::persist::PersistCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = PersistCommand::new("my_key").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(2, array.len());
+        assert_eq!("PERSIST", array[0].to_string().unwrap());
+        assert_eq!("my_key", array[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = PersistCommand::new("my_key").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("PERSIST", data[0].to_string().unwrap());
+        assert_eq!("my_key", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2_timeout_removed() {
+    let response = PersistCommand::new("my_key").eval_response(Resp2Frame::Integer(1));
+
+    assert!(response.unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3_no_timeout() {
+    let response = PersistCommand::new("my_key").eval_response(Resp3Frame::Number {
+        data: 0,
+        attributes: None,
+    });
+
+    assert!(!response.unwrap());
+}
+
+#[test]
+fn test_eval_response_invalid_integer() {
+    let response = PersistCommand::new("my_key").eval_response(Resp2Frame::Integer(-1));
+
+    assert!(response.is_err());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let response = PersistCommand::new("my_key").eval_response(Resp2Frame::SimpleString("OK".into()));
+
+    assert!(response.is_err());
+}
@@ -0,0 +1,111 @@
+use crate::commands::pexpire::{ExpireCond, PExpireCommand};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = PExpireCommand::new("my_key", 1500).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("PEXPIRE", array[0].to_string().unwrap());
+        assert_eq!("my_key", array[1].to_string().unwrap());
+        assert_eq!("1500", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = PExpireCommand::new("my_key", 1500).encode();
+
+    matches!(frame, Resp3Frame::Array { .. });
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("PEXPIRE", data[0].to_string().unwrap());
+        assert_eq!("my_key", data[1].to_string().unwrap());
+        assert_eq!("1500", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_condition_no_expiry() {
+    assert_condition(ExpireCond::IfNoExpiry, "NX");
+}
+
+#[test]
+fn test_encode_condition_has_expiry() {
+    assert_condition(ExpireCond::IfHasExpiry, "XX");
+}
+
+#[test]
+fn test_encode_condition_greater() {
+    assert_condition(ExpireCond::IfGreater, "GT");
+}
+
+#[test]
+fn test_encode_condition_less() {
+    assert_condition(ExpireCond::IfLess, "LT");
+}
+
+#[test]
+fn test_eval_response_resp2_timeout_set() {
+    let response = PExpireCommand::new("my_key", 1500).eval_response(Resp2Frame::Integer(1));
+
+    assert!(response.unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3_timeout_set() {
+    let response = PExpireCommand::new("my_key", 1500).eval_response(Resp3Frame::Number {
+        data: 1,
+        attributes: None,
+    });
+
+    assert!(response.unwrap());
+}
+
+#[test]
+fn test_eval_response_resp2_key_missing() {
+    let response = PExpireCommand::new("my_key", 1500).eval_response(Resp2Frame::Integer(0));
+
+    assert!(!response.unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3_key_missing() {
+    let response = PExpireCommand::new("my_key", 1500).eval_response(Resp3Frame::Number {
+        data: 0,
+        attributes: None,
+    });
+
+    assert!(!response.unwrap());
+}
+
+#[test]
+fn test_eval_response_invalid_integer() {
+    let response = PExpireCommand::new("my_key", 1500).eval_response(Resp2Frame::Integer(2));
+
+    assert!(response.is_err());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let response = PExpireCommand::new("my_key", 1500).eval_response(Resp2Frame::BulkString("1".into()));
+
+    assert!(response.is_err());
+}
+
+fn assert_condition(condition: ExpireCond, expected: &str) {
+    let frame: Resp2Frame = PExpireCommand::new("my_key", 1500).condition(condition).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("PEXPIRE", array[0].to_string().unwrap());
+        assert_eq!("my_key", array[1].to_string().unwrap());
+        assert_eq!("1500", array[2].to_string().unwrap());
+        assert_eq!(expected, array[3].to_string().unwrap());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::commands::punsubscribe::PUnsubscribeCommand;
+use crate::commands::Command;
+use bytes::Bytes;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let command = PUnsubscribeCommand::new(["first.*".into(), "second.*".into()]);
+    let frame: Resp2Frame = command.encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("PUNSUBSCRIBE", array[0].to_string().unwrap());
+        assert_eq!("first.*", array[1].to_string().unwrap());
+        assert_eq!("second.*", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let command = PUnsubscribeCommand::new(["first.*".into()]);
+    let frame: Resp3Frame = command.encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("PUNSUBSCRIBE", data[0].to_string().unwrap());
+        assert_eq!("first.*", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2_confirmation() {
+    let command = PUnsubscribeCommand::new(["first.*".into()]);
+    let response = command.eval_response(Resp2Frame::Array(vec![
+        Resp2Frame::BulkString(Bytes::from_static(b"punsubscribe")),
+        Resp2Frame::BulkString(Bytes::from_static(b"first.*")),
+        Resp2Frame::Integer(1),
+    ]));
+
+    assert_eq!(1, response.unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3_confirmation() {
+    let command = PUnsubscribeCommand::new(["first.*".into()]);
+    let response = command.eval_response(Resp3Frame::Push {
+        data: vec![
+            Resp3Frame::SimpleString {
+                data: Bytes::from_static(b"punsubscribe"),
+                attributes: None,
+            },
+            Resp3Frame::SimpleString {
+                data: Bytes::from_static(b"first.*"),
+                attributes: None,
+            },
+            Resp3Frame::Number {
+                data: 0,
+                attributes: None,
+            },
+        ],
+        attributes: None,
+    });
+
+    assert_eq!(0, response.unwrap());
+}
+
+#[test]
+fn test_eval_response_resp2_other_push() {
+    let command = PUnsubscribeCommand::new(["first.*".into()]);
+    let response = command.eval_response(Resp2Frame::Array(vec![
+        Resp2Frame::BulkString(Bytes::from_static(b"unsubscribe")),
+        Resp2Frame::BulkString(Bytes::from_static(b"channel")),
+        Resp2Frame::Integer(1),
+    ]));
+
+    assert!(response.is_err());
+}
+
+#[test]
+fn test_eval_response_resp3_no_push() {
+    let command = PUnsubscribeCommand::new(["first.*".into()]);
+    let response = command.eval_response(Resp3Frame::SimpleString {
+        data: Bytes::from_static(b"OK"),
+        attributes: None,
+    });
+
+    assert!(response.is_err());
+}
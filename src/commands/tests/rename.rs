@@ -0,0 +1,86 @@
+use crate::commands::helpers::CmdStr;
+use crate::commands::rename::{RenameCommand, RenameNxCommand};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_rename_encode_resp2() {
+    let frame: Resp2Frame = RenameCommand::new("source", "destination").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("RENAME", array[0].to_string().unwrap());
+        assert_eq!("source", array[1].to_string().unwrap());
+        assert_eq!("destination", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_rename_encode_resp3() {
+    let frame: Resp3Frame = RenameCommand::new("source", "destination").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("RENAME", data[0].to_string().unwrap());
+        assert_eq!("source", data[1].to_string().unwrap());
+        assert_eq!("destination", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_rename_eval_response() {
+    let command = RenameCommand::new("source", "destination");
+
+    command.eval_response(CmdStr::new("OK").to_simple()).unwrap();
+    assert!(command.eval_response(CmdStr::new("QUEUED").to_simple()).is_err());
+    assert!(command.eval_response(Resp2Frame::Integer(1)).is_err());
+}
+
+#[test]
+fn test_renamenx_encode_resp2() {
+    let frame: Resp2Frame = RenameNxCommand::new("source", "destination").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("RENAMENX", array[0].to_string().unwrap());
+        assert_eq!("source", array[1].to_string().unwrap());
+        assert_eq!("destination", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_renamenx_encode_resp3() {
+    let frame: Resp3Frame = RenameNxCommand::new("source", "destination").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("RENAMENX", data[0].to_string().unwrap());
+        assert_eq!("source", data[1].to_string().unwrap());
+        assert_eq!("destination", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_renamenx_eval_response() {
+    let command = RenameNxCommand::new("source", "destination");
+
+    assert!(command.eval_response(Resp2Frame::Integer(1)).unwrap());
+    assert!(!command
+        .eval_response(Resp3Frame::Number {
+            data: 0,
+            attributes: None,
+        })
+        .unwrap());
+}
+
+#[test]
+fn test_renamenx_eval_response_invalid_response() {
+    let response = RenameNxCommand::new("source", "destination").eval_response(Resp2Frame::Null);
+
+    assert!(response.is_err());
+}
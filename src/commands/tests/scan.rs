@@ -0,0 +1,88 @@
+use crate::commands::scan::{ScanCommand, ScanResponse};
+use crate::commands::Command;
+use bytes::Bytes;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = ScanCommand::new(0).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(2, array.len());
+        assert_eq!("SCAN", array[0].to_string().unwrap());
+        assert_eq!("0", array[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_options_resp3() {
+    let frame: Resp3Frame = ScanCommand::new(17).pattern("user:*").count(100).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(6, data.len());
+        assert_eq!("SCAN", data[0].to_string().unwrap());
+        assert_eq!("17", data[1].to_string().unwrap());
+        assert_eq!("MATCH", data[2].to_string().unwrap());
+        assert_eq!("user:*", data[3].to_string().unwrap());
+        assert_eq!("COUNT", data[4].to_string().unwrap());
+        assert_eq!("100", data[5].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("12".into()),
+        Resp2Frame::Array(vec![
+            Resp2Frame::BulkString("key1".into()),
+            Resp2Frame::BulkString("key2".into()),
+        ]),
+    ]);
+
+    assert_eq!(
+        ScanResponse {
+            cursor: 12,
+            keys: vec![Bytes::from_static(b"key1"), Bytes::from_static(b"key2")],
+        },
+        ScanCommand::new(0).eval_response(frame).unwrap()
+    );
+}
+
+#[test]
+fn test_eval_response_resp3() {
+    let frame = Resp3Frame::Array {
+        data: vec![
+            Resp3Frame::BlobString {
+                data: "0".into(),
+                attributes: None,
+            },
+            Resp3Frame::Array {
+                data: vec![],
+                attributes: None,
+            },
+        ],
+        attributes: None,
+    };
+
+    let response = ScanCommand::new(5).eval_response(frame).unwrap();
+    assert_eq!(0, response.cursor);
+    assert!(response.keys.is_empty());
+}
+
+#[test]
+fn test_eval_response_invalid_cursor() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("abc".into()),
+        Resp2Frame::Array(vec![]),
+    ]);
+
+    assert!(ScanCommand::new(0).eval_response(frame).is_err());
+}
+
+#[test]
+fn test_eval_response_invalid_type() {
+    assert!(ScanCommand::new(0).eval_response(Resp2Frame::Integer(0)).is_err());
+}
@@ -26,6 +26,28 @@ fn test_encode_expiration_seconds() {
     assert_command(vec!["SET", "test_key", "value123", "EX", "120"], command);
 }
 
+#[test]
+fn test_encode_expiration_seconds_jittered() {
+    let first = jittered_ttl(120, 30, 1);
+    let second = jittered_ttl(120, 30, 2);
+
+    assert!((120..=150).contains(&first));
+    assert!((120..=150).contains(&second));
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_encode_expiration_seconds_jittered_deterministic() {
+    assert_eq!(jittered_ttl(60, 1000, 4711), jittered_ttl(60, 1000, 4711));
+}
+
+#[test]
+fn test_encode_expiration_seconds_jittered_zero_jitter() {
+    let command =
+        SetCommand::new("test_key", "value123").expires(ExpirationPolicy::SecondsJittered(120, 0, 7));
+    assert_command(vec!["SET", "test_key", "value123", "EX", "120"], command);
+}
+
 #[test]
 fn test_encode_expiration_milliseconds() {
     let command = SetCommand::new("test_key", "value123").expires(ExpirationPolicy::Milliseconds(1674));
@@ -291,3 +313,16 @@ fn assert_resp3_command(expected: Vec<&'static str>, frame: Resp3Frame) {
         }
     }
 }
+
+/// Returns the EX argument of a jittered SET command
+fn jittered_ttl(base: usize, jitter: usize, seed: u64) -> usize {
+    let command =
+        SetCommand::new("key", "value").expires(ExpirationPolicy::SecondsJittered(base, jitter, seed));
+    let frame: Resp2Frame = command.encode();
+
+    let Resp2Frame::Array(array) = frame else {
+        panic!("Unexpected frame type");
+    };
+    assert_eq!("EX", array[3].to_string().unwrap());
+    array[4].to_string().unwrap().parse().unwrap()
+}
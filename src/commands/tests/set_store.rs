@@ -0,0 +1,130 @@
+use crate::commands::set_store::{SDiffStoreCommand, SInterStoreCommand, SUnionStoreCommand};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_inter_store_resp2() {
+    let frame: Resp2Frame =
+        SInterStoreCommand::new("destination", ["first".into(), "second".into()]).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("SINTERSTORE", array[0].to_string().unwrap());
+        assert_eq!("destination", array[1].to_string().unwrap());
+        assert_eq!("first", array[2].to_string().unwrap());
+        assert_eq!("second", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_inter_store_resp3() {
+    let frame: Resp3Frame =
+        SInterStoreCommand::new("destination", ["first".into(), "second".into()]).encode();
+
+    matches!(frame, Resp3Frame::Array { .. });
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("SINTERSTORE", data[0].to_string().unwrap());
+        assert_eq!("destination", data[1].to_string().unwrap());
+        assert_eq!("first", data[2].to_string().unwrap());
+        assert_eq!("second", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_union_store_resp2() {
+    let frame: Resp2Frame =
+        SUnionStoreCommand::new("destination", ["first".into(), "second".into()]).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("SUNIONSTORE", array[0].to_string().unwrap());
+        assert_eq!("destination", array[1].to_string().unwrap());
+        assert_eq!("first", array[2].to_string().unwrap());
+        assert_eq!("second", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_union_store_resp3() {
+    let frame: Resp3Frame =
+        SUnionStoreCommand::new("destination", ["first".into(), "second".into()]).encode();
+
+    matches!(frame, Resp3Frame::Array { .. });
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("SUNIONSTORE", data[0].to_string().unwrap());
+        assert_eq!("destination", data[1].to_string().unwrap());
+        assert_eq!("first", data[2].to_string().unwrap());
+        assert_eq!("second", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_diff_store_resp2() {
+    let frame: Resp2Frame = SDiffStoreCommand::new("destination", ["first".into(), "second".into()]).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("SDIFFSTORE", array[0].to_string().unwrap());
+        assert_eq!("destination", array[1].to_string().unwrap());
+        assert_eq!("first", array[2].to_string().unwrap());
+        assert_eq!("second", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_diff_store_resp3() {
+    let frame: Resp3Frame = SDiffStoreCommand::new("destination", ["first".into(), "second".into()]).encode();
+
+    matches!(frame, Resp3Frame::Array { .. });
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("SDIFFSTORE", data[0].to_string().unwrap());
+        assert_eq!("destination", data[1].to_string().unwrap());
+        assert_eq!("first", data[2].to_string().unwrap());
+        assert_eq!("second", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2_success() {
+    let command = SInterStoreCommand::new("destination", ["first".into(), "second".into()]);
+    let response = command.eval_response(Resp2Frame::Integer(2));
+
+    assert_eq!(2, response.unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3_success() {
+    let command = SUnionStoreCommand::new("destination", ["first".into(), "second".into()]);
+    let response = command.eval_response(Resp3Frame::Number {
+        data: 3,
+        attributes: None,
+    });
+
+    assert_eq!(3, response.unwrap());
+}
+
+#[test]
+fn test_eval_response_resp2_invalid_response() {
+    let command = SDiffStoreCommand::new("destination", ["first".into(), "second".into()]);
+    let response = command.eval_response(Resp2Frame::BulkString("3".into()));
+
+    assert!(response.is_err());
+}
+
+#[test]
+fn test_eval_response_resp3_invalid_response() {
+    let command = SDiffStoreCommand::new("destination", ["first".into(), "second".into()]);
+    let response = command.eval_response(Resp3Frame::BlobString {
+        data: "test".into(),
+        attributes: None,
+    });
+
+    assert!(response.is_err());
+}
@@ -0,0 +1,56 @@
+use crate::commands::setrange::SetRangeCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = SetRangeCommand::new("key", 6, "Redis").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("SETRANGE", array[0].to_string().unwrap());
+        assert_eq!("key", array[1].to_string().unwrap());
+        assert_eq!("6", array[2].to_string().unwrap());
+        assert_eq!("Redis", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = SetRangeCommand::new("key", 0, "J").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("SETRANGE", data[0].to_string().unwrap());
+        assert_eq!("key", data[1].to_string().unwrap());
+        assert_eq!("0", data[2].to_string().unwrap());
+        assert_eq!("J", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response() {
+    let command = SetRangeCommand::new("key", 6, "Redis");
+
+    assert_eq!(11, command.eval_response(Resp2Frame::Integer(11)).unwrap());
+    assert_eq!(
+        5,
+        command
+            .eval_response(Resp3Frame::Number {
+                data: 5,
+                attributes: None
+            })
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = SetRangeCommand::new("key", 6, "Redis");
+
+    assert!(command.eval_response(Resp2Frame::SimpleString("OK".into())).is_err());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+}
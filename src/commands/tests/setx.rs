@@ -0,0 +1,82 @@
+use crate::commands::setx::{SetExCommand, SetNxCommand};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_setnx_encode_resp2() {
+    let frame: Resp2Frame = SetNxCommand::new("key", "value").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("SETNX", array[0].to_string().unwrap());
+        assert_eq!("key", array[1].to_string().unwrap());
+        assert_eq!("value", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_setnx_encode_resp3() {
+    let frame: Resp3Frame = SetNxCommand::new("key", "value").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("SETNX", data[0].to_string().unwrap());
+        assert_eq!("key", data[1].to_string().unwrap());
+        assert_eq!("value", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_setnx_eval_response() {
+    let command = SetNxCommand::new("key", "value");
+
+    assert!(command.eval_response(Resp2Frame::Integer(1)).unwrap());
+    assert!(!command
+        .eval_response(Resp3Frame::Number {
+            data: 0,
+            attributes: None
+        })
+        .unwrap());
+    assert!(command.eval_response(Resp2Frame::Integer(2)).is_err());
+    assert!(command.eval_response(Resp2Frame::SimpleString("OK".into())).is_err());
+}
+
+#[test]
+fn test_setex_encode_resp2() {
+    let frame: Resp2Frame = SetExCommand::new("key", 60, "value").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("SETEX", array[0].to_string().unwrap());
+        assert_eq!("key", array[1].to_string().unwrap());
+        assert_eq!("60", array[2].to_string().unwrap());
+        assert_eq!("value", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_setex_encode_resp3() {
+    let frame: Resp3Frame = SetExCommand::new("key", 60, "value").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("SETEX", data[0].to_string().unwrap());
+        assert_eq!("key", data[1].to_string().unwrap());
+        assert_eq!("60", data[2].to_string().unwrap());
+        assert_eq!("value", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_setex_eval_response() {
+    let command = SetExCommand::new("key", 60, "value");
+
+    assert!(command.eval_response(Resp2Frame::SimpleString("OK".into())).is_ok());
+    assert!(command.eval_response(Resp2Frame::SimpleString("QUEUED".into())).is_err());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+}
@@ -0,0 +1,125 @@
+use crate::commands::sismember::{SetCardCommand, SetIsMemberCommand};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = SetIsMemberCommand::new("my_set", "green").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("SISMEMBER", array[0].to_string().unwrap());
+        assert_eq!("my_set", array[1].to_string().unwrap());
+        assert_eq!("green", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = SetIsMemberCommand::new("my_set", "green").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("SISMEMBER", data[0].to_string().unwrap());
+        assert_eq!("my_set", data[1].to_string().unwrap());
+        assert_eq!("green", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2_integer() {
+    let command = SetIsMemberCommand::new("my_set", "green");
+
+    assert!(command.eval_response(Resp2Frame::Integer(1)).unwrap());
+    assert!(!command.eval_response(Resp2Frame::Integer(0)).unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3_boolean() {
+    let command = SetIsMemberCommand::new("my_set", "green");
+
+    assert!(command
+        .eval_response(Resp3Frame::Boolean {
+            data: true,
+            attributes: None
+        })
+        .unwrap());
+    assert!(!command
+        .eval_response(Resp3Frame::Boolean {
+            data: false,
+            attributes: None
+        })
+        .unwrap());
+}
+
+#[test]
+fn test_eval_response_resp3_integer() {
+    let command = SetIsMemberCommand::new("my_set", "green");
+
+    assert!(command
+        .eval_response(Resp3Frame::Number {
+            data: 1,
+            attributes: None
+        })
+        .unwrap());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = SetIsMemberCommand::new("my_set", "green");
+
+    assert!(command.eval_response(Resp2Frame::Integer(2)).is_err());
+    assert!(command.eval_response(Resp2Frame::BulkString("1".into())).is_err());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+}
+
+#[test]
+fn test_card_encode_resp2() {
+    let frame: Resp2Frame = SetCardCommand::new("my_set").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(2, array.len());
+        assert_eq!("SCARD", array[0].to_string().unwrap());
+        assert_eq!("my_set", array[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_card_encode_resp3() {
+    let frame: Resp3Frame = SetCardCommand::new("my_set").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("SCARD", data[0].to_string().unwrap());
+        assert_eq!("my_set", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_card_eval_response() {
+    let command = SetCardCommand::new("my_set");
+
+    assert_eq!(3, command.eval_response(Resp2Frame::Integer(3)).unwrap());
+    assert_eq!(
+        0,
+        command
+            .eval_response(Resp3Frame::Number {
+                data: 0,
+                attributes: None
+            })
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_card_eval_response_invalid_response() {
+    let command = SetCardCommand::new("my_set");
+
+    assert!(command.eval_response(Resp2Frame::BulkString("3".into())).is_err());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+}
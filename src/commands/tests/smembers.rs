@@ -0,0 +1,92 @@
+use crate::commands::smembers::SetMembersCommand;
+use crate::commands::Command;
+use alloc::collections::BTreeSet;
+use bytes::Bytes;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = SetMembersCommand::new("my_set").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(2, array.len());
+        assert_eq!("SMEMBERS", array[0].to_string().unwrap());
+        assert_eq!("my_set", array[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = SetMembersCommand::new("my_set").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("SMEMBERS", data[0].to_string().unwrap());
+        assert_eq!("my_set", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2_members() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("red".into()),
+        Resp2Frame::BulkString("green".into()),
+    ]);
+
+    let response = SetMembersCommand::new("my_set").eval_response(frame).unwrap();
+    assert_eq!(
+        BTreeSet::from([Bytes::from_static(b"green"), Bytes::from_static(b"red")]),
+        response.into_iter().collect()
+    );
+}
+
+#[test]
+fn test_eval_response_resp3_set() {
+    let frame = Resp3Frame::Set {
+        data: [
+            Resp3Frame::BlobString {
+                data: "red".into(),
+                attributes: None,
+            },
+            Resp3Frame::BlobString {
+                data: "green".into(),
+                attributes: None,
+            },
+        ]
+        .into_iter()
+        .collect(),
+        attributes: None,
+    };
+
+    let mut response = SetMembersCommand::new("my_set").eval_response(frame).unwrap();
+    response.sort();
+    assert_eq!(
+        vec![Bytes::from_static(b"green"), Bytes::from_static(b"red")],
+        response
+    );
+}
+
+#[test]
+fn test_eval_response_empty_set() {
+    let command = SetMembersCommand::new("my_set");
+
+    assert!(command.eval_response(Resp2Frame::Array(vec![])).unwrap().is_empty());
+    assert!(command
+        .eval_response(Resp3Frame::Set {
+            data: Default::default(),
+            attributes: None
+        })
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = SetMembersCommand::new("my_set");
+
+    assert!(command.eval_response(Resp2Frame::Integer(1)).is_err());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+}
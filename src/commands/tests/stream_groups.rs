@@ -0,0 +1,236 @@
+use crate::commands::stream_groups::{StreamEntry, XAckCommand, XGroupCreateCommand, XReadGroupCommand};
+use crate::commands::Command;
+use bytes::Bytes;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, FrameMap, Resp3Frame as _};
+
+#[test]
+fn test_group_create_encode_resp2() {
+    let frame: Resp2Frame = XGroupCreateCommand::new("stream", "group", "$").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(5, array.len());
+        assert_eq!("XGROUP", array[0].to_string().unwrap());
+        assert_eq!("CREATE", array[1].to_string().unwrap());
+        assert_eq!("stream", array[2].to_string().unwrap());
+        assert_eq!("group", array[3].to_string().unwrap());
+        assert_eq!("$", array[4].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_group_create_encode_mkstream_resp3() {
+    let frame: Resp3Frame = XGroupCreateCommand::new("stream", "group", "0").mkstream().encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(6, data.len());
+        assert_eq!("XGROUP", data[0].to_string().unwrap());
+        assert_eq!("0", data[4].to_string().unwrap());
+        assert_eq!("MKSTREAM", data[5].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_group_create_eval_response() {
+    let command = XGroupCreateCommand::new("stream", "group", "$");
+
+    assert!(command.eval_response(Resp2Frame::SimpleString("OK".into())).is_ok());
+    assert!(command.eval_response(Resp2Frame::SimpleString("QUEUED".into())).is_err());
+}
+
+#[test]
+fn test_read_group_encode_new_entries() {
+    let frame: Resp2Frame = XReadGroupCommand::new("group", "consumer", "stream").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(7, array.len());
+        assert_eq!("XREADGROUP", array[0].to_string().unwrap());
+        assert_eq!("GROUP", array[1].to_string().unwrap());
+        assert_eq!("group", array[2].to_string().unwrap());
+        assert_eq!("consumer", array[3].to_string().unwrap());
+        assert_eq!("STREAMS", array[4].to_string().unwrap());
+        assert_eq!("stream", array[5].to_string().unwrap());
+        assert_eq!(">", array[6].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_read_group_encode_all_options() {
+    let frame: Resp3Frame = XReadGroupCommand::new("group", "consumer", "stream")
+        .count(10)
+        .block(500)
+        .noack()
+        .pending("0")
+        .encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(12, data.len());
+        assert_eq!("COUNT", data[4].to_string().unwrap());
+        assert_eq!("10", data[5].to_string().unwrap());
+        assert_eq!("BLOCK", data[6].to_string().unwrap());
+        assert_eq!("500", data[7].to_string().unwrap());
+        assert_eq!("NOACK", data[8].to_string().unwrap());
+        assert_eq!("STREAMS", data[9].to_string().unwrap());
+        assert_eq!("stream", data[10].to_string().unwrap());
+        assert_eq!("0", data[11].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_read_group_eval_response_resp2() {
+    let frame = Resp2Frame::Array(vec![Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("stream".into()),
+        Resp2Frame::Array(vec![
+            Resp2Frame::Array(vec![
+                Resp2Frame::BulkString("1-0".into()),
+                Resp2Frame::Array(vec![
+                    Resp2Frame::BulkString("temp".into()),
+                    Resp2Frame::BulkString("21".into()),
+                    Resp2Frame::BulkString("unit".into()),
+                    Resp2Frame::BulkString("C".into()),
+                ]),
+            ]),
+            Resp2Frame::Array(vec![Resp2Frame::BulkString("2-0".into()), Resp2Frame::Null]),
+        ]),
+    ])]);
+
+    let response = XReadGroupCommand::new("group", "consumer", "stream")
+        .eval_response(frame)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            StreamEntry {
+                id: Bytes::from_static(b"1-0"),
+                fields: Some(vec![
+                    (Bytes::from_static(b"temp"), Bytes::from_static(b"21")),
+                    (Bytes::from_static(b"unit"), Bytes::from_static(b"C")),
+                ]),
+            },
+            StreamEntry {
+                id: Bytes::from_static(b"2-0"),
+                fields: None,
+            },
+        ],
+        response
+    );
+}
+
+#[test]
+fn test_read_group_eval_response_resp2_null() {
+    let response = XReadGroupCommand::new("group", "consumer", "stream")
+        .eval_response(Resp2Frame::Null)
+        .unwrap();
+
+    assert!(response.is_empty());
+}
+
+#[test]
+fn test_read_group_eval_response_resp2_invalid() {
+    let command = XReadGroupCommand::new("group", "consumer", "stream");
+
+    let odd_fields = Resp2Frame::Array(vec![Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("stream".into()),
+        Resp2Frame::Array(vec![Resp2Frame::Array(vec![
+            Resp2Frame::BulkString("1-0".into()),
+            Resp2Frame::Array(vec![Resp2Frame::BulkString("temp".into())]),
+        ])]),
+    ])]);
+    assert!(command.eval_response(odd_fields).is_err());
+    assert!(command.eval_response(Resp2Frame::Integer(1)).is_err());
+}
+
+#[test]
+fn test_read_group_eval_response_resp3_map() {
+    let frame = Resp3Frame::Map {
+        data: FrameMap::from([(
+            Resp3Frame::BlobString {
+                data: "stream".into(),
+                attributes: None,
+            },
+            Resp3Frame::Array {
+                data: vec![Resp3Frame::Array {
+                    data: vec![
+                        Resp3Frame::BlobString {
+                            data: "1-0".into(),
+                            attributes: None,
+                        },
+                        Resp3Frame::Array {
+                            data: vec![
+                                Resp3Frame::BlobString {
+                                    data: "temp".into(),
+                                    attributes: None,
+                                },
+                                Resp3Frame::BlobString {
+                                    data: "21".into(),
+                                    attributes: None,
+                                },
+                            ],
+                            attributes: None,
+                        },
+                    ],
+                    attributes: None,
+                }],
+                attributes: None,
+            },
+        )]),
+        attributes: None,
+    };
+
+    let response = XReadGroupCommand::new("group", "consumer", "stream")
+        .eval_response(frame)
+        .unwrap();
+
+    assert_eq!(
+        vec![StreamEntry {
+            id: Bytes::from_static(b"1-0"),
+            fields: Some(vec![(Bytes::from_static(b"temp"), Bytes::from_static(b"21"))]),
+        }],
+        response
+    );
+}
+
+#[test]
+fn test_read_group_eval_response_resp3_null() {
+    let response = XReadGroupCommand::new("group", "consumer", "stream")
+        .eval_response(Resp3Frame::Null)
+        .unwrap();
+
+    assert!(response.is_empty());
+}
+
+#[test]
+fn test_ack_encode() {
+    let frame: Resp2Frame = XAckCommand::new("stream", "group", "1-0").id("2-0").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(5, array.len());
+        assert_eq!("XACK", array[0].to_string().unwrap());
+        assert_eq!("stream", array[1].to_string().unwrap());
+        assert_eq!("group", array[2].to_string().unwrap());
+        assert_eq!("1-0", array[3].to_string().unwrap());
+        assert_eq!("2-0", array[4].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_ack_eval_response() {
+    let command = XAckCommand::new("stream", "group", "1-0").id("2-0");
+
+    assert_eq!(2, command.eval_response(Resp2Frame::Integer(2)).unwrap());
+    assert_eq!(
+        1,
+        command
+            .eval_response(Resp3Frame::Number {
+                data: 1,
+                attributes: None
+            })
+            .unwrap()
+    );
+    assert!(command.eval_response(Resp2Frame::Null).is_err());
+}
@@ -0,0 +1,60 @@
+use crate::commands::swapdb::SwapDbCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = SwapDbCommand::new(0, 3).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("SWAPDB", array[0].to_string().unwrap());
+        assert_eq!("0", array[1].to_string().unwrap());
+        assert_eq!("3", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = SwapDbCommand::new(1, 2).encode();
+
+    matches!(frame, Resp3Frame::Array { .. });
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("SWAPDB", data[0].to_string().unwrap());
+        assert_eq!("1", data[1].to_string().unwrap());
+        assert_eq!("2", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2_ok() {
+    let response = SwapDbCommand::new(0, 1).eval_response(Resp2Frame::SimpleString("OK".into()));
+    assert!(response.is_ok());
+}
+
+#[test]
+fn test_eval_response_resp3_ok() {
+    let response = SwapDbCommand::new(0, 1).eval_response(Resp3Frame::SimpleString {
+        data: "OK".into(),
+        attributes: None,
+    });
+    assert!(response.is_ok());
+}
+
+#[test]
+fn test_eval_response_resp2_invalid() {
+    let response = SwapDbCommand::new(0, 1).eval_response(Resp2Frame::SimpleString("WRONG".into()));
+    assert!(response.is_err());
+}
+
+#[test]
+fn test_eval_response_resp3_invalid() {
+    let response = SwapDbCommand::new(0, 1).eval_response(Resp3Frame::Number {
+        data: 1,
+        attributes: None,
+    });
+    assert!(response.is_err());
+}
@@ -0,0 +1,71 @@
+use crate::commands::ttl::{PttlCommand, TtlCommand, TtlResponse};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_ttl_resp2() {
+    let frame: Resp2Frame = TtlCommand::new("my_key").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(2, array.len());
+        assert_eq!("TTL", array[0].to_string().unwrap());
+        assert_eq!("my_key", array[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_pttl_resp3() {
+    let frame: Resp3Frame = PttlCommand::new("my_key").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(2, data.len());
+        assert_eq!("PTTL", data[0].to_string().unwrap());
+        assert_eq!("my_key", data[1].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_duration() {
+    let response = TtlCommand::new("my_key").eval_response(Resp2Frame::Integer(120));
+    assert_eq!(TtlResponse::Duration(120), response.unwrap());
+
+    let response = PttlCommand::new("my_key").eval_response(Resp3Frame::Number {
+        data: 0,
+        attributes: None,
+    });
+    assert_eq!(TtlResponse::Duration(0), response.unwrap());
+}
+
+#[test]
+fn test_eval_response_no_expiry() {
+    let response = TtlCommand::new("my_key").eval_response(Resp2Frame::Integer(-1));
+
+    assert_eq!(TtlResponse::NoExpiry, response.unwrap());
+}
+
+#[test]
+fn test_eval_response_key_missing() {
+    let response = PttlCommand::new("my_key").eval_response(Resp3Frame::Number {
+        data: -2,
+        attributes: None,
+    });
+
+    assert_eq!(TtlResponse::KeyMissing, response.unwrap());
+}
+
+#[test]
+fn test_eval_response_invalid_negative() {
+    let response = TtlCommand::new("my_key").eval_response(Resp2Frame::Integer(-3));
+
+    assert!(response.is_err());
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let response = TtlCommand::new("my_key").eval_response(Resp2Frame::BulkString("120".into()));
+
+    assert!(response.is_err());
+}
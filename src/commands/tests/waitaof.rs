@@ -0,0 +1,83 @@
+use crate::commands::waitaof::WaitAofCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame = WaitAofCommand::new(1, 2, 100).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("WAITAOF", array[0].to_string().unwrap());
+        assert_eq!("1", array[1].to_string().unwrap());
+        assert_eq!("2", array[2].to_string().unwrap());
+        assert_eq!("100", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = WaitAofCommand::new(1, 2, 100).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("WAITAOF", data[0].to_string().unwrap());
+        assert_eq!("1", data[1].to_string().unwrap());
+        assert_eq!("2", data[2].to_string().unwrap());
+        assert_eq!("100", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_resp2() {
+    let frame = Resp2Frame::Array(vec![Resp2Frame::Integer(1), Resp2Frame::Integer(2)]);
+    let response = WaitAofCommand::new(1, 2, 100).eval_response(frame).unwrap();
+
+    assert_eq!((1, 2), response);
+}
+
+#[test]
+fn test_eval_response_resp3() {
+    let frame = Resp3Frame::Array {
+        data: vec![
+            Resp3Frame::Number {
+                data: 0,
+                attributes: None,
+            },
+            Resp3Frame::Number {
+                data: 3,
+                attributes: None,
+            },
+        ],
+        attributes: None,
+    };
+    let response = WaitAofCommand::new(1, 2, 100).eval_response(frame).unwrap();
+
+    assert_eq!((0, 3), response);
+}
+
+#[test]
+fn test_eval_response_invalid_length() {
+    let frame = Resp2Frame::Array(vec![Resp2Frame::Integer(1)]);
+    let response = WaitAofCommand::new(1, 2, 100).eval_response(frame);
+
+    assert!(response.is_err());
+}
+
+#[test]
+fn test_eval_response_invalid_element() {
+    let frame = Resp2Frame::Array(vec![Resp2Frame::Integer(1), Resp2Frame::BulkString("2".into())]);
+    let response = WaitAofCommand::new(1, 2, 100).eval_response(frame);
+
+    assert!(response.is_err());
+}
+
+#[test]
+fn test_eval_response_no_array() {
+    let response = WaitAofCommand::new(1, 2, 100).eval_response(Resp2Frame::Integer(1));
+
+    assert!(response.is_err());
+}
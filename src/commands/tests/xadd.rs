@@ -0,0 +1,73 @@
+use crate::commands::xadd::XAddCommand;
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_fields() {
+    let frame: Resp2Frame = XAddCommand::new("stream", "temp", "21").field("unit", "C").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(7, array.len());
+        assert_eq!("XADD", array[0].to_string().unwrap());
+        assert_eq!("stream", array[1].to_string().unwrap());
+        assert_eq!("*", array[2].to_string().unwrap());
+        assert_eq!("temp", array[3].to_string().unwrap());
+        assert_eq!("21", array[4].to_string().unwrap());
+        assert_eq!("unit", array[5].to_string().unwrap());
+        assert_eq!("C", array[6].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_maxlen_approx() {
+    let frame: Resp3Frame = XAddCommand::new("stream", "temp", "21").maxlen_approx(1000).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(8, data.len());
+        assert_eq!("XADD", data[0].to_string().unwrap());
+        assert_eq!("stream", data[1].to_string().unwrap());
+        assert_eq!("MAXLEN", data[2].to_string().unwrap());
+        assert_eq!("~", data[3].to_string().unwrap());
+        assert_eq!("1000", data[4].to_string().unwrap());
+        assert_eq!("*", data[5].to_string().unwrap());
+        assert_eq!("temp", data[6].to_string().unwrap());
+        assert_eq!("21", data[7].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_maxlen_exact_explicit_id() {
+    let frame: Resp2Frame = XAddCommand::new("stream", "temp", "21").maxlen(50).id("5-0").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(7, array.len());
+        assert_eq!("MAXLEN", array[2].to_string().unwrap());
+        assert_eq!("50", array[3].to_string().unwrap());
+        assert_eq!("5-0", array[4].to_string().unwrap());
+        assert_eq!("temp", array[5].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response() {
+    let command = XAddCommand::new("stream", "temp", "21");
+
+    assert_eq!(
+        "1-0",
+        command.eval_response(Resp2Frame::BulkString("1-0".into())).unwrap()
+    );
+    assert_eq!(
+        "2-0",
+        command
+            .eval_response(Resp3Frame::BlobString {
+                data: "2-0".into(),
+                attributes: None
+            })
+            .unwrap()
+    );
+    assert!(command.eval_response(Resp2Frame::Integer(1)).is_err());
+}
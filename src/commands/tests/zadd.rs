@@ -0,0 +1,114 @@
+use crate::commands::zadd::{SortedSetAddCommand, SortedSetAddFlag};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_encode_resp2() {
+    let frame: Resp2Frame =
+        SortedSetAddCommand::new("my_set", [(1.0, "one".into()), (2.5, "two".into())]).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(6, array.len());
+        assert_eq!("ZADD", array[0].to_string().unwrap());
+        assert_eq!("my_set", array[1].to_string().unwrap());
+        assert_eq!("1", array[2].to_string().unwrap());
+        assert_eq!("one", array[3].to_string().unwrap());
+        assert_eq!("2.5", array[4].to_string().unwrap());
+        assert_eq!("two", array[5].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_resp3() {
+    let frame: Resp3Frame = SortedSetAddCommand::new("my_set", [(-0.125, "one".into())]).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(4, data.len());
+        assert_eq!("ZADD", data[0].to_string().unwrap());
+        assert_eq!("my_set", data[1].to_string().unwrap());
+        assert_eq!("-0.125", data[2].to_string().unwrap());
+        assert_eq!("one", data[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_score_formatting() {
+    let frame: Resp2Frame = SortedSetAddCommand::new(
+        "my_set",
+        [
+            (1e21, "large".into()),
+            (0.000001, "small".into()),
+            (-3.0, "negative".into()),
+        ],
+    )
+    .encode();
+
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!("1000000000000000000000", array[2].to_string().unwrap());
+        assert_eq!("0.000001", array[4].to_string().unwrap());
+        assert_eq!("-3", array[6].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_flags_placement() {
+    let frame: Resp2Frame = SortedSetAddCommand::new("my_set", [(1.0, "one".into())])
+        .flag(SortedSetAddFlag::Changed)
+        .flag(SortedSetAddFlag::IfGreater)
+        .flag(SortedSetAddFlag::OnlyExisting)
+        .flag(SortedSetAddFlag::Changed)
+        .encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(7, array.len());
+        assert_eq!("ZADD", array[0].to_string().unwrap());
+        assert_eq!("my_set", array[1].to_string().unwrap());
+        assert_eq!("XX", array[2].to_string().unwrap());
+        assert_eq!("GT", array[3].to_string().unwrap());
+        assert_eq!("CH", array[4].to_string().unwrap());
+        assert_eq!("1", array[5].to_string().unwrap());
+        assert_eq!("one", array[6].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_flags_nx_lt() {
+    let frame: Resp2Frame = SortedSetAddCommand::new("my_set", [(1.0, "one".into())])
+        .flag(SortedSetAddFlag::IfLess)
+        .flag(SortedSetAddFlag::OnlyNew)
+        .encode();
+
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(6, array.len());
+        assert_eq!("NX", array[2].to_string().unwrap());
+        assert_eq!("LT", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response() {
+    let command = SortedSetAddCommand::new("my_set", [(1.0, "one".into())]);
+
+    assert_eq!(1, command.eval_response(Resp2Frame::Integer(1)).unwrap());
+    assert_eq!(
+        0,
+        command
+            .eval_response(Resp3Frame::Number {
+                data: 0,
+                attributes: None
+            })
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_eval_response_invalid_response() {
+    let command = SortedSetAddCommand::new("my_set", [(1.0, "one".into())]);
+
+    assert!(command.eval_response(Resp2Frame::BulkString("1".into())).is_err());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+}
@@ -0,0 +1,209 @@
+use crate::commands::zrange_ext::{
+    LexBound, ScoreBound, ZRangeByLexCommand, ZRangeByScoreCommand, ZRangeStoreCommand,
+};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_score_bound_inclusive() {
+    assert_eq!("1.5", ScoreBound::Inclusive(1.5).encode());
+    assert_eq!("-3", ScoreBound::Inclusive(-3.0).encode());
+}
+
+#[test]
+fn test_score_bound_exclusive() {
+    assert_eq!("(1.5", ScoreBound::Exclusive(1.5).encode());
+    assert_eq!("(-3", ScoreBound::Exclusive(-3.0).encode());
+}
+
+#[test]
+fn test_score_bound_infinity() {
+    assert_eq!("-inf", ScoreBound::NegInfinity.encode());
+    assert_eq!("+inf", ScoreBound::PosInfinity.encode());
+}
+
+#[test]
+fn test_lex_bound_inclusive() {
+    assert_eq!("[abc", LexBound::inclusive("abc").encode());
+}
+
+#[test]
+fn test_lex_bound_exclusive() {
+    assert_eq!("(abc", LexBound::exclusive("abc").encode());
+}
+
+#[test]
+fn test_lex_bound_min_max() {
+    assert_eq!("-", LexBound::Min.encode());
+    assert_eq!("+", LexBound::Max.encode());
+}
+
+#[test]
+fn test_encode_by_score_resp2() {
+    let frame: Resp2Frame =
+        ZRangeByScoreCommand::new("key", ScoreBound::Exclusive(1.0), ScoreBound::PosInfinity).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("ZRANGEBYSCORE", array[0].to_string().unwrap());
+        assert_eq!("key", array[1].to_string().unwrap());
+        assert_eq!("(1", array[2].to_string().unwrap());
+        assert_eq!("+inf", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_by_score_limit_resp3() {
+    let frame: Resp3Frame =
+        ZRangeByScoreCommand::new("key", ScoreBound::NegInfinity, ScoreBound::Inclusive(2.5))
+            .limit(5, 10)
+            .encode();
+
+    matches!(frame, Resp3Frame::Array { .. });
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(7, data.len());
+        assert_eq!("ZRANGEBYSCORE", data[0].to_string().unwrap());
+        assert_eq!("key", data[1].to_string().unwrap());
+        assert_eq!("-inf", data[2].to_string().unwrap());
+        assert_eq!("2.5", data[3].to_string().unwrap());
+        assert_eq!("LIMIT", data[4].to_string().unwrap());
+        assert_eq!("5", data[5].to_string().unwrap());
+        assert_eq!("10", data[6].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_by_lex_resp2() {
+    let frame: Resp2Frame = ZRangeByLexCommand::new("key", LexBound::Min, LexBound::exclusive("c")).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("ZRANGEBYLEX", array[0].to_string().unwrap());
+        assert_eq!("key", array[1].to_string().unwrap());
+        assert_eq!("-", array[2].to_string().unwrap());
+        assert_eq!("(c", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_by_lex_limit_resp3() {
+    let frame: Resp3Frame = ZRangeByLexCommand::new("key", LexBound::inclusive("a"), LexBound::Max)
+        .limit(0, 3)
+        .encode();
+
+    matches!(frame, Resp3Frame::Array { .. });
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(7, data.len());
+        assert_eq!("ZRANGEBYLEX", data[0].to_string().unwrap());
+        assert_eq!("key", data[1].to_string().unwrap());
+        assert_eq!("[a", data[2].to_string().unwrap());
+        assert_eq!("+", data[3].to_string().unwrap());
+        assert_eq!("LIMIT", data[4].to_string().unwrap());
+        assert_eq!("0", data[5].to_string().unwrap());
+        assert_eq!("3", data[6].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_store_by_index() {
+    let frame: Resp2Frame = ZRangeStoreCommand::by_index("dst", "src", 0, -1).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(5, array.len());
+        assert_eq!("ZRANGESTORE", array[0].to_string().unwrap());
+        assert_eq!("dst", array[1].to_string().unwrap());
+        assert_eq!("src", array[2].to_string().unwrap());
+        assert_eq!("0", array[3].to_string().unwrap());
+        assert_eq!("-1", array[4].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_store_by_score_rev_limit() {
+    let frame: Resp2Frame =
+        ZRangeStoreCommand::by_score("dst", "src", ScoreBound::PosInfinity, ScoreBound::Exclusive(3.0))
+            .rev()
+            .limit(1, 2)
+            .encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(10, array.len());
+        assert_eq!("ZRANGESTORE", array[0].to_string().unwrap());
+        assert_eq!("dst", array[1].to_string().unwrap());
+        assert_eq!("src", array[2].to_string().unwrap());
+        assert_eq!("+inf", array[3].to_string().unwrap());
+        assert_eq!("(3", array[4].to_string().unwrap());
+        assert_eq!("BYSCORE", array[5].to_string().unwrap());
+        assert_eq!("REV", array[6].to_string().unwrap());
+        assert_eq!("LIMIT", array[7].to_string().unwrap());
+        assert_eq!("1", array[8].to_string().unwrap());
+        assert_eq!("2", array[9].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_encode_store_by_lex_resp3() {
+    let frame: Resp3Frame =
+        ZRangeStoreCommand::by_lex("dst", "src", LexBound::exclusive("a"), LexBound::Max).encode();
+
+    matches!(frame, Resp3Frame::Array { .. });
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(6, data.len());
+        assert_eq!("ZRANGESTORE", data[0].to_string().unwrap());
+        assert_eq!("dst", data[1].to_string().unwrap());
+        assert_eq!("src", data[2].to_string().unwrap());
+        assert_eq!("(a", data[3].to_string().unwrap());
+        assert_eq!("+", data[4].to_string().unwrap());
+        assert_eq!("BYLEX", data[5].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_eval_response_range_resp2() {
+    let command = ZRangeByScoreCommand::new("key", ScoreBound::NegInfinity, ScoreBound::PosInfinity);
+    let response = command
+        .eval_response(Resp2Frame::Array(vec![
+            Resp2Frame::BulkString("one".into()),
+            Resp2Frame::BulkString("two".into()),
+        ]))
+        .unwrap();
+
+    assert_eq!(vec!["one", "two"], response);
+}
+
+#[test]
+fn test_eval_response_range_resp3() {
+    let command = ZRangeByLexCommand::new("key", LexBound::Min, LexBound::Max);
+    let response = command
+        .eval_response(Resp3Frame::Array {
+            data: vec![Resp3Frame::BlobString {
+                data: "a".into(),
+                attributes: None,
+            }],
+            attributes: None,
+        })
+        .unwrap();
+
+    assert_eq!(vec!["a"], response);
+}
+
+#[test]
+fn test_eval_response_range_invalid() {
+    let command = ZRangeByLexCommand::new("key", LexBound::Min, LexBound::Max);
+    let response = command.eval_response(Resp2Frame::Integer(1));
+
+    assert!(response.is_err());
+}
+
+#[test]
+fn test_eval_response_store() {
+    let command = ZRangeStoreCommand::by_index("dst", "src", 0, -1);
+
+    assert_eq!(3, command.eval_response(Resp2Frame::Integer(3)).unwrap());
+    assert!(command.eval_response(Resp2Frame::BulkString("3".into())).is_err());
+}
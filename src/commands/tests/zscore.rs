@@ -0,0 +1,99 @@
+use crate::commands::helpers::CmdStr;
+use crate::commands::zscore::{SortedSetRemCommand, SortedSetScoreCommand};
+use crate::commands::Command;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
+
+#[test]
+fn test_score_encode_resp2() {
+    let frame: Resp2Frame = SortedSetScoreCommand::new("my_set", "first").encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(3, array.len());
+        assert_eq!("ZSCORE", array[0].to_string().unwrap());
+        assert_eq!("my_set", array[1].to_string().unwrap());
+        assert_eq!("first", array[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_score_encode_resp3() {
+    let frame: Resp3Frame = SortedSetScoreCommand::new("my_set", "first").encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("ZSCORE", data[0].to_string().unwrap());
+        assert_eq!("my_set", data[1].to_string().unwrap());
+        assert_eq!("first", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_score_eval_response_resp2() {
+    let response = SortedSetScoreCommand::new("my_set", "first").eval_response(CmdStr::new("3.5").to_bulk());
+
+    assert_eq!(Some(3.5), response.unwrap());
+}
+
+#[test]
+fn test_score_eval_response_resp3_double() {
+    let response = SortedSetScoreCommand::new("my_set", "first").eval_response(Resp3Frame::Double {
+        data: 3.5,
+        attributes: None,
+    });
+
+    assert_eq!(Some(3.5), response.unwrap());
+}
+
+#[test]
+fn test_score_eval_response_missing_member() {
+    let command = SortedSetScoreCommand::new("my_set", "first");
+
+    assert_eq!(None, command.eval_response(Resp2Frame::Null).unwrap());
+    assert_eq!(None, command.eval_response(Resp3Frame::Null).unwrap());
+}
+
+#[test]
+fn test_score_eval_response_invalid_response() {
+    let command = SortedSetScoreCommand::new("my_set", "first");
+
+    assert!(command.eval_response(CmdStr::new("not a number").to_bulk()).is_err());
+    assert!(command.eval_response(Resp2Frame::Integer(3)).is_err());
+}
+
+#[test]
+fn test_rem_encode_resp2() {
+    let frame: Resp2Frame = SortedSetRemCommand::new("my_set", ["first".into(), "second".into()]).encode();
+
+    assert!(matches!(frame, Resp2Frame::Array(_)));
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("ZREM", array[0].to_string().unwrap());
+        assert_eq!("my_set", array[1].to_string().unwrap());
+        assert_eq!("first", array[2].to_string().unwrap());
+        assert_eq!("second", array[3].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_rem_encode_resp3() {
+    let frame: Resp3Frame = SortedSetRemCommand::new("my_set", ["first".into()]).encode();
+
+    assert!(matches!(frame, Resp3Frame::Array { .. }));
+    if let Resp3Frame::Array { data, attributes: _ } = frame {
+        assert_eq!(3, data.len());
+        assert_eq!("ZREM", data[0].to_string().unwrap());
+        assert_eq!("my_set", data[1].to_string().unwrap());
+        assert_eq!("first", data[2].to_string().unwrap());
+    }
+}
+
+#[test]
+fn test_rem_eval_response() {
+    let command = SortedSetRemCommand::new("my_set", ["first".into()]);
+
+    assert_eq!(1, command.eval_response(Resp2Frame::Integer(1)).unwrap());
+    assert!(command.eval_response(Resp3Frame::Null).is_err());
+}
@@ -0,0 +1,220 @@
+//! Abstraction of TTL and PTTL commands.
+//!
+//! For general information about these commands, see the [Redis documentation](<https://redis.io/commands/ttl/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::set::{ExpirationPolicy, SetCommand};
+//!# use embedded_redis::commands::ttl::{PttlCommand, TtlCommand, TtlResponse};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!
+//! let command = SetCommand::new("ttl_key", "value").expires(ExpirationPolicy::Seconds(60));
+//! client.send(command).unwrap().wait().unwrap();
+//!
+//! // Remaining time to live in seconds
+//! let response = client.send(TtlCommand::new("ttl_key")).unwrap().wait().unwrap();
+//! assert!(matches!(response, TtlResponse::Duration(_)));
+//!
+//! // Remaining time to live in milliseconds
+//! let response = client.send(PttlCommand::new("not_existing")).unwrap().wait().unwrap();
+//! assert_eq!(TtlResponse::KeyMissing, response);
+//! ```
+//! # Shorthand
+//! [Client](Client#method.ttl) and [Client](Client#method.pttl) provide shorthand methods.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::ttl::TtlResponse;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! client.set("persistent_key", "value").unwrap().wait().unwrap();
+//!
+//! let response = client.ttl("persistent_key").unwrap().wait().unwrap();
+//! assert_eq!(TtlResponse::NoExpiry, response);
+//! ```
+//! # Remaining ratio
+//! [Client](Client#method.ttl_remaining_ratio) provides a blocking helper returning the fraction of the original
+//! TTL remaining, e.g. for refreshing cache entries proactively before they expire.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::set::{ExpirationPolicy, SetCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let command = SetCommand::new("cached_key", "value").expires(ExpirationPolicy::Seconds(60));
+//! client.send(command).unwrap().wait().unwrap();
+//!
+//! let ratio = client.ttl_remaining_ratio("cached_key", 60_000).unwrap().unwrap();
+//! if ratio < 0.2 {
+//!     // Refresh the entry
+//! }
+//!
+//! // No ratio for keys without expiry or missing keys
+//! assert_eq!(None, client.ttl_remaining_ratio("not_existing", 60_000).unwrap());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::future::Future;
+use crate::network::protocol::Protocol;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Response of TTL and PTTL commands
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TtlResponse {
+    /// Remaining time to live. Seconds in case of TTL, milliseconds in case of PTTL.
+    Duration(i64),
+    /// Key exists but has no associated expiry (-1)
+    NoExpiry,
+    /// Key does not exist (-2)
+    KeyMissing,
+}
+
+impl TtlResponse {
+    /// Maps the integer reply, which encodes the special cases as negative values
+    fn from_integer(value: i64) -> Result<Self, ResponseTypeError> {
+        match value {
+            -1 => Ok(TtlResponse::NoExpiry),
+            -2 => Ok(TtlResponse::KeyMissing),
+            value if value >= 0 => Ok(TtlResponse::Duration(value)),
+            _ => Err(ResponseTypeError {}),
+        }
+    }
+}
+
+/// Abstraction for TTL command
+pub struct TtlCommand {
+    key: Bytes,
+}
+
+impl TtlCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        TtlCommand { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for TtlCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Remaining time to live in seconds
+    type Response = TtlResponse;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("TTL").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        TtlResponse::from_integer(frame.to_integer().ok_or(ResponseTypeError {})?)
+    }
+}
+
+/// Abstraction for PTTL command
+pub struct PttlCommand {
+    key: Bytes,
+}
+
+impl PttlCommand {
+    pub fn new<K>(key: K) -> Self
+    where
+        Bytes: From<K>,
+    {
+        PttlCommand { key: key.into() }
+    }
+}
+
+impl<F> Command<F> for PttlCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Remaining time to live in milliseconds
+    type Response = TtlResponse;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("PTTL").arg(&self.key).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        TtlResponse::from_integer(frame.to_integer().ok_or(ResponseTypeError {})?)
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [TtlCommand]
+    pub fn ttl<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, TtlCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(TtlCommand::new(key))
+    }
+
+    /// Shorthand for [PttlCommand]
+    pub fn pttl<K>(&'a self, key: K) -> Result<Future<'a, N, C, P, PttlCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(PttlCommand::new(key))
+    }
+
+    /// Returns the fraction of the original TTL (in milliseconds) remaining, based on PTTL. Blocks until the
+    /// response is received.
+    ///
+    /// Returns None if the key is missing, has no expiry or the original TTL is zero. The ratio may exceed 1.0,
+    /// in case the current TTL is greater than the given original TTL.
+    pub fn ttl_remaining_ratio<K>(&'a self, key: K, original_ttl: usize) -> Result<Option<f32>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        if original_ttl == 0 {
+            return Ok(None);
+        }
+
+        match self.pttl(key)?.wait()? {
+            TtlResponse::Duration(remaining) => Ok(Some(remaining as f32 / original_ttl as f32)),
+            TtlResponse::NoExpiry | TtlResponse::KeyMissing => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Abstraction of WAIT command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/wait/>).
+//!
+//! # Basic usage
+//! Blocks until all previous write commands of this connection are acknowledged by the given number of replicas
+//! or the timeout (milliseconds) is reached. Returns the number of acknowledging replicas.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::wait::WaitCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("wait_key", "value").unwrap().wait().unwrap();
+//!
+//! let command = WaitCommand::new(0, 100);
+//! let replicas = client.send(command).unwrap().wait().unwrap();
+//! assert!(replicas >= 0);
+//! ```
+//! # Shorthand
+//! [Client](Client#method.wait_replicas) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let _ = client.wait_replicas(0, 100).unwrap().wait().unwrap();
+//! ```
+//! # Polling
+//! As WAIT is single-shot, [Client](Client#method.wait_replication) provides a blocking helper, which repeats WAIT
+//! until the requested replica count is reached or the client timeout is expired.
+//! ```no_run
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!# use embedded_time::duration::Extensions;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! connection_handler.timeout(1_000_000.microseconds());
+//!
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("wait_key", "value").unwrap().wait().unwrap();
+//!
+//! // Best replica count achieved within client timeout
+//! let replicas = client.wait_replication(2, 100).unwrap();
+//! ```
+//! # Durable writes
+//! [Client](Client#method.set_durable) combines SET and WAIT for critical state, which must not get lost on
+//! failover. An error is returned in case the write was not replicated in time.
+//! ```no_run
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::{CommandErrors, ConnectionHandler};
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! match client.set_durable("device_state", "armed", 1, 100) {
+//!     Ok(()) => {}
+//!     Err(CommandErrors::InsufficientReplicas(count)) => println!("replicated to {count} replica(s) only"),
+//!     Err(error) => println!("write failed: {error}"),
+//! }
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, IsNullFrame, ToInteger, ToStringBytes, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::timeout::Timeout;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of WAIT command
+pub struct WaitCommand {
+    /// Number of replicas to wait for
+    replicas: usize,
+
+    /// Server side timeout in milliseconds. 0 blocks forever.
+    milliseconds: usize,
+}
+
+impl WaitCommand {
+    pub fn new(replicas: usize, milliseconds: usize) -> Self {
+        Self {
+            replicas,
+            milliseconds,
+        }
+    }
+}
+
+impl<F> Command<F> for WaitCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Number of replicas acknowledging the previous writes
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("WAIT")
+            .arg_uint(self.replicas)
+            .arg_uint(self.milliseconds)
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [WaitCommand]
+    pub fn wait_replicas(
+        &'a self,
+        replicas: usize,
+        milliseconds: usize,
+    ) -> Result<Future<'a, N, C, P, WaitCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(WaitCommand::new(replicas, milliseconds))
+    }
+
+    /// Repeats WAIT command (using the given server side timeout in milliseconds) until at least the given
+    /// number of replicas acknowledged the previous writes or the client timeout is expired.
+    /// Returns the best achieved replica count.
+    ///
+    /// In case no client timeout is configured, WAIT is just executed once.
+    /// The server side timeout should be shorter than the client timeout, as otherwise the response is not awaited.
+    pub fn wait_replication(&'a self, replicas: usize, milliseconds: usize) -> Result<i64, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        let timeout = Timeout::new(self.clock, self.timeout_duration)?;
+        let mut best = 0;
+
+        loop {
+            best = best.max(self.wait_replicas(replicas, milliseconds)?.wait()?);
+
+            if best >= replicas as i64 || !timeout.is_active() || timeout.expired()? {
+                return Ok(best);
+            }
+        }
+    }
+
+    /// Sets the key and waits (using the given server side timeout in milliseconds) until at least the given
+    /// number of replicas acknowledged the write. Blocks until both commands are completed.
+    ///
+    /// Returns [CommandErrors::InsufficientReplicas] in case less replicas acknowledged the write in time.
+    /// The value is still set on the primary in this case.
+    pub fn set_durable<K, V>(
+        &'a self,
+        key: K,
+        value: V,
+        replicas: usize,
+        milliseconds: usize,
+    ) -> Result<(), CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: ToStringBytes,
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: IsNullFrame,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        self.set(key, value)?.wait()?;
+
+        let acknowledged = self.wait_replicas(replicas, milliseconds)?.wait()?;
+        if acknowledged < replicas as i64 {
+            return Err(CommandErrors::InsufficientReplicas(acknowledged));
+        }
+
+        Ok(())
+    }
+}
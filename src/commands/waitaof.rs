@@ -0,0 +1,122 @@
+//! Abstraction of WAITAOF command (Redis >= 7.2).
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/waitaof/>).
+//!
+//! # Basic usage
+//! Blocks until all previous write commands of this connection are fsynced to the AOF of the local server and/or
+//! the given number of replicas, or the timeout (milliseconds) is reached.
+//! Returns the number of local servers (0 or 1) and replicas which acknowledged the fsync.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::waitaof::WaitAofCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! client.set("waitaof_key", "value").unwrap().wait().unwrap();
+//!
+//! let command = WaitAofCommand::new(0, 0, 100);
+//! let (local, replicas) = client.send(command).unwrap().wait().unwrap();
+//! assert!(local >= 0 && replicas >= 0);
+//! ```
+//! # Timeout
+//! Like WAIT, the command blocks on server side up to the given timeout. A timeout of 0 blocks forever.
+//! The server side timeout should be shorter than the client timeout, as otherwise the response is not awaited
+//! and the future fails with [Timeout](crate::network::CommandErrors::Timeout).
+//!
+//! # Shorthand
+//! [Client](Client#method.wait_aof) provides a shorthand method for this command.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let _ = client.wait_aof(0, 0, 100).unwrap().wait().unwrap();
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToIntegerVec};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of WAITAOF command
+pub struct WaitAofCommand {
+    /// Number of local servers (0 or 1) to wait for
+    numlocal: usize,
+
+    /// Number of replicas to wait for
+    numreplicas: usize,
+
+    /// Server side timeout in milliseconds. 0 blocks forever.
+    milliseconds: usize,
+}
+
+impl WaitAofCommand {
+    pub fn new(numlocal: usize, numreplicas: usize, milliseconds: usize) -> Self {
+        Self {
+            numlocal,
+            numreplicas,
+            milliseconds,
+        }
+    }
+}
+
+impl<F> Command<F> for WaitAofCommand
+where
+    F: From<CommandBuilder> + ToIntegerVec,
+{
+    /// Number of local servers and replicas acknowledging the fsync of previous writes
+    type Response = (i64, i64);
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("WAITAOF")
+            .arg_uint(self.numlocal)
+            .arg_uint(self.numreplicas)
+            .arg_uint(self.milliseconds)
+            .into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        match frame.to_integer_vec().ok_or(ResponseTypeError {})?[..] {
+            [local, replicas] => Ok((local, replicas)),
+            _ => Err(ResponseTypeError {}),
+        }
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [WaitAofCommand]
+    pub fn wait_aof(
+        &'a self,
+        numlocal: usize,
+        numreplicas: usize,
+        milliseconds: usize,
+    ) -> Result<Future<'a, N, C, P, WaitAofCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToIntegerVec,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        self.send(WaitAofCommand::new(numlocal, numreplicas, milliseconds))
+    }
+}
@@ -0,0 +1,236 @@
+//! Abstraction of XADD command and stream based logging.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/xadd/>).
+//!
+//! # Adding entries
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::xadd::XAddCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!
+//! // Entry ID is generated by server, stream is capped to roughly 1000 entries
+//! let command = XAddCommand::new("sensor_stream", "temp", "21").field("unit", "C").maxlen_approx(1000);
+//! let id = client.send(command).unwrap().wait().unwrap();
+//! ```
+//! # Trimming
+//! [maxlen](XAddCommand::maxlen) trims the stream to exactly the given number of entries.
+//! [maxlen_approx](XAddCommand::maxlen_approx) uses the `~` modifier, which allows the server to trim whole
+//! macro nodes only. This is considerably more efficient, while the stream may slightly exceed the limit.
+//!
+//! # Logging
+//! [Logger] writes structured log entries to a capped stream, e.g. for collecting on-device logs centrally.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::xadd::{Logger, LogLevel};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let logger = Logger::new(&client, "device_logs", 10_000);
+//! logger.log(LogLevel::Warn, [("msg", "battery low"), ("voltage", "3.1")]).unwrap();
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToStringBytes};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::protocol::Protocol;
+use alloc::vec;
+use alloc::vec::Vec;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of XADD command
+pub struct XAddCommand {
+    stream: Bytes,
+
+    /// Entry ID. `*` for server generated ID.
+    id: Bytes,
+
+    /// Field/value pairs of the entry
+    fields: Vec<(Bytes, Bytes)>,
+
+    /// MAXLEN trimming option
+    max_len: Option<MaxLen>,
+}
+
+/// MAXLEN trimming option
+#[derive(Debug, Clone, Copy)]
+struct MaxLen {
+    threshold: usize,
+
+    /// `~` modifier
+    approximate: bool,
+}
+
+impl XAddCommand {
+    /// Creates a new entry with server generated ID, consisting of the given field/value pair
+    pub fn new<S, K, V>(stream: S, field: K, value: V) -> Self
+    where
+        Bytes: From<S>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        Self {
+            stream: stream.into(),
+            id: Bytes::from_static(b"*"),
+            fields: vec![(field.into(), value.into())],
+            max_len: None,
+        }
+    }
+
+    /// Adds a further field/value pair
+    pub fn field<K, V>(mut self, field: K, value: V) -> Self
+    where
+        Bytes: From<K>,
+        Bytes: From<V>,
+    {
+        self.fields.push((field.into(), value.into()));
+        self
+    }
+
+    /// Uses an explicit entry ID instead of a server generated one
+    pub fn id<I>(mut self, id: I) -> Self
+    where
+        Bytes: From<I>,
+    {
+        self.id = id.into();
+        self
+    }
+
+    /// Trims the stream to exactly the given number of entries
+    pub fn maxlen(mut self, threshold: usize) -> Self {
+        self.max_len = Some(MaxLen {
+            threshold,
+            approximate: false,
+        });
+        self
+    }
+
+    /// Trims the stream to roughly the given number of entries (`MAXLEN ~`)
+    pub fn maxlen_approx(mut self, threshold: usize) -> Self {
+        self.max_len = Some(MaxLen {
+            threshold,
+            approximate: true,
+        });
+        self
+    }
+}
+
+impl<F> Command<F> for XAddCommand
+where
+    F: From<CommandBuilder> + ToStringBytes,
+{
+    /// ID of the added entry
+    type Response = Bytes;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("XADD").arg(&self.stream);
+
+        if let Some(max_len) = self.max_len {
+            builder = builder
+                .arg_static("MAXLEN")
+                .arg_static_option(max_len.approximate.then_some("~"))
+                .arg_uint(max_len.threshold);
+        }
+
+        builder = builder.arg(&self.id);
+        for (field, value) in &self.fields {
+            builder = builder.arg(field).arg(value);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_string_bytes().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Severity of a log entry
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    fn to_arg(self) -> &'static str {
+        match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Trace => "TRACE",
+        }
+    }
+}
+
+/// Writes structured log entries to a stream, which is approximately capped to the given size
+pub struct Logger<'a, N: TcpClientStack, C: Clock, P: Protocol>
+where
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    client: &'a Client<'a, N, C, P>,
+    stream: Bytes,
+
+    /// Approximate max. number of entries kept
+    max_len: usize,
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Logger<'a, N, C, P>
+where
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Creates a new logger writing to the given stream, which is trimmed to roughly `max_len` entries
+    pub fn new<S>(client: &'a Client<'a, N, C, P>, stream: S, max_len: usize) -> Self
+    where
+        Bytes: From<S>,
+    {
+        Self {
+            client,
+            stream: stream.into(),
+            max_len,
+        }
+    }
+
+    /// Adds a log entry and returns its ID. Blocks until the entry is written.
+    ///
+    /// The level is stored in the `level` field, followed by the given field/value pairs.
+    pub fn log<K, V, I>(&self, level: LogLevel, fields: I) -> Result<Bytes, CommandErrors>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        Bytes: From<K>,
+        Bytes: From<V>,
+        AuthCommand: Command<<P as Protocol>::FrameType>,
+        <P as Protocol>::FrameType: From<CommandBuilder> + ToStringBytes,
+    {
+        let mut command =
+            XAddCommand::new(self.stream.clone(), "level", level.to_arg()).maxlen_approx(self.max_len);
+        for (field, value) in fields {
+            command = command.field(field, value);
+        }
+
+        self.client.send(command)?.wait()
+    }
+}
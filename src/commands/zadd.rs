@@ -0,0 +1,202 @@
+//! Abstraction of ZADD command.
+//!
+//! For general information about this command, see the [Redis documentation](<https://redis.io/commands/zadd/>).
+//!
+//! # Using command object
+//! Adds the score/member pairs to the sorted set. Scores of existing members are updated.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::zadd::SortedSetAddCommand;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["zadd_set".into()]).unwrap().wait().unwrap();
+//!
+//! let command = SortedSetAddCommand::new("zadd_set", [(1.0, "one".into()), (2.5, "two".into())]);
+//! let response = client.send(command).unwrap().wait().unwrap();
+//!
+//! // Number of new members
+//! assert_eq!(2, response);
+//! ```
+//! # Flags (NX, XX, GT, LT, CH)
+//! *GT, LT and NX options require Redis version >= 6.2*
+//!
+//! The behaviour may be adjusted by flags, s. [SortedSetAddFlag] enum. Flags are always encoded in the order
+//! required by Redis, regardless of the order they are added.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::zadd::{SortedSetAddCommand, SortedSetAddFlag};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["zadd_flags".into()]).unwrap().wait().unwrap();
+//!# client.send(SortedSetAddCommand::new("zadd_flags", [(5.0, "member".into())])).unwrap().wait().unwrap();
+//!#
+//! // Only raises the score of existing members and returns the number of changed members
+//! let command = SortedSetAddCommand::new("zadd_flags", [(10.0, "member".into()), (1.0, "new".into())])
+//!     .flag(SortedSetAddFlag::Changed)
+//!     .flag(SortedSetAddFlag::OnlyExisting)
+//!     .flag(SortedSetAddFlag::IfGreater);
+//!
+//! assert_eq!(1, client.send(command).unwrap().wait().unwrap());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.zadd) provides a shorthand method for adding a single member.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["zadd_shorthand".into()]).unwrap().wait().unwrap();
+//!#
+//! assert_eq!(1, client.zadd("zadd_shorthand", 0.5, "member").unwrap().wait().unwrap());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Flags of ZADD command
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SortedSetAddFlag {
+    /// NX option: Only add new members, never update existing ones
+    OnlyNew,
+    /// XX option: Only update existing members, never add new ones
+    OnlyExisting,
+    /// GT option: Only update existing members if the new score is greater. New members are still added.
+    IfGreater,
+    /// LT option: Only update existing members if the new score is less. New members are still added.
+    IfLess,
+    /// CH option: Return the number of changed (added and updated) members instead of just the added ones
+    Changed,
+}
+
+impl SortedSetAddFlag {
+    /// All flags in the order expected by Redis
+    const ORDER: [SortedSetAddFlag; 5] = [
+        SortedSetAddFlag::OnlyNew,
+        SortedSetAddFlag::OnlyExisting,
+        SortedSetAddFlag::IfGreater,
+        SortedSetAddFlag::IfLess,
+        SortedSetAddFlag::Changed,
+    ];
+
+    /// Returns the argument of the flag
+    fn option(&self) -> &'static str {
+        match self {
+            SortedSetAddFlag::OnlyNew => "NX",
+            SortedSetAddFlag::OnlyExisting => "XX",
+            SortedSetAddFlag::IfGreater => "GT",
+            SortedSetAddFlag::IfLess => "LT",
+            SortedSetAddFlag::Changed => "CH",
+        }
+    }
+}
+
+/// Abstraction of ZADD command
+pub struct SortedSetAddCommand<const N: usize> {
+    /// Sorted set key
+    key: Bytes,
+
+    /// Score/member pairs
+    members: [(f64, Bytes); N],
+
+    flags: Vec<SortedSetAddFlag>,
+}
+
+impl<const N: usize> SortedSetAddCommand<N> {
+    pub fn new<K>(key: K, members: [(f64, Bytes); N]) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            key: key.into(),
+            members,
+            flags: Vec::new(),
+        }
+    }
+
+    /// Adds the given flag. Redis rejects incompatible combinations (e.g. NX together with XX, GT or LT).
+    pub fn flag(mut self, flag: SortedSetAddFlag) -> Self {
+        if !self.flags.contains(&flag) {
+            self.flags.push(flag);
+        }
+        self
+    }
+}
+
+impl<F: From<CommandBuilder> + ToInteger, const N: usize> Command<F> for SortedSetAddCommand<N> {
+    /// Number of added members. In case of [CH flag](SortedSetAddFlag::Changed), the number of added and
+    /// updated members.
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("ZADD").arg(&self.key);
+
+        for flag in SortedSetAddFlag::ORDER.iter().filter(|flag| self.flags.contains(flag)) {
+            builder = builder.arg_static(flag.option());
+        }
+
+        for (score, member) in &self.members {
+            // Display formatting of f64 is locale independent and never uses an exponent
+            builder = builder.arg(&score.to_string().into()).arg(member);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [SortedSetAddCommand] adding a single member
+    /// For adding multiple members or using flags, use [SortedSetAddCommand] directly instead
+    pub fn zadd<K, M>(
+        &'a self,
+        key: K,
+        score: f64,
+        member: M,
+    ) -> Result<Future<'a, N, C, P, SortedSetAddCommand<1>>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        Bytes: From<M>,
+    {
+        self.send(SortedSetAddCommand::new(key, [(score, member.into())]))
+    }
+}
@@ -0,0 +1,457 @@
+//! Abstraction of ZRANGEBYSCORE, ZRANGEBYLEX and ZRANGESTORE commands.
+//!
+//! For general information about these commands, see the Redis documentation of
+//! [ZRANGEBYSCORE](<https://redis.io/commands/zrangebyscore/>),
+//! [ZRANGEBYLEX](<https://redis.io/commands/zrangebylex/>) and
+//! [ZRANGESTORE](<https://redis.io/commands/zrangestore/>).
+//!
+//! Range boundaries are defined by [ScoreBound] and [LexBound].
+//!
+//! # Range by score
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::commands::zrange_ext::{ScoreBound, ZRangeByScoreCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.send(CommandBuilder::new("DEL").arg_static("scores").to_command()).unwrap().wait().unwrap();
+//!# client.send(CommandBuilder::new("ZADD").arg_static("scores").arg_static("1").arg_static("one")
+//!#   .arg_static("2").arg_static("two").arg_static("3").arg_static("three").to_command()).unwrap().wait().unwrap();
+//!
+//! // Members with 1 < score <= +inf
+//! let command = ZRangeByScoreCommand::new("scores", ScoreBound::Exclusive(1.0), ScoreBound::PosInfinity);
+//! let response = client.send(command).unwrap().wait().unwrap();
+//! assert_eq!(vec!["two", "three"], response);
+//!
+//! // Skipping the first member and returning at most one member
+//! let command = ZRangeByScoreCommand::new("scores", ScoreBound::NegInfinity, ScoreBound::PosInfinity).limit(1, 1);
+//! let response = client.send(command).unwrap().wait().unwrap();
+//! assert_eq!(vec!["two"], response);
+//! ```
+//! # Range by lexicographical order
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::commands::zrange_ext::{LexBound, ZRangeByLexCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.send(CommandBuilder::new("DEL").arg_static("names").to_command()).unwrap().wait().unwrap();
+//!# client.send(CommandBuilder::new("ZADD").arg_static("names").arg_static("0").arg_static("a")
+//!#   .arg_static("0").arg_static("b").arg_static("0").arg_static("c").to_command()).unwrap().wait().unwrap();
+//!#
+//! // Members in range [b, +]
+//! let command = ZRangeByLexCommand::new("names", LexBound::inclusive("b"), LexBound::Max);
+//! let response = client.send(command).unwrap().wait().unwrap();
+//! assert_eq!(vec!["b", "c"], response);
+//! ```
+//! # Storing a range
+//! *Requires Redis version >= 6.2*
+//!
+//! The resulting range is stored at the destination key. Only the number of stored elements is returned.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::commands::zrange_ext::{ScoreBound, ZRangeStoreCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.send(CommandBuilder::new("DEL").arg_static("source").to_command()).unwrap().wait().unwrap();
+//!# client.send(CommandBuilder::new("ZADD").arg_static("source").arg_static("1").arg_static("one")
+//!#   .arg_static("2").arg_static("two").to_command()).unwrap().wait().unwrap();
+//!#
+//! // By index (first two elements)
+//! let command = ZRangeStoreCommand::by_index("destination", "source", 0, 1);
+//! assert_eq!(2, client.send(command).unwrap().wait().unwrap());
+//!
+//! // By score
+//! let command = ZRangeStoreCommand::by_score("destination", "source", ScoreBound::Inclusive(2.0), ScoreBound::PosInfinity);
+//! assert_eq!(1, client.send(command).unwrap().wait().unwrap());
+//! ```
+//! # Shorthand
+//! [Client](Client#method.zrangebyscore) provides shorthand methods for ZRANGEBYSCORE and ZRANGEBYLEX.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::zrange_ext::{LexBound, ScoreBound};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! let _ = client.zrangebyscore("scores", ScoreBound::NegInfinity, ScoreBound::Inclusive(10.5));
+//! let _ = client.zrangebylex("names", LexBound::Min, LexBound::exclusive("c"));
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToBytesVec, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use bytes::{BufMut, Bytes, BytesMut};
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Score boundary of a sorted set range
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScoreBound {
+    /// Score is included in the range
+    Inclusive(f64),
+    /// Score is excluded from the range
+    Exclusive(f64),
+    /// Lowest possible score (-inf)
+    NegInfinity,
+    /// Highest possible score (+inf)
+    PosInfinity,
+}
+
+impl ScoreBound {
+    /// Returns the argument representation of the bound
+    pub(crate) fn encode(&self) -> Bytes {
+        match self {
+            ScoreBound::Inclusive(score) => format!("{}", score).into(),
+            ScoreBound::Exclusive(score) => format!("({}", score).into(),
+            ScoreBound::NegInfinity => Bytes::from_static(b"-inf"),
+            ScoreBound::PosInfinity => Bytes::from_static(b"+inf"),
+        }
+    }
+}
+
+/// Lexicographical boundary of a sorted set range
+#[derive(Clone, Debug, PartialEq)]
+pub enum LexBound {
+    /// Value is included in the range
+    Inclusive(Bytes),
+    /// Value is excluded from the range
+    Exclusive(Bytes),
+    /// Lowest possible value (-)
+    Min,
+    /// Highest possible value (+)
+    Max,
+}
+
+impl LexBound {
+    pub fn inclusive<V>(value: V) -> Self
+    where
+        Bytes: From<V>,
+    {
+        LexBound::Inclusive(value.into())
+    }
+
+    pub fn exclusive<V>(value: V) -> Self
+    where
+        Bytes: From<V>,
+    {
+        LexBound::Exclusive(value.into())
+    }
+
+    /// Returns the argument representation of the bound
+    pub(crate) fn encode(&self) -> Bytes {
+        match self {
+            LexBound::Inclusive(value) => prefixed(b'[', value),
+            LexBound::Exclusive(value) => prefixed(b'(', value),
+            LexBound::Min => Bytes::from_static(b"-"),
+            LexBound::Max => Bytes::from_static(b"+"),
+        }
+    }
+}
+
+/// Prepends the given prefix to the value
+fn prefixed(prefix: u8, value: &Bytes) -> Bytes {
+    let mut buffer = BytesMut::with_capacity(value.len() + 1);
+    buffer.put_u8(prefix);
+    buffer.put_slice(value);
+    buffer.freeze()
+}
+
+/// LIMIT option: Offset and maximum count of the returned elements
+#[derive(Clone, Debug)]
+struct Limit {
+    offset: usize,
+    count: usize,
+}
+
+impl Limit {
+    fn append(limit: &Option<Limit>, builder: CommandBuilder) -> CommandBuilder {
+        match limit {
+            None => builder,
+            Some(limit) => builder.arg_static("LIMIT").arg_uint(limit.offset).arg_uint(limit.count),
+        }
+    }
+}
+
+/// Abstraction of ZRANGEBYSCORE command
+pub struct ZRangeByScoreCommand {
+    key: Bytes,
+    min: ScoreBound,
+    max: ScoreBound,
+    limit: Option<Limit>,
+}
+
+impl ZRangeByScoreCommand {
+    pub fn new<K>(key: K, min: ScoreBound, max: ScoreBound) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            key: key.into(),
+            min,
+            max,
+            limit: None,
+        }
+    }
+
+    /// Skips `offset` elements and returns at most `count` elements
+    pub fn limit(mut self, offset: usize, count: usize) -> Self {
+        self.limit = Some(Limit { offset, count });
+        self
+    }
+}
+
+impl<F> Command<F> for ZRangeByScoreCommand
+where
+    F: From<CommandBuilder> + ToBytesVec,
+{
+    /// Members in the given range, ordered from low to high score
+    type Response = Vec<Bytes>;
+
+    fn encode(&self) -> F {
+        let builder = CommandBuilder::new("ZRANGEBYSCORE")
+            .arg(&self.key)
+            .arg(&self.min.encode())
+            .arg(&self.max.encode());
+
+        Limit::append(&self.limit, builder).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bytes_vec().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Abstraction of ZRANGEBYLEX command
+pub struct ZRangeByLexCommand {
+    key: Bytes,
+    min: LexBound,
+    max: LexBound,
+    limit: Option<Limit>,
+}
+
+impl ZRangeByLexCommand {
+    pub fn new<K>(key: K, min: LexBound, max: LexBound) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            key: key.into(),
+            min,
+            max,
+            limit: None,
+        }
+    }
+
+    /// Skips `offset` elements and returns at most `count` elements
+    pub fn limit(mut self, offset: usize, count: usize) -> Self {
+        self.limit = Some(Limit { offset, count });
+        self
+    }
+}
+
+impl<F> Command<F> for ZRangeByLexCommand
+where
+    F: From<CommandBuilder> + ToBytesVec,
+{
+    /// Members in the given range, in lexicographical order
+    type Response = Vec<Bytes>;
+
+    fn encode(&self) -> F {
+        let builder = CommandBuilder::new("ZRANGEBYLEX")
+            .arg(&self.key)
+            .arg(&self.min.encode())
+            .arg(&self.max.encode());
+
+        Limit::append(&self.limit, builder).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_bytes_vec().ok_or(ResponseTypeError {})
+    }
+}
+
+/// Abstraction of ZRANGESTORE command
+/// *Requires Redis version >= 6.2*
+pub struct ZRangeStoreCommand {
+    destination: Bytes,
+    source: Bytes,
+
+    /// Encoded min/start argument
+    min: Bytes,
+
+    /// Encoded max/stop argument
+    max: Bytes,
+
+    /// BYSCORE or BYLEX option. None in case of index range.
+    range_type: Option<&'static str>,
+
+    reverse: bool,
+    limit: Option<Limit>,
+}
+
+impl ZRangeStoreCommand {
+    /// Stores the elements between the given (zero-based) indexes. Negative indexes count from the end.
+    pub fn by_index<D, S>(destination: D, source: S, start: i64, stop: i64) -> Self
+    where
+        Bytes: From<D>,
+        Bytes: From<S>,
+    {
+        Self::new(
+            destination,
+            source,
+            start.to_string().into(),
+            stop.to_string().into(),
+            None,
+        )
+    }
+
+    /// Stores the elements in the given score range (BYSCORE option)
+    pub fn by_score<D, S>(destination: D, source: S, min: ScoreBound, max: ScoreBound) -> Self
+    where
+        Bytes: From<D>,
+        Bytes: From<S>,
+    {
+        Self::new(destination, source, min.encode(), max.encode(), Some("BYSCORE"))
+    }
+
+    /// Stores the elements in the given lexicographical range (BYLEX option)
+    pub fn by_lex<D, S>(destination: D, source: S, min: LexBound, max: LexBound) -> Self
+    where
+        Bytes: From<D>,
+        Bytes: From<S>,
+    {
+        Self::new(destination, source, min.encode(), max.encode(), Some("BYLEX"))
+    }
+
+    /// REV option: Reverses the ordering. The bounds need to be passed in reversed order (max first).
+    pub fn rev(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    /// Skips `offset` elements and stores at most `count` elements.
+    /// Only supported for score and lexicographical ranges.
+    pub fn limit(mut self, offset: usize, count: usize) -> Self {
+        self.limit = Some(Limit { offset, count });
+        self
+    }
+
+    fn new<D, S>(destination: D, source: S, min: Bytes, max: Bytes, range_type: Option<&'static str>) -> Self
+    where
+        Bytes: From<D>,
+        Bytes: From<S>,
+    {
+        Self {
+            destination: destination.into(),
+            source: source.into(),
+            min,
+            max,
+            range_type,
+            reverse: false,
+            limit: None,
+        }
+    }
+}
+
+impl<F> Command<F> for ZRangeStoreCommand
+where
+    F: From<CommandBuilder> + ToInteger,
+{
+    /// Number of elements in the resulting sorted set
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("ZRANGESTORE")
+            .arg(&self.destination)
+            .arg(&self.source)
+            .arg(&self.min)
+            .arg(&self.max)
+            .arg_static_option(self.range_type);
+
+        if self.reverse {
+            builder = builder.arg_static("REV");
+        }
+
+        Limit::append(&self.limit, builder).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [ZRangeByScoreCommand]
+    /// For using LIMIT option, use [ZRangeByScoreCommand] directly instead
+    pub fn zrangebyscore<K>(
+        &'a self,
+        key: K,
+        min: ScoreBound,
+        max: ScoreBound,
+    ) -> Result<Future<'a, N, C, P, ZRangeByScoreCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToBytesVec,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(ZRangeByScoreCommand::new(key, min, max))
+    }
+
+    /// Shorthand for [ZRangeByLexCommand]
+    /// For using LIMIT option, use [ZRangeByLexCommand] directly instead
+    pub fn zrangebylex<K>(
+        &'a self,
+        key: K,
+        min: LexBound,
+        max: LexBound,
+    ) -> Result<Future<'a, N, C, P, ZRangeByLexCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToBytesVec,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+    {
+        self.send(ZRangeByLexCommand::new(key, min, max))
+    }
+}
@@ -0,0 +1,179 @@
+//! Abstraction of ZSCORE and ZREM commands.
+//!
+//! For general information about these commands, see the [Redis documentation](<https://redis.io/commands/zscore/>)
+//! and [ZREM](<https://redis.io/commands/zrem/>).
+//!
+//! # Using command object
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::zadd::SortedSetAddCommand;
+//!# use embedded_redis::commands::zscore::{SortedSetRemCommand, SortedSetScoreCommand};
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//! let mut stack = Stack::default();
+//! let clock = StandardClock::default();
+//!
+//! let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!# client.del(["zscore_set".into()]).unwrap().wait().unwrap();
+//!
+//! client.send(SortedSetAddCommand::new("zscore_set", [(3.5, "first".into()), (1.0, "second".into())])).unwrap().wait().unwrap();
+//!
+//! let score = client.send(SortedSetScoreCommand::new("zscore_set", "first")).unwrap().wait().unwrap();
+//! assert_eq!(Some(3.5), score);
+//!
+//! // Returns the number of removed members
+//! let command = SortedSetRemCommand::new("zscore_set", ["first".into(), "not_existing".into()]);
+//! assert_eq!(1, client.send(command).unwrap().wait().unwrap());
+//! ```
+//! # Missing member
+//! In case of missing key or member, ZSCORE returns `None`.
+//!
+//! # Shorthand
+//! [Client](Client#method.zscore) and [Client](Client#method.zrem) provide shorthand methods.
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let mut connection_handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+//!# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//!#
+//! assert_eq!(None, client.zscore("missing_set", "member").unwrap().wait().unwrap());
+//! assert_eq!(0, client.zrem("missing_set", "member").unwrap().wait().unwrap());
+//! ```
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, IsNullFrame, ToDouble, ToInteger};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::protocol::Protocol;
+use crate::network::{Client, CommandErrors, Future};
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Abstraction of ZSCORE command
+pub struct SortedSetScoreCommand {
+    /// Sorted set key
+    key: Bytes,
+
+    member: Bytes,
+}
+
+impl SortedSetScoreCommand {
+    pub fn new<K, M>(key: K, member: M) -> Self
+    where
+        Bytes: From<K>,
+        Bytes: From<M>,
+    {
+        Self {
+            key: key.into(),
+            member: member.into(),
+        }
+    }
+}
+
+impl<F> Command<F> for SortedSetScoreCommand
+where
+    F: From<CommandBuilder> + IsNullFrame + ToDouble,
+{
+    /// Score of the member. None in case of missing key or member.
+    type Response = Option<f64>;
+
+    fn encode(&self) -> F {
+        CommandBuilder::new("ZSCORE").arg(&self.key).arg(&self.member).into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        if frame.is_null_frame() {
+            return Ok(None);
+        }
+
+        Ok(Some(frame.to_double().ok_or(ResponseTypeError {})?))
+    }
+}
+
+/// Abstraction of ZREM command
+pub struct SortedSetRemCommand<const N: usize> {
+    /// Sorted set key
+    key: Bytes,
+
+    /// Members to remove
+    members: [Bytes; N],
+}
+
+impl<const N: usize> SortedSetRemCommand<N> {
+    pub fn new<K>(key: K, members: [Bytes; N]) -> Self
+    where
+        Bytes: From<K>,
+    {
+        Self {
+            key: key.into(),
+            members,
+        }
+    }
+}
+
+impl<F: From<CommandBuilder> + ToInteger, const N: usize> Command<F> for SortedSetRemCommand<N> {
+    /// Number of removed members, not including non existing members
+    type Response = i64;
+
+    fn encode(&self) -> F {
+        let mut builder = CommandBuilder::new("ZREM").arg(&self.key);
+
+        for member in &self.members {
+            builder = builder.arg(member);
+        }
+
+        builder.into()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        frame.to_integer().ok_or(ResponseTypeError {})
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Shorthand for [SortedSetScoreCommand]
+    pub fn zscore<K, M>(
+        &'a self,
+        key: K,
+        member: M,
+    ) -> Result<Future<'a, N, C, P, SortedSetScoreCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: IsNullFrame + ToDouble,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        Bytes: From<M>,
+    {
+        self.send(SortedSetScoreCommand::new(key, member))
+    }
+
+    /// Shorthand for [SortedSetRemCommand] removing a single member
+    /// For removing multiple members, use [SortedSetRemCommand] directly instead
+    pub fn zrem<K, M>(
+        &'a self,
+        key: K,
+        member: M,
+    ) -> Result<Future<'a, N, C, P, SortedSetRemCommand<1>>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: ToInteger,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        Bytes: From<K>,
+        Bytes: From<M>,
+    {
+        self.send(SortedSetRemCommand::new(key, [member.into()]))
+    }
+}
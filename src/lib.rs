@@ -81,6 +81,35 @@ pub mod commands;
 ///
 /// ConnectionHandler is caching the connection, so later recreation of new Clients is cheap.
 ///
+/// ### Protocol detection
+///
+/// In case the server capabilities are unknown, the supported protocol may be detected by
+/// [detect_protocol](crate::network::ConnectionHandler::detect_protocol). RESP3 connections are ready to use,
+/// while RESP2 servers require a new handler:
+/// ```
+///# use core::str::FromStr;
+///# use core::net::SocketAddr;
+///# use std_embedded_nal::Stack;
+///# use std_embedded_time::StandardClock;
+///# use embedded_redis::network::{ConnectionHandler, DetectedProtocol};
+///#
+///# let mut network_stack = Stack::default();
+///# let clock = StandardClock::default();
+///#
+///# let server_address = SocketAddr::from_str("127.0.0.1:6379").unwrap();
+/// let mut connection_handler = ConnectionHandler::auto(server_address);
+///
+/// match connection_handler.detect_protocol(&mut network_stack, Some(&clock)).unwrap() {
+///     DetectedProtocol::Resp3 => {
+///         let _client = connection_handler.connect(&mut network_stack, Some(&clock)).unwrap();
+///     }
+///     DetectedProtocol::Resp2 => {
+///         let mut connection_handler = ConnectionHandler::resp2(server_address);
+///         let _client = connection_handler.connect(&mut network_stack, Some(&clock)).unwrap();
+///     }
+/// }
+/// ```
+///
 /// ### Authentication
 ///
 /// Authentication is done in the following way:
@@ -107,6 +136,9 @@ pub mod commands;
 /// connection_handler.auth(Credentials::acl("user01", "secret123!"));
 /// # let _client = connection_handler.connect(&mut network_stack, Some(&clock));
 /// ```
+///
+/// Rotating credentials (e.g. short-lived tokens) may be applied to the cached connection by
+/// [reauth](network::ConnectionHandler::reauth). The new credentials are stored and used for future connections.
 /// ### Timeout
 ///
 /// The client includes a timeout mechanism. This allows setting a time limit for responses from the Redis server:
@@ -183,6 +215,29 @@ pub mod commands;
 ///# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
 /// ````
 ///
+/// ### Cooperative scheduling
+///
+/// Blocking waits are polling the network stack. On cooperative systems, a callback may be set for yielding to the
+/// scheduler each time no data is pending.
+///
+/// ````
+///# use core::str::FromStr;
+///# use core::net::SocketAddr;
+///# use std_embedded_nal::Stack;
+///# use std_embedded_time::StandardClock;
+///# use embedded_redis::network::ConnectionHandler;
+///#
+///# let mut stack = Stack::default();
+///# let clock = StandardClock::default();
+///#
+///# let server_address = SocketAddr::from_str("127.0.0.1:6379").unwrap();
+/// let mut connection_handler = ConnectionHandler::resp2(server_address);
+///
+/// connection_handler.on_wait(|| std::thread::yield_now());
+///
+///# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+/// ````
+///
 /// ### Concurrency
 ///
 /// While the Client is not Send, the connection handler is.
@@ -239,6 +294,84 @@ pub mod commands;
 /// }
 /// ```
 ///
+/// ### Pipelining
+/// Multiple commands may be sent at once before waiting for all responses.
+/// A [Pipeline](crate::network::Pipeline) of raw commands returns the response frames in order, while a tuple of
+/// typed commands returns the tuple of typed responses.
+/// ```
+///# use core::str::FromStr;
+///# use core::net::SocketAddr;
+///# use std_embedded_nal::Stack;
+///# use std_embedded_time::StandardClock;
+///# use embedded_redis::commands::builder::CommandBuilder;
+///# use embedded_redis::commands::get::GetCommand;
+///# use embedded_redis::network::{ConnectionHandler, Pipeline};
+///#
+///# let mut stack = Stack::default();
+///# let clock = StandardClock::default();
+///#
+///# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+///# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+///# client.set("pipeline_key", "value").unwrap().wait().unwrap();
+///#
+/// let pipeline = Pipeline::new()
+///     .push(CommandBuilder::new("GET").arg_static("pipeline_key"))
+///     .push(CommandBuilder::new("INCR").arg_static("pipeline_counter"));
+/// let frames = client.pipeline(pipeline).unwrap();
+/// assert_eq!(2, frames.len());
+///
+/// let get = GetCommand::static_key("pipeline_key");
+/// let incr = CommandBuilder::new("INCR").arg_static("pipeline_counter").to_command().expect_integer();
+/// let (value, counter) = client.typed_pipeline((get, incr)).unwrap();
+/// assert_eq!("value", value.unwrap().as_str().unwrap());
+/// ```
+///
+/// ### Profiling
+/// For locating bottlenecks on target, [profile](crate::network::Client::profile) executes a command blocking and
+/// measures the time spent on encoding, sending and waiting for the response.
+/// ```
+///# use core::str::FromStr;
+///# use core::net::SocketAddr;
+///# use std_embedded_nal::Stack;
+///# use std_embedded_time::StandardClock;
+///# use embedded_redis::commands::get::GetCommand;
+///# use embedded_redis::network::ConnectionHandler;
+///#
+///# let mut stack = Stack::default();
+///# let clock = StandardClock::default();
+///#
+///# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+///# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+///#
+/// let (_response, timings) = client.profile(GetCommand::static_key("profile_key")).unwrap();
+/// println!("Encoding: {}, sending: {}, waiting: {}", timings.encode, timings.send, timings.wait);
+/// ```
+///
+/// ### Retries
+/// [send_with_policy](crate::network::Client::send_with_policy) repeats a command on the error kinds given
+/// by a [RetryPolicy](crate::network::RetryPolicy). Only idempotent commands should be retried, as a failed
+/// attempt may still have been executed by the server.
+/// ```
+///# use core::str::FromStr;
+///# use core::net::SocketAddr;
+///# use std_embedded_nal::Stack;
+///# use std_embedded_time::StandardClock;
+///# use embedded_redis::commands::get::GetCommand;
+///# use embedded_redis::network::{CommandErrors, ConnectionHandler, RetryPolicy};
+///#
+///# let mut stack = Stack::default();
+///# let clock = StandardClock::default();
+///#
+///# let mut connection_handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+///# let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+///#
+/// let policy = RetryPolicy {
+///     attempts: 3,
+///     on: &[CommandErrors::Timeout, CommandErrors::TcpError],
+/// };
+/// let _response = client.send_with_policy(GetCommand::static_key("retry_key"), policy).unwrap();
+/// ```
+///
 /// ### Response type
 ///
 /// Response type dependents on executed command abstractions, e.g. [GetResponse](crate::commands::get::GetResponse)
@@ -1,7 +1,14 @@
 use crate::network::client::CommandErrors;
-use crate::network::future::Identity;
+use crate::network::future::{Identity, TimeoutHook, TimeoutInfo};
+#[cfg(feature = "metrics")]
+use crate::network::metrics::{self, CommandMetric, Metrics};
+use crate::network::pool::BufferSlot;
 use crate::network::protocol::Protocol;
 use crate::network::response::{MemoryParameters, ResponseBuffer};
+#[cfg(feature = "metrics")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "metrics")]
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 use bytes::BytesMut;
@@ -11,12 +18,15 @@ use core::ops::{Deref, DerefMut};
 use embedded_nal::TcpClientStack;
 use redis_protocol::error::RedisProtocolErrorKind::BufferTooSmall;
 
+/// Default max. encoded size of a single command in bytes, s. [Network::max_command_size]
+pub(crate) const DEFAULT_MAX_COMMAND_SIZE: usize = 4096;
+
 /// Manges interaction between network stack and response buffer
 pub(crate) struct Network<'a, N: TcpClientStack, P: Protocol> {
     protocol: P,
     stack: RefCell<&'a mut N>,
     socket: RefCell<&'a mut N::TcpSocket>,
-    buffer: RefCell<ResponseBuffer<P>>,
+    buffer: BufferSlot<'a, P>,
 
     /// Current valid Future series
     current_series: RefCell<usize>,
@@ -30,6 +40,22 @@ pub(crate) struct Network<'a, N: TcpClientStack, P: Protocol> {
     /// List of dropped futures, which did not call wait()
     /// For not leaking memory, response data of this futures is dropped on next send() call
     dropped_futures: RefCell<Vec<Identity>>,
+
+    /// Optional callback invoked while blocking waits for pending data
+    wait_hook: RefCell<Option<&'a mut (dyn FnMut() + Send)>>,
+
+    /// Optional callback invoked once a command timed out
+    timeout_hook: RefCell<Option<&'a mut TimeoutHook>>,
+
+    /// Max. encoded size of a single command, as respected by batching helpers
+    max_command_size: usize,
+
+    /// Reject keys and channels containing CR or LF
+    validate_keys: bool,
+
+    /// Per-command metrics
+    #[cfg(feature = "metrics")]
+    metrics: RefCell<Metrics>,
 }
 
 impl<'a, N: TcpClientStack, P: Protocol> Network<'a, N, P> {
@@ -38,16 +64,92 @@ impl<'a, N: TcpClientStack, P: Protocol> Network<'a, N, P> {
         socket: RefCell<&'a mut N::TcpSocket>,
         protocol: P,
         memory: MemoryParameters,
+    ) -> Self {
+        let buffer = BufferSlot::Owned(RefCell::new(ResponseBuffer::new(protocol.clone(), memory)));
+        Self::with_buffer(stack, socket, protocol, buffer)
+    }
+
+    /// Creates a new instance using the given lent response buffer, s. [BufferPool](crate::network::pool::BufferPool)
+    pub(crate) fn pooled(
+        stack: RefCell<&'a mut N>,
+        socket: RefCell<&'a mut N::TcpSocket>,
+        protocol: P,
+        buffer: &'a RefCell<ResponseBuffer<P>>,
+    ) -> Self {
+        Self::with_buffer(stack, socket, protocol, BufferSlot::Lent(buffer))
+    }
+
+    fn with_buffer(
+        stack: RefCell<&'a mut N>,
+        socket: RefCell<&'a mut N::TcpSocket>,
+        protocol: P,
+        buffer: BufferSlot<'a, P>,
     ) -> Self {
         Network {
-            protocol: protocol.clone(),
+            protocol,
             stack,
             socket,
-            buffer: RefCell::new(ResponseBuffer::new(protocol, memory)),
+            buffer,
             current_series: RefCell::new(0),
             next_index: RefCell::new(0),
             clear_buffer: RefCell::new(false),
             dropped_futures: RefCell::new(vec![]),
+            wait_hook: RefCell::new(None),
+            timeout_hook: RefCell::new(None),
+            max_command_size: DEFAULT_MAX_COMMAND_SIZE,
+            validate_keys: false,
+            #[cfg(feature = "metrics")]
+            metrics: RefCell::new(Metrics::default()),
+        }
+    }
+
+    /// Enables decoding of inline responses without type prefix
+    pub(crate) fn with_inline_responses(self, enabled: bool) -> Self {
+        self.buffer.borrow_mut().accept_inline(enabled);
+        self
+    }
+
+    /// Sets the max. encoded size of a single command
+    pub(crate) fn with_max_command_size(mut self, size: usize) -> Self {
+        self.max_command_size = size;
+        self
+    }
+
+    /// Enables rejecting keys and channels containing CR or LF
+    pub(crate) fn with_key_validation(mut self, enabled: bool) -> Self {
+        self.validate_keys = enabled;
+        self
+    }
+
+    /// Max. encoded size of a single command in bytes. Batching helpers (e.g. DEL of many keys) split their
+    /// commands accordingly.
+    pub(crate) fn max_command_size(&self) -> usize {
+        self.max_command_size
+    }
+
+    /// Sets the callback invoked while blocking waits for pending data
+    pub(crate) fn with_wait_hook(self, hook: Option<&'a mut (dyn FnMut() + Send)>) -> Self {
+        *self.wait_hook.borrow_mut() = hook;
+        self
+    }
+
+    /// Invokes the wait hook, if set
+    pub(crate) fn yield_wait(&self) {
+        if let Some(hook) = self.wait_hook.borrow_mut().as_mut() {
+            hook();
+        }
+    }
+
+    /// Sets the callback invoked once a command timed out
+    pub(crate) fn with_timeout_hook(self, hook: Option<&'a mut TimeoutHook>) -> Self {
+        *self.timeout_hook.borrow_mut() = hook;
+        self
+    }
+
+    /// Invokes the timeout hook, if set
+    pub(crate) fn notify_timeout(&self, id: &Identity) {
+        if let Some(hook) = self.timeout_hook.borrow_mut().as_mut() {
+            hook(&TimeoutInfo::from(id));
         }
     }
 
@@ -73,6 +175,84 @@ impl<'a, N: TcpClientStack, P: Protocol> Network<'a, N, P> {
 
     /// Encodes and sends the given command
     pub(crate) fn send(&self, frame: P::FrameType) -> Result<Identity, CommandErrors> {
+        self.prepare_send();
+        #[cfg(feature = "metrics")]
+        let keyword = metrics::keyword(&self.protocol.command_elements(&frame));
+
+        self.send_frame(frame)?;
+        let identity = self.next_identity();
+
+        #[cfg(feature = "metrics")]
+        let identity = self.metrics.borrow_mut().record_send(identity, keyword);
+        Ok(identity)
+    }
+
+    /// Sends the given pre-encoded command without any further encoding
+    pub(crate) fn send_raw(&self, data: &[u8]) -> Result<Identity, CommandErrors> {
+        self.prepare_send();
+        self.send_data(data)?;
+        Ok(self.next_identity())
+    }
+
+    /// Sends the given commands at once, e.g. for pipelining. Memory for all commands is allocated upfront:
+    /// The TX buffer is sized to the encoded length of all commands, and frame capacity for all responses
+    /// is reserved.
+    ///
+    /// If sending fails after some commands have already been sent, all futures are invalidated, as
+    /// the responses of the sent commands would otherwise be mapped to subsequent commands.
+    pub(crate) fn send_all(&self, frames: &[P::FrameType]) -> Result<Vec<Identity>, CommandErrors> {
+        self.prepare_send();
+
+        let encoded = self.encode_all(frames)?;
+        self.buffer.borrow_mut().reserve_frames(frames.len());
+
+        #[cfg(feature = "metrics")]
+        let mut keywords = frames
+            .iter()
+            .map(|frame| metrics::keyword(&self.protocol.command_elements(frame)));
+
+        let mut identities = Vec::with_capacity(encoded.len());
+        for data in encoded {
+            if let Err(error) = self.send_data(data.as_ref()) {
+                if !identities.is_empty() {
+                    self.invalidate_futures();
+                }
+                return Err(error);
+            }
+            let identity = self.next_identity();
+
+            #[cfg(feature = "metrics")]
+            let identity = self
+                .metrics
+                .borrow_mut()
+                .record_send(identity, keywords.next().unwrap_or_default());
+            identities.push(identity);
+        }
+
+        Ok(identities)
+    }
+
+    /// Encodes the given frames into a single pre-sized buffer. Returns one part per frame.
+    pub(crate) fn encode_all(&self, frames: &[P::FrameType]) -> Result<Vec<BytesMut>, CommandErrors> {
+        let size = frames.iter().map(|frame| self.protocol.encode_len(frame)).sum();
+        let mut buffer = BytesMut::with_capacity(size);
+        let mut parts = Vec::with_capacity(frames.len());
+
+        for frame in frames {
+            self.validate(frame)?;
+            buffer.resize(self.protocol.encode_len(frame), 0x0);
+            self.protocol
+                .encode_bytes(&mut buffer, frame)
+                .map_err(|_| CommandErrors::EncodingCommandFailed)?;
+            reject_empty(&buffer)?;
+            parts.push(buffer.split());
+        }
+
+        Ok(parts)
+    }
+
+    /// Clears the state in case of previous fatal errors and dropped futures
+    fn prepare_send(&self) {
         // Seems a fata error invalidated the current series, so everything needs to be cleared
         if *self.clear_buffer.borrow().deref() {
             self.clear_socket();
@@ -81,19 +261,23 @@ impl<'a, N: TcpClientStack, P: Protocol> Network<'a, N, P> {
 
         // Handle dropped futures for not leaking memory
         self.handle_dropped_futures();
+    }
 
-        self.send_frame(frame)?;
-
+    /// Allocates the identity of the next future
+    fn next_identity(&self) -> Identity {
         let identity = Identity {
             series: *self.current_series.borrow(),
             index: *self.next_index.borrow(),
+            #[cfg(feature = "metrics")]
+            keyword: None,
         };
         *self.next_index.borrow_mut() += 1;
-        Ok(identity)
+        identity
     }
 
     /// Raw network logic for sending a frame
     pub(crate) fn send_frame(&self, frame: P::FrameType) -> Result<(), CommandErrors> {
+        self.validate(&frame)?;
         let mut buffer = BytesMut::new();
 
         // Extend buffer if needed
@@ -105,10 +289,16 @@ impl<'a, N: TcpClientStack, P: Protocol> Network<'a, N, P> {
             }
         }
 
+        reject_empty(&buffer)?;
+        self.send_data(buffer.as_ref())
+    }
+
+    /// Raw network logic for sending data
+    fn send_data(&self, data: &[u8]) -> Result<(), CommandErrors> {
         let mut stack = self.stack.borrow_mut();
         let mut socket = self.socket.borrow_mut();
 
-        if stack.send(socket.deref_mut(), buffer.as_ref()).is_err() {
+        if stack.send(socket.deref_mut(), data).is_err() {
             return Err(CommandErrors::TcpError);
         };
 
@@ -155,11 +345,45 @@ impl<'a, N: TcpClientStack, P: Protocol> Network<'a, N, P> {
         *self.clear_buffer.borrow_mut() = true;
     }
 
+    /// Invalidates all futures and discards all received data, e.g. for realigning the response stream
+    pub(crate) fn reset(&self) {
+        *self.current_series.borrow_mut() += 1;
+        *self.next_index.borrow_mut() = 0;
+        *self.clear_buffer.borrow_mut() = false;
+        self.dropped_futures.borrow_mut().clear();
+        self.buffer.borrow_mut().clear();
+    }
+
+    /// Drops malformed data until the remaining data parses again, so complete frames following the garbage are
+    /// preserved. Returns true if data was dropped.
+    pub(crate) fn skip_faulty(&self) -> bool {
+        self.buffer.borrow_mut().skip_faulty()
+    }
+
     /// Future was dropped before fully fetching response data
     pub(crate) fn drop_future(&self, id: Identity) {
         self.dropped_futures.borrow_mut().push(id);
     }
 
+    /// Future was cancelled. Response data is dropped immediately if already received, otherwise
+    /// the future is handled like a dropped one.
+    pub(crate) fn cancel_future(&self, id: Identity) {
+        // Future got already invalidated, so there is nothing to clean up
+        if self.current_series.borrow().deref() != &id.series {
+            return;
+        }
+
+        self.receive_all();
+
+        let mut buffer = self.buffer.borrow_mut();
+        if buffer.is_complete(id.index) {
+            buffer.take_frame(id.index);
+            return;
+        }
+
+        self.drop_future(id);
+    }
+
     /// Drops response data of dropped futures
     pub fn handle_dropped_futures(&self) {
         if self.dropped_futures.borrow().is_empty() {
@@ -231,6 +455,11 @@ impl<'a, N: TcpClientStack, P: Protocol> Network<'a, N, P> {
     pub fn get_pending_frame_count(&self) -> usize {
         self.buffer.borrow().pending_frame_count()
     }
+
+    #[cfg(test)]
+    pub fn get_frame_capacity(&self) -> usize {
+        self.buffer.borrow().frame_capacity()
+    }
 }
 
 impl<N: TcpClientStack, P: Protocol> Debug for Network<'_, N, P> {
@@ -238,3 +467,184 @@ impl<N: TcpClientStack, P: Protocol> Debug for Network<'_, N, P> {
         f.debug_struct("Network").finish()
     }
 }
+
+impl<N: TcpClientStack, P: Protocol> Network<'_, N, P> {
+    /// Rejects the command if key validation is enabled and a key or channel contains CR or LF
+    fn validate(&self, frame: &P::FrameType) -> Result<(), CommandErrors> {
+        if !self.validate_keys {
+            return Ok(());
+        }
+
+        let elements = self.protocol.command_elements(frame);
+        let (keyword, arguments) = match elements.split_first() {
+            None => return Ok(()),
+            Some(split) => split,
+        };
+
+        let positions = key_positions(keyword).resolve(arguments);
+        let mut keys = arguments.iter().enumerate().filter(|(index, _)| match positions {
+            KeyPositions::None => false,
+            KeyPositions::First(count) => *index < count,
+            KeyPositions::At(position) => *index == position,
+            KeyPositions::Range(start, end) => (start..end).contains(index),
+            KeyPositions::EverySecond => index % 2 == 0,
+            KeyPositions::All => true,
+            // Resolved to a range above
+            KeyPositions::NumKeys(_) | KeyPositions::Streams => false,
+        });
+
+        match keys.any(|(_, key)| key.contains(&b'\r') || key.contains(&b'\n')) {
+            true => Err(CommandErrors::InvalidKey),
+            false => Ok(()),
+        }
+    }
+
+    /// Records the completion of an awaited command
+    #[cfg(feature = "metrics")]
+    pub(crate) fn record_completion(&self, id: &Identity, bucket: Option<usize>, success: bool) {
+        self.metrics.borrow_mut().record_completion(id, bucket, success);
+    }
+
+    #[cfg(feature = "metrics")]
+    pub(crate) fn metrics_snapshot(&self) -> BTreeMap<String, CommandMetric> {
+        self.metrics.borrow().snapshot()
+    }
+}
+
+/// Arguments of a command holding keys or channels
+#[derive(Debug, Clone, Copy)]
+enum KeyPositions {
+    /// Command without keys
+    None,
+    /// The first N arguments
+    First(usize),
+    /// A single argument at the given index, e.g. following a subcommand
+    At(usize),
+    /// Arguments from start (inclusive) to end (exclusive)
+    Range(usize, usize),
+    /// Every second argument, starting with the first one (key/value pairs)
+    EverySecond,
+    /// All arguments
+    All,
+    /// The argument at the given index states the number of keys, which follow directly
+    NumKeys(usize),
+    /// First half of the arguments following the STREAMS keyword, the second half are IDs
+    Streams,
+}
+
+impl KeyPositions {
+    /// Resolves positions depending on argument values to a fixed range
+    fn resolve(self, arguments: &[&[u8]]) -> Self {
+        match self {
+            KeyPositions::NumKeys(index) => {
+                let count = arguments
+                    .get(index)
+                    .and_then(|count| core::str::from_utf8(count).ok())
+                    .and_then(|count| count.parse::<usize>().ok());
+
+                match count {
+                    None => KeyPositions::None,
+                    Some(count) => KeyPositions::Range(index + 1, (index + 1).saturating_add(count)),
+                }
+            }
+            KeyPositions::Streams => {
+                let position =
+                    arguments.iter().position(|argument| argument.eq_ignore_ascii_case(b"STREAMS"));
+
+                match position {
+                    None => KeyPositions::None,
+                    Some(position) => {
+                        let count = (arguments.len() - position - 1) / 2;
+                        KeyPositions::Range(position + 1, position + 1 + count)
+                    }
+                }
+            }
+            positions => positions,
+        }
+    }
+}
+
+/// Commands deviating from the convention of a single key as first argument
+const KEY_POSITIONS: [(&[u8], KeyPositions); 53] = [
+    // Keys only
+    (b"DEL", KeyPositions::All),
+    (b"UNLINK", KeyPositions::All),
+    (b"EXISTS", KeyPositions::All),
+    (b"TOUCH", KeyPositions::All),
+    (b"MGET", KeyPositions::All),
+    (b"WATCH", KeyPositions::All),
+    (b"SINTER", KeyPositions::All),
+    (b"SUNION", KeyPositions::All),
+    (b"SDIFF", KeyPositions::All),
+    (b"SINTERSTORE", KeyPositions::All),
+    (b"SUNIONSTORE", KeyPositions::All),
+    (b"SDIFFSTORE", KeyPositions::All),
+    (b"PFCOUNT", KeyPositions::All),
+    (b"PFMERGE", KeyPositions::All),
+    // Keys followed by a numeric timeout, which never contains CR or LF
+    (b"BLPOP", KeyPositions::All),
+    (b"BRPOP", KeyPositions::All),
+    (b"BZPOPMIN", KeyPositions::All),
+    (b"BZPOPMAX", KeyPositions::All),
+    // Channels
+    (b"SUBSCRIBE", KeyPositions::All),
+    (b"UNSUBSCRIBE", KeyPositions::All),
+    (b"PSUBSCRIBE", KeyPositions::All),
+    (b"PUNSUBSCRIBE", KeyPositions::All),
+    (b"SSUBSCRIBE", KeyPositions::All),
+    (b"SUNSUBSCRIBE", KeyPositions::All),
+    // Key/value pairs
+    (b"MSET", KeyPositions::EverySecond),
+    (b"MSETNX", KeyPositions::EverySecond),
+    // Source and destination keys
+    (b"RENAME", KeyPositions::First(2)),
+    (b"RENAMENX", KeyPositions::First(2)),
+    (b"COPY", KeyPositions::First(2)),
+    (b"SMOVE", KeyPositions::First(2)),
+    (b"LMOVE", KeyPositions::First(2)),
+    (b"BLMOVE", KeyPositions::First(2)),
+    (b"RPOPLPUSH", KeyPositions::First(2)),
+    (b"BRPOPLPUSH", KeyPositions::First(2)),
+    (b"ZRANGESTORE", KeyPositions::First(2)),
+    // Key following a subcommand
+    (b"XGROUP", KeyPositions::At(1)),
+    (b"OBJECT", KeyPositions::At(1)),
+    // Keys following the STREAMS keyword
+    (b"XREAD", KeyPositions::Streams),
+    (b"XREADGROUP", KeyPositions::Streams),
+    // Keys following the numkeys argument. Scripts and arguments may contain CR or LF.
+    (b"EVAL", KeyPositions::NumKeys(1)),
+    (b"EVAL_RO", KeyPositions::NumKeys(1)),
+    (b"EVALSHA", KeyPositions::NumKeys(1)),
+    (b"EVALSHA_RO", KeyPositions::NumKeys(1)),
+    (b"FCALL", KeyPositions::NumKeys(1)),
+    (b"FCALL_RO", KeyPositions::NumKeys(1)),
+    // Commands without key
+    (b"AUTH", KeyPositions::None),
+    (b"CLIENT", KeyPositions::None),
+    (b"CONFIG", KeyPositions::None),
+    (b"ECHO", KeyPositions::None),
+    (b"FUNCTION", KeyPositions::None),
+    (b"HELLO", KeyPositions::None),
+    (b"PING", KeyPositions::None),
+    (b"SCRIPT", KeyPositions::None),
+];
+
+/// Determines the key arguments by the command keyword. By convention, the first argument is the key for
+/// most commands.
+fn key_positions(keyword: &[u8]) -> KeyPositions {
+    KEY_POSITIONS
+        .iter()
+        .find(|(command, _)| keyword.eq_ignore_ascii_case(command))
+        .map_or(KeyPositions::First(1), |(_, positions)| *positions)
+}
+
+/// Rejects commands without any element (e.g. empty [CommandBuilder](crate::commands::builder::CommandBuilder)),
+/// as Redis does not respond to an empty multibulk properly
+fn reject_empty(encoded: &[u8]) -> Result<(), CommandErrors> {
+    if encoded == b"*0\r\n" {
+        return Err(CommandErrors::EncodingCommandFailed);
+    }
+
+    Ok(())
+}
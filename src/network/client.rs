@@ -1,20 +1,25 @@
 use crate::commands::auth::AuthCommand;
 use crate::commands::builder::CommandBuilder;
+use crate::commands::custom::CustomCommand;
 use crate::commands::hello::{HelloCommand, HelloResponse};
 use crate::commands::Command;
 use crate::network::buffer::Network;
 use crate::network::future::Future;
 use crate::network::handler::{ConnectionError, Credentials};
 use crate::network::protocol::{Protocol, Resp3};
+use crate::network::response::MemoryParameters;
 use crate::network::timeout::{Timeout, TimeoutError};
+use crate::network::version::ServerVersion;
 use crate::subscription::client::{Error, Subscription};
 use crate::subscription::messages::ToPushMessage;
 use alloc::string::String;
 use bytes::Bytes;
-use core::fmt::{Debug, Formatter};
+use core::cell::RefCell;
+use core::fmt::{Debug, Display, Formatter};
 use embedded_nal::TcpClientStack;
-use embedded_time::duration::Microseconds;
+use embedded_time::duration::{Extensions, Microseconds};
 use embedded_time::Clock;
+use redis_protocol::resp3::types::BytesAttributes;
 
 /// Error handling for command execution
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -22,12 +27,13 @@ pub enum CommandErrors {
     /// No response within expected time frame
     /// *Is recommended to create a new client/connection in this case*.
     Timeout,
-    /// Failed encoding TX command
+    /// Failed encoding TX command, e.g. in case of an empty command without keyword
     EncodingCommandFailed,
     /// Received an invalid response violating the RESP protocol. Ideally this should never happen.
     /// The following causes are conceivable
     /// * Bug in this library (e.g. incomplete implementation of RESP protocol)
     /// * Redis server bug
+    /// * Inline response (e.g. `PONG\r\n`) of a minimal server, s. [ConnectionHandler::inline_responses](crate::network::ConnectionHandler::inline_responses)
     /// * Network failure. As we are using TCP, only a network stack bug or other exotic causes (e.g. bit flip) is reasonable.
     /// * Is recommended to create a new client/connection in this case*.
     ProtocolViolation,
@@ -42,12 +48,78 @@ pub enum CommandErrors {
     /// Received an unexpected response incompatible with the command specification
     CommandResponseViolation,
     /// Redis error response. Inner value is the error message received.
+    /// The error code (e.g. `NOPERM`) may be extracted by [error_code](CommandErrors::error_code).
     ErrorResponse(String),
+    /// RESP3 error response carrying attributes. Inner values are the error message and the attributes received.
+    /// Error responses without attributes are reported as [ErrorResponse](CommandErrors::ErrorResponse).
+    ErrorResponseAttributes(String, BytesAttributes),
     /// Memory limit reached. s. [MemoryParameter](crate::network::MemoryParameters)
     /// *Is recommended to create a new client/connection in this case*.
     MemoryFull,
+    /// Write was not acknowledged by the required number of replicas in time, e.g. by
+    /// [set_durable](Client::set_durable). Inner value is the number of acknowledging replicas.
+    InsufficientReplicas(i64),
+    /// Key or channel name contains CR or LF, s. [ConnectionHandler::validate_keys](crate::network::ConnectionHandler::validate_keys).
+    /// The command was not sent.
+    InvalidKey,
 }
 
+impl CommandErrors {
+    /// Returns the error code of Redis error responses, which is the first uppercase word of the error message
+    /// by convention, e.g. `WRONGTYPE` or `NOPERM`. None for other errors or messages without code.
+    pub fn error_code(&self) -> Option<&str> {
+        let message = match self {
+            CommandErrors::ErrorResponse(message) | CommandErrors::ErrorResponseAttributes(message, _) => {
+                message
+            }
+            _ => return None,
+        };
+
+        let code = message.split(' ').next()?;
+        let valid = code
+            .bytes()
+            .all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit() || byte == b'_');
+
+        match valid && !code.is_empty() {
+            true => Some(code),
+            false => None,
+        }
+    }
+
+    /// Returns true if the command was rejected due to missing ACL permissions (`NOPERM` error response)
+    pub fn is_permission_denied(&self) -> bool {
+        self.error_code() == Some("NOPERM")
+    }
+}
+
+impl Display for CommandErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CommandErrors::Timeout => write!(f, "no response within the expected time frame"),
+            CommandErrors::EncodingCommandFailed => write!(f, "failed encoding the command"),
+            CommandErrors::ProtocolViolation => write!(f, "received response violating the RESP protocol"),
+            CommandErrors::InvalidFuture => {
+                write!(f, "future is no longer valid due to a previous fatal error")
+            }
+            CommandErrors::TcpError => write!(f, "network error"),
+            CommandErrors::TimerError => write!(f, "clock/timer failure"),
+            CommandErrors::CommandResponseViolation => {
+                write!(f, "received response incompatible with the command specification")
+            }
+            CommandErrors::ErrorResponse(message) | CommandErrors::ErrorResponseAttributes(message, _) => {
+                write!(f, "Redis error response: {message}")
+            }
+            CommandErrors::MemoryFull => write!(f, "response buffer memory limit reached"),
+            CommandErrors::InsufficientReplicas(count) => {
+                write!(f, "write acknowledged by only {count} replica(s)")
+            }
+            CommandErrors::InvalidKey => write!(f, "key or channel name contains CR or LF"),
+        }
+    }
+}
+
+impl core::error::Error for CommandErrors {}
+
 /// Client to execute Redis commands
 ///
 /// The functionality of the client is best explained by a [command example](crate::commands::get).
@@ -63,6 +135,9 @@ where
 
     /// Response to HELLO command, only used for RESP3
     pub(crate) hello_response: Option<&'a <HelloCommand as Command<<P as Protocol>::FrameType>>::Response>,
+
+    /// Server version assumed if not known by HELLO response
+    pub(crate) assumed_version: Option<ServerVersion>,
 }
 
 impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
@@ -86,6 +161,32 @@ where
         ))
     }
 
+    /// Sends the given pre-encoded RESP frame as it is, e.g. for replaying captured traffic.
+    /// The response frame is returned without any evaluation.
+    ///
+    /// The data must contain exactly one complete frame, as a single reply is expected. Otherwise
+    /// [EncodingCommandFailed](CommandErrors::EncodingCommandFailed) is returned and nothing is sent.
+    pub fn send_bytes(&'a self, raw: Bytes) -> Result<Future<'a, N, C, P, CustomCommand>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        match self.network.get_protocol().decode(&raw) {
+            Ok(Some((_, size))) if size == raw.len() => {}
+            _ => return Err(CommandErrors::EncodingCommandFailed),
+        }
+
+        let id = self.network.send_raw(&raw)?;
+
+        // Command is just used for evaluating the response, as the frame is already encoded
+        Ok(Future::new(
+            id,
+            CommandBuilder::default().to_command(),
+            self.network.get_protocol(),
+            &self.network,
+            Timeout::new(self.clock, self.timeout_duration)?,
+        ))
+    }
+
     /// Subscribes the given channels and returns a subscription client.
     ///
     /// *If the subscriptions fails, it's recommended to close the connection, as a the
@@ -101,10 +202,26 @@ where
         Subscription::new(self, channels).subscribe()
     }
 
+    /// Subscribes the given patterns (PSUBSCRIBE) and returns a subscription client. Received messages carry the
+    /// matching pattern, s. [Message::pattern](crate::subscription::Message::pattern).
+    ///
+    /// *If the subscriptions fails, it's recommended to close the connection, as a the
+    /// state is undefined. A further reuse of the connection could cause subsequent errors*
+    pub fn psubscribe<const L: usize>(
+        self,
+        patterns: [Bytes; L],
+    ) -> Result<Subscription<'a, N, C, P, L>, Error>
+    where
+        <P as Protocol>::FrameType: ToPushMessage,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        Subscription::new(self, patterns).patterns().subscribe()
+    }
+
     /// Authenticates blocking with the given credentials during client initialization
     pub(crate) fn auth(&'a self, credentials: Option<Credentials>) -> Result<(), ConnectionError> {
-        if credentials.is_some() {
-            self.send(AuthCommand::from(credentials.as_ref().unwrap()))
+        if let Some(credentials) = &credentials {
+            self.send(AuthCommand::from(credentials))
                 .map_err(auth_error)?
                 .wait()
                 .map_err(auth_error)?;
@@ -148,11 +265,63 @@ where
     }
 }
 
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Creates a new client based on an already connected socket. No timeout is applied.
+    ///
+    /// Intended for advanced use cases in which sockets are managed outside of [ConnectionHandler](crate::network::ConnectionHandler),
+    /// e.g. after a custom handshake. Neither authentication nor protocol switching (HELLO) is done, so the connection
+    /// is expected to be ready for executing commands of the given protocol.
+    /// Accordingly, [try_hello_response](Client::try_hello_response) returns None for clients created this way.
+    pub fn from_socket(
+        stack: &'a mut N,
+        socket: &'a mut N::TcpSocket,
+        clock: Option<&'a C>,
+        protocol: P,
+        memory: MemoryParameters,
+    ) -> Self {
+        Client {
+            network: Network::new(RefCell::new(stack), RefCell::new(socket), protocol, memory),
+            timeout_duration: 0.microseconds(),
+            clock,
+            hello_response: None,
+            assumed_version: None,
+        }
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    HelloCommand: Command<<P as Protocol>::FrameType, Response = HelloResponse>,
+{
+    /// Returns the version of the Redis server, if known.
+    ///
+    /// The version reported by HELLO response (RESP3) takes precedence over the version assumed by
+    /// [ConnectionHandler::assume_version](crate::network::ConnectionHandler::assume_version).
+    /// None if neither is available.
+    pub fn server_version(&self) -> Option<ServerVersion> {
+        self.hello_response
+            .and_then(|response| ServerVersion::parse(&response.version))
+            .or(self.assumed_version)
+    }
+}
+
 impl<N: TcpClientStack, C: Clock> Client<'_, N, C, Resp3> {
     /// Returns the response to HELLO command executed during connection initialization
     /// [Client HELLO response]
+    ///
+    /// Panics if the client was created by [from_socket](Client::from_socket), as no HELLO command is executed.
+    /// Use [try_hello_response](Client::try_hello_response) in this case.
     pub fn get_hello_response(&self) -> &HelloResponse {
-        self.hello_response.as_ref().unwrap()
+        self.hello_response.unwrap()
+    }
+
+    /// Returns the response to HELLO command executed during connection initialization.
+    /// None if the client was created by [from_socket](Client::from_socket).
+    pub fn try_hello_response(&self) -> Option<&HelloResponse> {
+        self.hello_response
     }
 }
 
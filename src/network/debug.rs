@@ -0,0 +1,150 @@
+//! Rendering of RESP frames for logging and debugging, e.g. for diagnosing unexpected
+//! [ProtocolViolation](crate::network::CommandErrors::ProtocolViolation) errors.
+use alloc::string::String;
+use core::fmt::Write;
+use redis_protocol::resp2::types::BytesFrame as Resp2Frame;
+use redis_protocol::resp3::types::BytesFrame as Resp3Frame;
+use redis_protocol::resp3::types::RespVersion;
+
+/// Renders the given RESP2/RESP3 frame as JSON-like string
+///
+/// * Strings are rendered as JSON strings. Non UTF-8 strings are rendered as `{"hex":"..."}` object.
+/// * Errors are rendered as `{"error":"..."}` object.
+/// * Arrays, sets and pushes are rendered as JSON arrays, maps as JSON objects. Non-string keys are quoted.
+/// * RESP3 attributes are omitted.
+pub fn frame_to_json<F: ToJson>(frame: &F) -> String {
+    let mut output = String::new();
+    frame.write_json(&mut output);
+    output
+}
+
+/// Trait for rendering frames as JSON-like string, s. [frame_to_json]
+pub trait ToJson {
+    /// Appends the rendered frame to the given output
+    fn write_json(&self, output: &mut String);
+}
+
+impl ToJson for Resp2Frame {
+    fn write_json(&self, output: &mut String) {
+        match self {
+            Resp2Frame::SimpleString(data) | Resp2Frame::BulkString(data) => write_bytes(output, data),
+            Resp2Frame::Error(message) => write_error(output, message.as_bytes()),
+            Resp2Frame::Integer(number) => {
+                let _ = write!(output, "{number}");
+            }
+            Resp2Frame::Array(frames) => write_array(output, frames),
+            Resp2Frame::Null => output.push_str("null"),
+        }
+    }
+}
+
+impl ToJson for Resp3Frame {
+    fn write_json(&self, output: &mut String) {
+        match self {
+            Resp3Frame::BlobString { data, .. }
+            | Resp3Frame::SimpleString { data, .. }
+            | Resp3Frame::VerbatimString { data, .. }
+            | Resp3Frame::ChunkedString(data) => write_bytes(output, data),
+            Resp3Frame::BlobError { data, .. } => write_error(output, data),
+            Resp3Frame::SimpleError { data, .. } => write_error(output, data.as_bytes()),
+            Resp3Frame::Boolean { data, .. } => {
+                let _ = write!(output, "{data}");
+            }
+            Resp3Frame::Null => output.push_str("null"),
+            Resp3Frame::Number { data, .. } => {
+                let _ = write!(output, "{data}");
+            }
+            Resp3Frame::Double { data, .. } => {
+                if data.is_finite() {
+                    let _ = write!(output, "{data}");
+                } else {
+                    // NaN and infinity are not valid JSON numbers
+                    let _ = write!(output, "\"{data}\"");
+                }
+            }
+            Resp3Frame::BigNumber { data, .. } => write_bytes(output, data),
+            Resp3Frame::Array { data, .. } | Resp3Frame::Push { data, .. } => write_array(output, data),
+            Resp3Frame::Set { data, .. } => write_array(output, data),
+            Resp3Frame::Map { data, .. } => {
+                output.push('{');
+                for (index, (key, value)) in data.iter().enumerate() {
+                    if index > 0 {
+                        output.push(',');
+                    }
+
+                    write_key(output, key);
+                    output.push(':');
+                    value.write_json(output);
+                }
+                output.push('}');
+            }
+            Resp3Frame::Hello { version, .. } => {
+                let version = match version {
+                    RespVersion::RESP2 => "2",
+                    RespVersion::RESP3 => "3",
+                };
+                let _ = write!(output, "{{\"hello\":{version}}}");
+            }
+        }
+    }
+}
+
+/// Renders a map key, which is quoted in case of non-string frames
+fn write_key(output: &mut String, key: &Resp3Frame) {
+    let rendered = frame_to_json(key);
+    if rendered.starts_with('"') {
+        output.push_str(&rendered);
+    } else {
+        write_escaped(output, &rendered);
+    }
+}
+
+fn write_array<'a, F: ToJson + 'a>(output: &mut String, frames: impl IntoIterator<Item = &'a F>) {
+    output.push('[');
+    for (index, frame) in frames.into_iter().enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+        frame.write_json(output);
+    }
+    output.push(']');
+}
+
+fn write_error(output: &mut String, message: &[u8]) {
+    output.push_str("{\"error\":");
+    write_bytes(output, message);
+    output.push('}');
+}
+
+/// Renders a binary-safe string. Non UTF-8 data is rendered as hex.
+fn write_bytes(output: &mut String, data: &[u8]) {
+    match core::str::from_utf8(data) {
+        Ok(string) => write_escaped(output, string),
+        Err(_) => {
+            output.push_str("{\"hex\":\"");
+            for byte in data {
+                let _ = write!(output, "{byte:02x}");
+            }
+            output.push_str("\"}");
+        }
+    }
+}
+
+/// Renders the string as quoted JSON string
+fn write_escaped(output: &mut String, string: &str) {
+    output.push('"');
+    for char in string.chars() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            char if char.is_control() => {
+                let _ = write!(output, "\\u{:04x}", char as u32);
+            }
+            char => output.push(char),
+        }
+    }
+    output.push('"');
+}
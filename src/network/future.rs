@@ -2,8 +2,12 @@ use crate::commands::Command;
 use crate::network::buffer::Network;
 use crate::network::client::CommandErrors;
 use crate::network::client::CommandErrors::CommandResponseViolation;
+#[cfg(feature = "metrics")]
+use crate::network::metrics;
 use crate::network::protocol::Protocol;
 use crate::network::timeout::Timeout;
+#[cfg(feature = "metrics")]
+use alloc::string::String;
 use embedded_nal::TcpClientStack;
 use embedded_time::Clock;
 use nb;
@@ -17,6 +21,34 @@ pub(crate) struct Identity {
 
     /// Unique index of mapping future to response message
     pub index: usize,
+
+    /// Keyword of the command, used for recording metrics on completion
+    #[cfg(feature = "metrics")]
+    pub keyword: Option<String>,
+}
+
+/// Information about a timed out command, passed to the callback set by
+/// [ConnectionHandler::on_timeout](crate::network::ConnectionHandler::on_timeout)
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TimeoutInfo {
+    /// Connection series of the command. Incremented on each fatal error (e.g. timeout), which invalidates
+    /// all pending futures.
+    pub series: usize,
+
+    /// Index of the command within its series
+    pub index: usize,
+}
+
+/// Callback invoked once a command timed out
+pub(crate) type TimeoutHook = dyn FnMut(&TimeoutInfo) + Send;
+
+impl From<&Identity> for TimeoutInfo {
+    fn from(id: &Identity) -> Self {
+        TimeoutInfo {
+            series: id.series,
+            index: id.index,
+        }
+    }
 }
 
 /// Non-blocking response management
@@ -57,7 +89,21 @@ impl<'a, N: TcpClientStack, C: Clock, P: Protocol, Cmd: Command<P::FrameType>> F
     /// Throws an error on invalid response or timeout (if configured)
     pub fn wait(mut self) -> Result<Cmd::Response, CommandErrors> {
         self.wait_called = true;
+        let result = self.wait_response();
+
+        // Clock is read once on completion
+        #[cfg(feature = "metrics")]
+        self.network.record_completion(
+            &self.id,
+            self.timeout.elapsed().map(metrics::latency_bucket),
+            result.is_ok(),
+        );
+
+        result
+    }
 
+    /// Blocks until response is received and evaluates the response
+    fn wait_response(&mut self) -> Result<Cmd::Response, CommandErrors> {
         if self.error.is_some() {
             return Err(self.error.clone().unwrap());
         }
@@ -74,6 +120,17 @@ impl<'a, N: TcpClientStack, C: Clock, P: Protocol, Cmd: Command<P::FrameType>> F
         }
     }
 
+    /// Cancels the future, as the response is no longer of interest.
+    ///
+    /// Pending socket data is received non-blocking. If the response has already arrived, it's
+    /// discarded immediately. Otherwise the response is discarded on the next send() call, like
+    /// in case of a dropped future.
+    pub fn cancel(mut self) {
+        // Response is managed by cancel_future(), so destructor has nothing left to do
+        self.wait_called = true;
+        self.network.cancel_future(self.id.clone());
+    }
+
     /// Non blocking method for checking if data is ready
     /// So if true is returned, wait() is non-blocking
     /// Reads all pending data and returns true if response is ready
@@ -111,6 +168,7 @@ impl<'a, N: TcpClientStack, C: Clock, P: Protocol, Cmd: Command<P::FrameType>> F
                     }
                     nb::Error::WouldBlock => {
                         if self.timeout.expired()? {
+                            self.network.notify_timeout(&self.id);
                             self.network.invalidate_futures();
                             return Err(CommandErrors::Timeout);
                         }
@@ -118,6 +176,8 @@ impl<'a, N: TcpClientStack, C: Clock, P: Protocol, Cmd: Command<P::FrameType>> F
                         if !block {
                             return Ok(());
                         }
+
+                        self.network.yield_wait();
                     }
                 }
             }
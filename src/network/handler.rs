@@ -1,15 +1,20 @@
 use crate::commands::auth::AuthCommand;
 use crate::commands::builder::{CommandBuilder, ToStringOption};
-use crate::commands::hello::HelloCommand;
+use crate::commands::hello::{HelloCommand, HelloResponse};
 use crate::commands::ping::PingCommand;
 use crate::commands::Command;
-use crate::network::buffer::Network;
+use crate::network::buffer::{Network, DEFAULT_MAX_COMMAND_SIZE};
 use crate::network::client::{Client, CommandErrors};
+use crate::network::future::{TimeoutHook, TimeoutInfo};
 use crate::network::handler::ConnectionError::{TcpConnectionFailed, TcpSocketError};
+use crate::network::pool::BufferPool;
 use crate::network::protocol::{Protocol, Resp2, Resp3};
 use crate::network::response::MemoryParameters;
+use crate::network::version::ServerVersion;
+use alloc::boxed::Box;
 use alloc::string::{String, ToString};
 use core::cell::RefCell;
+use core::fmt::{Display, Formatter};
 use core::net::SocketAddr;
 use embedded_nal::TcpClientStack;
 use embedded_time::duration::Extensions;
@@ -29,9 +34,51 @@ pub enum ConnectionError {
     AuthenticationError(CommandErrors),
 
     /// Protocol switch (switch to RESP3) failed with the given sub error
+    ///
+    /// [MemoryFull](CommandErrors::MemoryFull) indicates that the HELLO response exceeds the configured
+    /// [memory limit](MemoryParameters::memory_limit), e.g. in case of a server with many loaded modules.
     ProtocolSwitchError(CommandErrors),
 }
 
+impl Display for ConnectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConnectionError::TcpSocketError => write!(f, "unable to get a socket from the network layer"),
+            ConnectionError::TcpConnectionFailed => write!(f, "TCP connect failed"),
+            ConnectionError::AuthenticationError(error) => write!(f, "authentication failed: {error}"),
+            ConnectionError::ProtocolSwitchError(CommandErrors::MemoryFull) => write!(
+                f,
+                "protocol switch (HELLO) failed: response exceeds the memory limit, \
+                 consider raising MemoryParameters::memory_limit"
+            ),
+            ConnectionError::ProtocolSwitchError(error) => {
+                write!(f, "protocol switch (HELLO) failed: {error}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ConnectionError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ConnectionError::AuthenticationError(error) | ConnectionError::ProtocolSwitchError(error) => {
+                Some(error)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Protocol supported by the server, as determined by [detect_protocol](ConnectionHandler::detect_protocol)
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DetectedProtocol {
+    /// Server rejected HELLO command, e.g. Redis version < 6.0
+    Resp2,
+
+    /// Protocol switch succeeded
+    Resp3,
+}
+
 /// Authentication credentials
 #[derive(Clone)]
 pub struct Credentials {
@@ -94,6 +141,27 @@ where
     /// Use PING command for testing connection
     use_ping: bool,
 
+    /// Accept inline responses without type prefix
+    inline_responses: bool,
+
+    /// Max. encoded size of a single command, as respected by batching helpers
+    max_command_size: usize,
+
+    /// Reject keys and channels containing CR or LF
+    validate_keys: bool,
+
+    /// Optional response buffer reused by subsequent clients
+    buffer_pool: Option<BufferPool<P>>,
+
+    /// Callback invoked while blocking waits for pending data
+    on_wait: Option<Box<dyn FnMut() + Send>>,
+
+    /// Callback invoked once a command timed out
+    on_timeout: Option<Box<TimeoutHook>>,
+
+    /// Server version assumed for version dependent commands, if not known by HELLO response
+    assumed_version: Option<ServerVersion>,
+
     /// Response to HELLO command, only used for RESP3
     pub(crate) hello_response: Option<<HelloCommand as Command<<P as Protocol>::FrameType>>::Response>,
 }
@@ -110,6 +178,35 @@ impl<N: TcpClientStack> ConnectionHandler<N, Resp3> {
     pub fn resp3(remote: SocketAddr) -> ConnectionHandler<N, Resp3> {
         ConnectionHandler::new(remote, Resp3 {})
     }
+
+    /// Creates a new connection handler for servers of unknown capabilities. The supported protocol is
+    /// determined by [detect_protocol](Self::detect_protocol).
+    pub fn auto(remote: SocketAddr) -> ConnectionHandler<N, Resp3> {
+        ConnectionHandler::new(remote, Resp3 {})
+    }
+
+    /// Connects to the server and attempts switching to RESP3 by HELLO command.
+    ///
+    /// If the protocol switch succeeds, the connection is cached, so this handler can be used right away.
+    /// If the server rejects HELLO by an error response, the connection is closed and
+    /// [Resp2](DetectedProtocol::Resp2) is returned. In this case a new handler needs to be created by
+    /// [resp2](ConnectionHandler::resp2) using the same configuration.
+    ///
+    /// Any other error, e.g. failed authentication or timeout, is returned as is.
+    pub fn detect_protocol<C: Clock>(
+        &mut self,
+        network: &mut N,
+        clock: Option<&C>,
+    ) -> Result<DetectedProtocol, ConnectionError> {
+        match self.connect(network, clock) {
+            Ok(_) => Ok(DetectedProtocol::Resp3),
+            Err(ConnectionError::ProtocolSwitchError(CommandErrors::ErrorResponse(_))) => {
+                self.disconnect(network);
+                Ok(DetectedProtocol::Resp2)
+            }
+            Err(error) => Err(error),
+        }
+    }
 }
 
 impl<N: TcpClientStack, P: Protocol> ConnectionHandler<N, P>
@@ -130,6 +227,13 @@ where
             memory: MemoryParameters::default(),
             protocol,
             use_ping: false,
+            inline_responses: false,
+            max_command_size: DEFAULT_MAX_COMMAND_SIZE,
+            validate_keys: false,
+            buffer_pool: None,
+            on_wait: None,
+            on_timeout: None,
+            assumed_version: None,
             hello_response: None,
         }
     }
@@ -193,6 +297,47 @@ where
         }
     }
 
+    /// Re-issues HELLO command on the cached connection and replaces the cached response, e.g. in case
+    /// the server identity may have changed. Has no effect in case of RESP2.
+    ///
+    /// [TcpConnectionFailed](ConnectionError::TcpConnectionFailed) is returned if no connection is established.
+    pub fn refresh_hello<C: Clock>(
+        &mut self,
+        network: &mut N,
+        clock: Option<&C>,
+    ) -> Result<(), ConnectionError> {
+        if self.socket.is_none() {
+            return Err(TcpConnectionFailed);
+        }
+
+        let response = self.create_client(network, clock).init(None)?;
+        if response.is_some() {
+            self.hello_response = response;
+        }
+
+        Ok(())
+    }
+
+    /// Authenticates the cached connection with the given credentials, e.g. in case of rotating short-lived tokens.
+    /// On success, the credentials are stored and used for future connections.
+    ///
+    /// In case of an error response, the previous credentials are kept. The connection stays authenticated
+    /// by the previous credentials, as Redis does not reset the authentication on failed AUTH commands.
+    /// If no connection is established, the credentials are just stored for the next connect.
+    pub fn reauth<C: Clock>(
+        &mut self,
+        network: &mut N,
+        clock: Option<&C>,
+        credentials: Credentials,
+    ) -> Result<(), ConnectionError> {
+        if self.socket.is_some() {
+            self.create_client(network, clock).auth(Some(credentials.clone()))?;
+        }
+
+        self.authentication = Some(credentials);
+        Ok(())
+    }
+
     /// Tests if the cached socket is still connected, if not it's closed
     fn test_socket<'a, C: Clock>(&'a mut self, network: &'a mut N, clock: Option<&'a C>) {
         if self.socket.is_none() {
@@ -247,16 +392,24 @@ where
         stack: &'a mut N,
         clock: Option<&'a C>,
     ) -> Client<'a, N, C, P> {
+        let stack = RefCell::new(stack);
+        let socket = RefCell::new(self.socket.as_mut().unwrap());
+        let network = match self.buffer_pool.as_mut() {
+            None => Network::new(stack, socket, self.protocol.clone(), self.memory.clone()),
+            Some(pool) => Network::pooled(stack, socket, self.protocol.clone(), pool.lend()),
+        };
+
         Client {
-            network: Network::new(
-                RefCell::new(stack),
-                RefCell::new(self.socket.as_mut().unwrap()),
-                self.protocol.clone(),
-                self.memory.clone(),
-            ),
+            network: network
+                .with_wait_hook(self.on_wait.as_deref_mut().map(|hook| hook as &mut (dyn FnMut() + Send)))
+                .with_timeout_hook(self.on_timeout.as_deref_mut().map(|hook| hook as &mut TimeoutHook))
+                .with_inline_responses(self.inline_responses)
+                .with_max_command_size(self.max_command_size)
+                .with_key_validation(self.validate_keys),
             timeout_duration: self.timeout,
             clock,
             hello_response: self.hello_response.as_ref(),
+            assumed_version: self.assumed_version,
         }
     }
 }
@@ -283,9 +436,100 @@ where
         self
     }
 
+    /// Accepts inline responses without RESP type prefix (e.g. `PONG\r\n`), as sent by some minimal
+    /// Redis-compatible servers. Lines starting with an alphanumeric character are decoded as simple string.
+    ///
+    /// By default, inline responses are treated as protocol violation, resulting in
+    /// [ProtocolViolation](crate::network::CommandErrors::ProtocolViolation).
+    pub fn inline_responses(&mut self) -> &mut Self {
+        self.inline_responses = true;
+        self
+    }
+
+    /// Sets the max. encoded size of a single command in bytes, which is respected by batching helpers like
+    /// [del_many_pipelined](Client::del_many_pipelined). Defaults to 4096 bytes.
+    pub fn max_command_size(&mut self, size: usize) -> &mut Self {
+        self.max_command_size = size;
+        self
+    }
+
+    /// Rejects commands with keys or channel names containing CR or LF by
+    /// [InvalidKey](crate::network::CommandErrors::InvalidKey) before sending them. Such names are valid for Redis,
+    /// but commonly the result of malformed input, e.g. unsanitized line-based data.
+    ///
+    /// Keys are determined per command keyword: All arguments of multi-key commands (e.g. DEL, MGET, BLPOP) and
+    /// subscriptions, every second argument of key/value commands (MSET, MSETNX), the first two arguments of
+    /// commands with source and destination key (e.g. RENAME, COPY, LMOVE), the key following the subcommand
+    /// (XGROUP, OBJECT), the keys following the STREAMS keyword (XREAD, XREADGROUP), the keys following the
+    /// numkeys argument of scripts and functions (e.g. EVAL, FCALL), none for commands without key
+    /// (e.g. AUTH, PING). Otherwise, only the first argument is checked.
+    ///
+    /// Validation takes place when sending a command, not when constructing it. So invalid keys are only
+    /// reported by the send call (e.g. [Client::get](crate::network::Client::get)).
+    pub fn validate_keys(&mut self) -> &mut Self {
+        self.validate_keys = true;
+        self
+    }
+
     /// Sets memory allocation parameters
     pub fn memory(&mut self, parameters: MemoryParameters) -> &mut Self {
         self.memory = parameters;
+        if self.buffer_pool.is_some() {
+            self.buffer_pool = Some(BufferPool::new(self.protocol.clone(), self.memory.clone()));
+        }
+        self
+    }
+
+    /// Reuses a single response buffer for all clients created by this handler, instead of allocating new buffers
+    /// for each client. Recommended if short-lived clients are created frequently.
+    ///
+    /// The buffer is reset each time a client is created. Capacity exceeding the [memory parameters](Self::memory),
+    /// e.g. caused by a large response, is released at this point.
+    pub fn use_buffer_pool(&mut self) -> &mut Self {
+        if self.buffer_pool.is_none() {
+            self.buffer_pool = Some(BufferPool::new(self.protocol.clone(), self.memory.clone()));
+        }
+        self
+    }
+
+    #[cfg(test)]
+    pub(crate) fn buffer_pool_reused(&self) -> usize {
+        self.buffer_pool.as_ref().map(|pool| pool.reused()).unwrap_or(0)
+    }
+
+    /// Sets a callback, which is invoked each time a blocking wait finds no pending data.
+    /// May be used for yielding to the scheduler on cooperative systems.
+    pub fn on_wait<F: FnMut() + Send + 'static>(&mut self, callback: F) -> &mut Self {
+        self.on_wait = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets a callback, which is invoked once a command timed out, e.g. for tracking metrics or triggering a
+    /// health check. Invoked before the error is returned by the future or by
+    /// [resync_to_ping](Client::resync_to_ping).
+    ///
+    /// Timeouts of (un)subscription confirmations are not reported, as they are returned as
+    /// [subscription error](crate::subscription::Error::Timeout) instead.
+    pub fn on_timeout<F: FnMut(&TimeoutInfo) + Send + 'static>(&mut self, callback: F) -> &mut Self {
+        self.on_timeout = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the server version assumed for version dependent helpers, e.g. for devices connecting to a
+    /// known fixed server. The version is overridden by HELLO response in case of RESP3.
+    pub fn assume_version(&mut self, major: u16, minor: u16, patch: u16) -> &mut Self {
+        self.assumed_version = Some(ServerVersion::new(major, minor, patch));
         self
     }
 }
+
+impl<N: TcpClientStack, P: Protocol> ConnectionHandler<N, P>
+where
+    HelloCommand: Command<<P as Protocol>::FrameType, Response = HelloResponse>,
+{
+    /// Returns the response to HELLO command of the last connection initialization
+    /// None in case of RESP2 or if no connection was established yet
+    pub fn hello_response(&self) -> Option<&HelloResponse> {
+        self.hello_response.as_ref()
+    }
+}
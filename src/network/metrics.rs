@@ -0,0 +1,96 @@
+use crate::commands::auth::AuthCommand;
+use crate::commands::hello::HelloCommand;
+use crate::commands::Command;
+use crate::network::client::Client;
+use crate::network::future::Identity;
+use crate::network::protocol::Protocol;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use embedded_nal::TcpClientStack;
+use embedded_time::duration::Microseconds;
+use embedded_time::fixed_point::FixedPoint;
+use embedded_time::{Clock, TimeInt};
+
+/// Upper bounds (inclusive) of the latency histogram buckets in microseconds.
+/// Latencies exceeding the greatest bound are counted by an additional last bucket.
+pub const LATENCY_BUCKETS: [u32; 7] = [1_000, 5_000, 10_000, 50_000, 100_000, 500_000, 1_000_000];
+
+/// Aggregated metrics of a command keyword, s. [Client::metrics_snapshot]
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct CommandMetric {
+    /// Number of sent commands
+    pub count: usize,
+
+    /// Number of awaited commands failing, e.g. due to error response or timeout
+    pub errors: usize,
+
+    /// Latency histogram of awaited commands. Index i counts the latencies up to [LATENCY_BUCKETS]\[i\],
+    /// the last index counts all greater latencies. Latencies are measured from sending until completion of
+    /// [wait()](crate::network::Future::wait), so only recorded if a clock is given.
+    pub latency: [usize; LATENCY_BUCKETS.len() + 1],
+}
+
+/// Metrics of all commands sent on a connection
+#[derive(Debug, Default)]
+pub(crate) struct Metrics {
+    commands: BTreeMap<String, CommandMetric>,
+}
+
+impl Metrics {
+    /// Counts the sent command and tags the future identity for recording the completion
+    pub(crate) fn record_send(&mut self, mut identity: Identity, keyword: String) -> Identity {
+        self.commands.entry(keyword.clone()).or_default().count += 1;
+        identity.keyword = Some(keyword);
+        identity
+    }
+
+    /// Records the completion of an awaited command
+    pub(crate) fn record_completion(&mut self, identity: &Identity, bucket: Option<usize>, success: bool) {
+        let metric = match &identity.keyword {
+            None => return,
+            Some(keyword) => self.commands.entry(keyword.clone()).or_default(),
+        };
+
+        if !success {
+            metric.errors += 1;
+        }
+
+        if let Some(bucket) = bucket {
+            metric.latency[bucket] += 1;
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> BTreeMap<String, CommandMetric> {
+        self.commands.clone()
+    }
+}
+
+/// Returns the upper case command keyword, which is the first element of the command
+pub(crate) fn keyword(elements: &[&[u8]]) -> String {
+    match elements.first() {
+        None => String::new(),
+        Some(keyword) => String::from_utf8_lossy(keyword).to_ascii_uppercase(),
+    }
+}
+
+/// Returns the index of the histogram bucket matching the given latency
+pub(crate) fn latency_bucket<T: TimeInt>(latency: Microseconds<T>) -> usize {
+    LATENCY_BUCKETS
+        .iter()
+        .position(|bound| latency.integer() <= T::from(*bound))
+        .unwrap_or(LATENCY_BUCKETS.len())
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Returns the metrics aggregated per command keyword (e.g. "GET") since the connection was established.
+    ///
+    /// Commands are counted once sent. Errors and latencies are recorded once the future is awaited by
+    /// [wait](crate::network::Future::wait). Commands sent by [send_bytes](Client::send_bytes) are not tracked.
+    pub fn metrics_snapshot(&self) -> BTreeMap<String, CommandMetric> {
+        self.network.metrics_snapshot()
+    }
+}
@@ -1,18 +1,37 @@
 pub use client::{Client, CommandErrors};
-pub use future::Future;
-pub use handler::{ConnectionError, ConnectionHandler, Credentials};
+pub use future::{Future, TimeoutInfo};
+pub use handler::{ConnectionError, ConnectionHandler, Credentials, DetectedProtocol};
+#[cfg(feature = "metrics")]
+pub use metrics::{CommandMetric, LATENCY_BUCKETS};
+pub use pipeline::{Pipeline, TypedPipeline};
+pub use profile::CommandTimings;
 pub use protocol::{Resp2, Resp3};
 pub use response::MemoryParameters;
+pub use retry::RetryPolicy;
+pub use version::ServerVersion;
 
 pub(crate) mod buffer;
 pub(crate) mod client;
+#[cfg(any(test, feature = "mock"))]
+pub(crate) mod debug;
 pub(crate) mod future;
 pub(crate) mod handler;
+#[cfg(feature = "metrics")]
+pub(crate) mod metrics;
+pub(crate) mod pipeline;
+pub(crate) mod pool;
+pub(crate) mod profile;
 pub(crate) mod protocol;
 pub(crate) mod response;
+pub(crate) mod retry;
 pub(crate) mod timeout;
+pub(crate) mod version;
 
 pub(crate) mod tests;
 
+#[cfg(feature = "mock")]
+pub use debug::{frame_to_json, ToJson};
 #[cfg(feature = "mock")]
 pub use tests::mocks::{create_mocked_client, MockFrames, MockNetworkStack, NetworkMockBuilder};
+#[cfg(feature = "mock")]
+pub use tests::server::MockServer;
@@ -0,0 +1,166 @@
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::CommandBuilder;
+use crate::commands::custom::CustomCommand;
+use crate::commands::hello::HelloCommand;
+use crate::commands::Command;
+use crate::network::client::{Client, CommandErrors};
+use crate::network::future::{Future, Identity};
+use crate::network::protocol::Protocol;
+use crate::network::timeout::Timeout;
+use alloc::vec::Vec;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// List of raw commands, which are sent at once before waiting for the responses
+///
+/// For receiving typed responses, s. [TypedPipeline].
+#[derive(Clone, Default)]
+pub struct Pipeline {
+    commands: Vec<CommandBuilder>,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty pipeline with capacity for the given number of commands.
+    /// Pushing up to this number of commands does not reallocate.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            commands: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Appends the given command
+    pub fn push(mut self, command: CommandBuilder) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Number of commands
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// Returns true if the pipeline contains no commands
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+}
+
+/// Tuple of commands, which are sent at once (like [Pipeline]) before waiting for the responses.
+/// The response is the tuple of the typed command responses in the same order.
+///
+/// Implemented for tuples up to eight commands.
+pub trait TypedPipeline<F> {
+    type Response;
+
+    /// Sends all commands and waits for all responses
+    fn execute<'a, N, C, P>(self, client: &'a Client<'a, N, C, P>) -> Result<Self::Response, CommandErrors>
+    where
+        N: TcpClientStack,
+        C: Clock,
+        P: Protocol<FrameType = F>,
+        AuthCommand: Command<F>,
+        HelloCommand: Command<F>;
+}
+
+macro_rules! typed_pipeline {
+    ($($command:ident $future:ident),+) => {
+        impl<F, $($command: Command<F>),+> TypedPipeline<F> for ($($command,)+) {
+            type Response = ($(<$command as Command<F>>::Response,)+);
+
+            #[allow(non_snake_case)]
+            fn execute<'a, N, C, P>(self, client: &'a Client<'a, N, C, P>) -> Result<Self::Response, CommandErrors>
+            where
+                N: TcpClientStack,
+                C: Clock,
+                P: Protocol<FrameType = F>,
+                AuthCommand: Command<F>,
+                HelloCommand: Command<F>,
+            {
+                let ($($future,)+) = self;
+                let frames: Vec<F> = alloc::vec![$(<$command as Command<F>>::encode(&$future),)+];
+                let mut sent = client.send_pipelined(&frames)?.into_iter();
+
+                $(
+                    // One identity is returned per frame
+                    let (id, timeout) = sent.next().unwrap();
+                    let $future = Future::new(id, $future, client.network.get_protocol(), &client.network, timeout);
+                )+
+
+                Ok(($($future.wait()?,)+))
+            }
+        }
+    };
+}
+
+typed_pipeline!(A a);
+typed_pipeline!(A a, B b);
+typed_pipeline!(A a, B b, C2 c);
+typed_pipeline!(A a, B b, C2 c, D d);
+typed_pipeline!(A a, B b, C2 c, D d, E e);
+typed_pipeline!(A a, B b, C2 c, D d, E e, F2 f);
+typed_pipeline!(A a, B b, C2 c, D d, E e, F2 f, G g);
+typed_pipeline!(A a, B b, C2 c, D d, E e, F2 f, G g, H h);
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Sends all commands of the pipeline at once and waits for all responses.
+    /// Returns the raw response frames in the order of the commands.
+    ///
+    /// In case of an error response, the error is returned and remaining responses are discarded.
+    ///
+    /// Memory is allocated upfront based on the number of commands: The TX buffer holds all encoded
+    /// commands, and response frame capacity is reserved accordingly.
+    pub fn pipeline(&'a self, pipeline: Pipeline) -> Result<Vec<P::FrameType>, CommandErrors>
+    where
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        let commands: Vec<CustomCommand> =
+            pipeline.commands.into_iter().map(|command| command.to_command()).collect();
+        let frames: Vec<P::FrameType> = commands.iter().map(|command| command.encode()).collect();
+        let sent = self.send_pipelined(&frames)?;
+
+        let futures: Vec<_> = sent
+            .into_iter()
+            .zip(commands)
+            .map(|((id, timeout), command)| {
+                Future::new(id, command, self.network.get_protocol(), &self.network, timeout)
+            })
+            .collect();
+
+        futures.into_iter().map(|future| future.wait()).collect()
+    }
+
+    /// Sends all commands of the tuple at once and waits for all responses.
+    /// Returns the typed responses in the order of the commands.
+    ///
+    /// In case of an error response, the error is returned and remaining responses are discarded.
+    pub fn typed_pipeline<T>(&'a self, commands: T) -> Result<T::Response, CommandErrors>
+    where
+        T: TypedPipeline<P::FrameType>,
+    {
+        commands.execute(self)
+    }
+
+    /// Sends the given frames at once. Returns the identity and timeout of each command in the same order.
+    ///
+    /// All timeouts are started before sending, so a clock failure does not leave sent commands without future.
+    fn send_pipelined(
+        &'a self,
+        frames: &[P::FrameType],
+    ) -> Result<Vec<(Identity, Timeout<'a, C>)>, CommandErrors> {
+        let mut timeouts = Vec::with_capacity(frames.len());
+        for _ in frames {
+            timeouts.push(Timeout::new(self.clock, self.timeout_duration)?);
+        }
+
+        let identities = self.network.send_all(frames)?;
+        Ok(identities.into_iter().zip(timeouts).collect())
+    }
+}
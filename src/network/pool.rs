@@ -0,0 +1,63 @@
+use crate::network::protocol::Protocol;
+use crate::network::response::{MemoryParameters, ResponseBuffer};
+use core::cell::RefCell;
+use core::ops::Deref;
+
+/// Response buffer retained by the connection handler and lent to the clients it creates.
+///
+/// As clients borrow the handler mutably, at most one client exists at a time. So a single buffer is
+/// sufficient, which is reset each time it is lent.
+pub(crate) struct BufferPool<P: Protocol> {
+    buffer: RefCell<ResponseBuffer<P>>,
+    memory: MemoryParameters,
+
+    /// Number of times the buffer was lent after initial use
+    reused: usize,
+
+    /// Buffer was lent at least once
+    lent: bool,
+}
+
+impl<P: Protocol> BufferPool<P> {
+    pub(crate) fn new(protocol: P, memory: MemoryParameters) -> Self {
+        Self {
+            buffer: RefCell::new(ResponseBuffer::new(protocol, memory.clone())),
+            memory,
+            reused: 0,
+            lent: false,
+        }
+    }
+
+    /// Resets the buffer and lends it to the next client
+    pub(crate) fn lend(&mut self) -> &RefCell<ResponseBuffer<P>> {
+        if self.lent {
+            self.reused += 1;
+        }
+
+        self.lent = true;
+        self.buffer.get_mut().reset(&self.memory);
+        &self.buffer
+    }
+
+    #[cfg(test)]
+    pub(crate) fn reused(&self) -> usize {
+        self.reused
+    }
+}
+
+/// Response buffer either owned by the client or lent from a [BufferPool]
+pub(crate) enum BufferSlot<'a, P: Protocol> {
+    Owned(RefCell<ResponseBuffer<P>>),
+    Lent(&'a RefCell<ResponseBuffer<P>>),
+}
+
+impl<P: Protocol> Deref for BufferSlot<'_, P> {
+    type Target = RefCell<ResponseBuffer<P>>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            BufferSlot::Owned(buffer) => buffer,
+            BufferSlot::Lent(buffer) => buffer,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::commands::auth::AuthCommand;
+use crate::commands::hello::HelloCommand;
+use crate::commands::Command;
+use crate::network::client::{Client, CommandErrors};
+use crate::network::future::Future;
+use crate::network::protocol::Protocol;
+use crate::network::timeout::Timeout;
+use embedded_nal::TcpClientStack;
+use embedded_time::duration::Microseconds;
+use embedded_time::{Clock, Instant, TimeInt};
+
+/// Time spent on the individual steps of a command execution, s. [Client::profile]
+///
+/// T: Integer type of the clock
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct CommandTimings<T: TimeInt> {
+    /// Encoding the command to a RESP frame
+    pub encode: Microseconds<T>,
+
+    /// Serializing the frame and passing the data to the network stack
+    pub send: Microseconds<T>,
+
+    /// Waiting for the response, including receiving, parsing and evaluating the response
+    pub wait: Microseconds<T>,
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Executes the given command blocking and measures the time spent on encoding, sending and waiting
+    /// for the response. Intended as development tool for locating bottlenecks on target.
+    ///
+    /// Requires a clock, otherwise [TimerError](CommandErrors::TimerError) is returned.
+    /// The resolution of the timings is limited to the resolution of the clock.
+    pub fn profile<Cmd>(
+        &'a self,
+        command: Cmd,
+    ) -> Result<(Cmd::Response, CommandTimings<C::T>), CommandErrors>
+    where
+        Cmd: Command<P::FrameType>,
+    {
+        let start = self.now()?;
+        let frame = command.encode();
+        let encoded = self.now()?;
+        let id = self.network.send(frame)?;
+        let sent = self.now()?;
+
+        let future = Future::new(
+            id,
+            command,
+            self.network.get_protocol(),
+            &self.network,
+            Timeout::new(self.clock, self.timeout_duration)?,
+        );
+        let response = future.wait()?;
+        let received = self.now()?;
+
+        let timings = CommandTimings {
+            encode: elapsed(&start, &encoded)?,
+            send: elapsed(&encoded, &sent)?,
+            wait: elapsed(&sent, &received)?,
+        };
+
+        Ok((response, timings))
+    }
+
+    /// Returns the current instant of the clock
+    fn now(&self) -> Result<Instant<C>, CommandErrors> {
+        self.clock
+            .ok_or(CommandErrors::TimerError)?
+            .try_now()
+            .map_err(|_| CommandErrors::TimerError)
+    }
+}
+
+/// Returns the duration between the given instants
+fn elapsed<C: Clock>(start: &Instant<C>, end: &Instant<C>) -> Result<Microseconds<C::T>, CommandErrors> {
+    let duration = end.checked_duration_since(start).ok_or(CommandErrors::TimerError)?;
+    Microseconds::try_from(duration).map_err(|_| CommandErrors::TimerError)
+}
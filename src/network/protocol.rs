@@ -1,10 +1,13 @@
 use crate::network::client::CommandErrors;
 use alloc::string::ToString;
+use alloc::vec::Vec;
 use bytes::{Bytes, BytesMut};
-use redis_protocol::error::RedisProtocolError;
+use redis_protocol::error::{RedisProtocolError, RedisProtocolErrorKind};
 use redis_protocol::resp2::types::BytesFrame as Resp2Frame;
+use redis_protocol::resp2::types::Resp2Frame as _;
 use redis_protocol::resp3::types::BytesFrame as Resp3Frame;
-use redis_protocol::resp3::types::DecodedFrame;
+use redis_protocol::resp3::types::Resp3Frame as _;
+use redis_protocol::resp3::types::{DecodedFrame, StreamedFrame};
 use redis_protocol::{resp2, resp3};
 
 /// Generic wrapper for redis-protocol encode/decode methods
@@ -17,6 +20,9 @@ pub trait Protocol: Clone {
     /// Encodes Frame to buffer
     fn encode_bytes(&self, buf: &mut BytesMut, frame: &Self::FrameType) -> Result<usize, RedisProtocolError>;
 
+    /// Returns the number of bytes needed for encoding the given frame
+    fn encode_len(&self, frame: &Self::FrameType) -> usize;
+
     /// Wraps response error to CommandError
     fn assert_error(&self, frame: &Self::FrameType) -> Result<(), CommandErrors>;
 
@@ -24,6 +30,20 @@ pub trait Protocol: Clone {
     fn requires_hello(&self) -> bool {
         false
     }
+
+    /// Returns true if push messages are distinguishable from command replies, so subscribed connections
+    /// are not restricted to subscriber mode commands
+    fn supports_push(&self) -> bool {
+        false
+    }
+
+    /// Returns the elements (keyword and arguments) of the given command frame.
+    /// Empty in case the frame is not an array of strings, e.g. for special frames like HELLO.
+    ///
+    /// Empty by default, so key validation and command metrics are skipped.
+    fn command_elements<'f>(&self, _frame: &'f Self::FrameType) -> Vec<&'f [u8]> {
+        Vec::new()
+    }
 }
 
 /// Abstraction for RESP2 protocol
@@ -41,12 +61,29 @@ impl Protocol for Resp2 {
         resp2::encode::encode_bytes(buf, frame, false)
     }
 
+    fn encode_len(&self, frame: &Self::FrameType) -> usize {
+        frame.encode_len(false)
+    }
+
     fn assert_error(&self, frame: &Self::FrameType) -> Result<(), CommandErrors> {
         match frame {
             Resp2Frame::Error(message) => Err(CommandErrors::ErrorResponse(message.to_string())),
             _ => Ok(()),
         }
     }
+
+    fn command_elements<'f>(&self, frame: &'f Self::FrameType) -> Vec<&'f [u8]> {
+        match frame {
+            Resp2Frame::Array(items) => items
+                .iter()
+                .filter_map(|item| match item {
+                    Resp2Frame::BulkString(data) | Resp2Frame::SimpleString(data) => Some(data.as_ref()),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
 }
 
 /// Abstraction for RESP3 protocol
@@ -56,22 +93,14 @@ pub struct Resp3 {}
 impl Protocol for Resp3 {
     type FrameType = Resp3Frame;
 
-    /// Currently just decodes complete frames
-    /// In case of streaming frame None is returned. In Redis <= 6 there is currently no command
-    /// returning a stream frame.
+    /// Decodes complete frames as well as streamed strings and aggregates (`?` length).
+    /// Streamed frames are assembled to a complete frame once the terminating chunk is received.
+    /// Until then None is returned, so the buffered data stays untouched.
     fn decode(&self, data: &Bytes) -> Result<Option<(Self::FrameType, usize)>, RedisProtocolError> {
-        match resp3::decode::streaming::decode_bytes(data) {
-            Ok(option) => match option {
-                None => Ok(None),
-                Some(decoded) => {
-                    let (frame, size) = decoded;
-                    match frame {
-                        DecodedFrame::Streaming(_) => Ok(None),
-                        DecodedFrame::Complete(complete_frame) => Ok(Some((complete_frame, size))),
-                    }
-                }
-            },
-            Err(error) => Err(error),
+        match resp3::decode::streaming::decode_bytes(data)? {
+            None => Ok(None),
+            Some((DecodedFrame::Complete(frame), size)) => Ok(Some((frame, size))),
+            Some((DecodedFrame::Streaming(streamed), size)) => self.decode_stream(data, streamed, size),
         }
     }
 
@@ -79,9 +108,27 @@ impl Protocol for Resp3 {
         resp3::encode::complete::encode_bytes(buf, frame, false)
     }
 
+    fn encode_len(&self, frame: &Self::FrameType) -> usize {
+        frame.encode_len(false)
+    }
+
     fn assert_error(&self, frame: &Self::FrameType) -> Result<(), CommandErrors> {
         match frame {
+            Resp3Frame::BlobError {
+                data: _,
+                attributes: Some(attributes),
+            } => Err(CommandErrors::ErrorResponseAttributes(
+                "blob".to_string(),
+                attributes.clone(),
+            )),
             Resp3Frame::BlobError { .. } => Err(CommandErrors::ErrorResponse("blob".to_string())),
+            Resp3Frame::SimpleError {
+                data,
+                attributes: Some(attributes),
+            } => Err(CommandErrors::ErrorResponseAttributes(
+                data.to_string(),
+                attributes.clone(),
+            )),
             Resp3Frame::SimpleError { data, attributes: _ } => {
                 Err(CommandErrors::ErrorResponse(data.to_string()))
             }
@@ -92,4 +139,57 @@ impl Protocol for Resp3 {
     fn requires_hello(&self) -> bool {
         true
     }
+
+    fn supports_push(&self) -> bool {
+        true
+    }
+
+    fn command_elements<'f>(&self, frame: &'f Self::FrameType) -> Vec<&'f [u8]> {
+        match frame {
+            Resp3Frame::Array { data, attributes: _ } => data
+                .iter()
+                .filter_map(|item| match item {
+                    Resp3Frame::BlobString { data, attributes: _ }
+                    | Resp3Frame::SimpleString { data, attributes: _ } => Some(data.as_ref()),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl Resp3 {
+    /// Collects the chunks of a streamed frame, starting after its header at the given offset
+    ///
+    /// returns: Total size of the streamed frame including the terminating chunk
+    /// None is returned in case the terminating chunk was not received yet
+    fn decode_stream(
+        &self,
+        data: &Bytes,
+        mut streamed: StreamedFrame<Resp3Frame>,
+        mut offset: usize,
+    ) -> Result<Option<(Resp3Frame, usize)>, RedisProtocolError> {
+        while !streamed.is_finished() {
+            if offset >= data.len() {
+                return Ok(None);
+            }
+
+            match resp3::decode::streaming::decode_bytes(&data.slice(offset..))? {
+                None => return Ok(None),
+                Some((DecodedFrame::Complete(frame), size)) => {
+                    streamed.add_frame(frame);
+                    offset += size;
+                }
+                Some((DecodedFrame::Streaming(_), _)) => {
+                    return Err(RedisProtocolError::new(
+                        RedisProtocolErrorKind::DecodeError,
+                        "Nested streamed frames are not supported",
+                    ));
+                }
+            }
+        }
+
+        Ok(Some((streamed.take()?, offset)))
+    }
 }
@@ -1,6 +1,7 @@
 use crate::network::protocol::Protocol;
 use alloc::vec::Vec;
 use bytes::Bytes;
+use redis_protocol::error::RedisProtocolErrorKind;
 
 /// Parameters for memory optimization
 #[derive(Debug, Clone)]
@@ -13,6 +14,10 @@ pub struct MemoryParameters {
 
     /// Optional buffer memory limit in bytes for preventing DOS attacks.
     /// [CommandErrors::MemoryFull](crate::network::CommandErrors::MemoryFull) error is returned in case limit is reached.
+    ///
+    /// *In case of RESP3, the limit needs to cover the HELLO response as well, which grows with the number of
+    /// loaded server modules. Otherwise connecting fails with
+    /// [ProtocolSwitchError(MemoryFull)](crate::network::ConnectionError::ProtocolSwitchError).*
     pub memory_limit: Option<usize>,
 }
 
@@ -48,6 +53,9 @@ pub(crate) struct ResponseBuffer<P: Protocol> {
 
     /// Memory limit in bytes. 0 in case if no limit is used.
     limit: usize,
+
+    /// Lines without RESP type prefix are decoded as simple string
+    inline: bool,
 }
 
 impl<P: Protocol> ResponseBuffer<P> {
@@ -60,9 +68,16 @@ impl<P: Protocol> ResponseBuffer<P> {
             frame_offset: 0,
             faulty: false,
             limit: parameters.memory_limit.unwrap_or(0),
+            inline: false,
         }
     }
 
+    /// Enables or disables decoding of inline responses (e.g. `PONG\r\n`).
+    /// If disabled, inline responses mark the buffer as faulty.
+    pub fn accept_inline(&mut self, enabled: bool) {
+        self.inline = enabled;
+    }
+
     /// Appends data to buffer
     pub fn append(&mut self, data: &[u8]) {
         if self.is_full() {
@@ -152,25 +167,55 @@ impl<P: Protocol> ResponseBuffer<P> {
             return None;
         }
 
-        let bytes = Bytes::from(self.buffer[start..].to_vec());
-
-        let result = self.decoder.decode(&bytes);
-        if result.is_err() {
-            self.faulty = true;
-            return None;
+        // RESP type prefixes are never alphanumeric
+        if self.inline && self.buffer[start].is_ascii_alphanumeric() {
+            return self.parse_inline(start);
         }
 
-        // No frame found
-        if result.as_ref().unwrap().is_none() {
-            return None;
-        }
+        let bytes = Bytes::from(self.buffer[start..].to_vec());
+
+        let frame = match self.decoder.decode(&bytes) {
+            Ok(Some(frame)) => frame,
+            // No complete frame found
+            Ok(None) => return None,
+            // Incomplete frame, e.g. binary payload containing CRLF split across chunks
+            Err(error) if matches!(error.kind(), RedisProtocolErrorKind::BufferTooSmall(_)) => return None,
+            // Malformed frame
+            Err(_) => {
+                self.faulty = true;
+                return None;
+            }
+        };
 
-        let frame = result.unwrap().unwrap();
         self.frames.push(Some(frame.0));
         self.frame_count += 1;
         Some(frame.1 - 1 + start)
     }
 
+    /// Parses an inline response line by decoding it as simple string
+    ///
+    /// returns: Option<usize> Position/Index of line termination
+    /// None is returned in case the line is not complete yet
+    fn parse_inline(&mut self, start: usize) -> Option<usize> {
+        let length = self.buffer[start..].windows(2).position(|window| window == b"\r\n")? + 2;
+
+        let mut line = Vec::with_capacity(length + 1);
+        line.push(b'+');
+        line.extend_from_slice(&self.buffer[start..start + length]);
+
+        match self.decoder.decode(&Bytes::from(line)) {
+            Ok(Some((frame, _))) => {
+                self.frames.push(Some(frame));
+                self.frame_count += 1;
+                Some(start + length - 1)
+            }
+            _ => {
+                self.faulty = true;
+                None
+            }
+        }
+    }
+
     /// Is message at index given index complete
     pub fn is_complete(&self, mut index: usize) -> bool {
         if index < self.frame_offset {
@@ -188,6 +233,45 @@ impl<P: Protocol> ResponseBuffer<P> {
         self.faulty
     }
 
+    /// Drops malformed data at the start of a faulty buffer and resumes parsing of the remaining data.
+    /// Data is dropped up to the next position at which a (possibly incomplete) frame starts, so frames following
+    /// the malformed data are preserved. Returns false if the buffer is not faulty.
+    pub fn skip_faulty(&mut self) -> bool {
+        if !self.faulty {
+            return false;
+        }
+
+        while self.faulty {
+            // Malformed frame is always located at the start, as previous complete frames got drained
+            let position = self.next_frame_start().unwrap_or(self.buffer.len());
+            self.buffer.drain(..position);
+
+            self.faulty = false;
+            self.parse_frames();
+        }
+
+        true
+    }
+
+    /// Returns the first position after the start of the buffer, at which data decodes to a complete or
+    /// incomplete frame. None is returned in case the remaining data is malformed entirely.
+    fn next_frame_start(&self) -> Option<usize> {
+        // Buffer is copied once, as slices of Bytes share the underlying memory
+        let bytes = Bytes::from(self.buffer.clone());
+
+        (1..bytes.len()).find(|position| {
+            // RESP type prefixes are never alphanumeric
+            if self.inline && bytes[*position].is_ascii_alphanumeric() {
+                return true;
+            }
+
+            match self.decoder.decode(&bytes.slice(*position..)) {
+                Ok(_) => true,
+                Err(error) => matches!(error.kind(), RedisProtocolErrorKind::BufferTooSmall(_)),
+            }
+        })
+    }
+
     /// Returns true if max. buffer size is reached
     pub fn is_full(&self) -> bool {
         if self.limit == 0 {
@@ -197,6 +281,20 @@ impl<P: Protocol> ResponseBuffer<P> {
         self.buffer.len() > self.limit
     }
 
+    /// Reserves capacity for exactly the given number of additional frames
+    pub fn reserve_frames(&mut self, count: usize) {
+        self.frames.reserve_exact(count);
+    }
+
+    /// Resets the buffer for reuse by a new client. Settings are restored to defaults, while capacity
+    /// exceeding the given parameters (e.g. after a large response) is released.
+    pub fn reset(&mut self, parameters: &MemoryParameters) {
+        self.clear();
+        self.buffer.shrink_to(parameters.buffer_size);
+        self.frames.shrink_to(parameters.frame_capacity);
+        self.inline = false;
+    }
+
     /// Resets the buffer in case of fatal error
     pub fn clear(&mut self) {
         self.frames.clear();
@@ -211,6 +309,11 @@ impl<P: Protocol> ResponseBuffer<P> {
         self.frame_count
     }
 
+    #[cfg(test)]
+    pub fn frame_capacity(&self) -> usize {
+        self.frames.capacity()
+    }
+
     #[cfg(test)]
     pub fn frame_offset(&self) -> usize {
         self.frame_offset
@@ -0,0 +1,91 @@
+use crate::commands::auth::AuthCommand;
+use crate::commands::builder::{CommandBuilder, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::{Command, ResponseTypeError};
+use crate::network::client::{Client, CommandErrors};
+use crate::network::protocol::Protocol;
+use core::mem::discriminant;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Policy for repeating commands on failure, s. [Client::send_with_policy]
+///
+/// Errors are matched by kind, so inner values are ignored. E.g. `ErrorResponse(String::new())` matches all
+/// error responses.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy<'a> {
+    /// Max. number of attempts, including the first one. 0 is handled like 1.
+    pub attempts: usize,
+
+    /// Errors kinds on which the command is repeated. Any other error is returned immediately.
+    pub on: &'a [CommandErrors],
+}
+
+impl RetryPolicy<'_> {
+    /// Returns true if the given error is retryable
+    pub fn matches(&self, error: &CommandErrors) -> bool {
+        self.on.iter().any(|kind| discriminant(kind) == discriminant(error))
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    AuthCommand: Command<<P as Protocol>::FrameType>,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Sends the given command and waits blocking for the response. The command is repeated according to the
+    /// given policy. The error of the last attempt is returned once all attempts are exhausted.
+    ///
+    /// After timeouts and protocol violations, the response stream is realigned by
+    /// [resync_to_ping](Client::resync_to_ping) before the next attempt, so a late reply of the previous attempt
+    /// is not mistaken for the response of the next one. An error of the resync is returned immediately.
+    /// Reconnecting is beyond the scope of the client, so a broken connection needs to be handled by
+    /// [ConnectionHandler](crate::network::ConnectionHandler).
+    ///
+    /// *Only use retries for idempotent commands (e.g. GET or SET). A failed attempt may still have been executed
+    /// by the server (e.g. in case of a timeout), so non-idempotent commands (e.g. INCR) could be applied multiple times.*
+    pub fn send_with_policy<Cmd>(
+        &'a self,
+        command: Cmd,
+        policy: RetryPolicy,
+    ) -> Result<Cmd::Response, CommandErrors>
+    where
+        Cmd: Command<P::FrameType>,
+        <P as Protocol>::FrameType: ToStringOption,
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+    {
+        let mut remaining = policy.attempts.max(1);
+
+        loop {
+            remaining -= 1;
+
+            let error = match self.send(Attempt(&command)).and_then(|future| future.wait()) {
+                Ok(response) => return Ok(response),
+                Err(error) => error,
+            };
+
+            if remaining == 0 || !policy.matches(&error) {
+                return Err(error);
+            }
+
+            if matches!(error, CommandErrors::Timeout | CommandErrors::ProtocolViolation) {
+                self.resync_to_ping()?;
+            }
+        }
+    }
+}
+
+/// Single attempt of a command, so the same command can be sent repeatedly
+struct Attempt<'c, Cmd>(&'c Cmd);
+
+impl<F, Cmd: Command<F>> Command<F> for Attempt<'_, Cmd> {
+    type Response = Cmd::Response;
+
+    fn encode(&self) -> F {
+        self.0.encode()
+    }
+
+    fn eval_response(&self, frame: F) -> Result<Self::Response, ResponseTypeError> {
+        self.0.eval_response(frame)
+    }
+}
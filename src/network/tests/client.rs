@@ -1,13 +1,24 @@
+use crate::commands::bitfield::{BitfieldOverflow, CounterArray};
+use crate::commands::builder::CommandBuilder;
+use crate::commands::custom::RedisValue;
+use crate::commands::get::GetCommand;
 use crate::commands::set::SetCommand;
+use crate::commands::sorted_index::SortedStringIndex;
+use crate::commands::ttl::TtlResponse;
+use crate::commands::xadd::{LogLevel, Logger};
+use crate::commands::zrange_ext::{LexBound, ScoreBound};
 use crate::network::buffer::Network;
 use crate::network::client::Client;
 use crate::network::client::CommandErrors::{
-    CommandResponseViolation, ErrorResponse, InvalidFuture, ProtocolViolation, TcpError, Timeout, TimerError,
+    CommandResponseViolation, ErrorResponse, ErrorResponseAttributes, InvalidFuture, ProtocolViolation,
+    TcpError, Timeout, TimerError,
 };
 use crate::network::handler::ConnectionError::{AuthenticationError, ProtocolSwitchError};
 use crate::network::handler::Credentials;
+use crate::network::pipeline::Pipeline;
 use crate::network::protocol::{Resp2, Resp3};
 use crate::network::response::MemoryParameters;
+use crate::network::retry::RetryPolicy;
 use crate::network::tests::mocks::MockTcpError::Error1;
 use crate::network::tests::mocks::{
     create_mocked_client, MockNetworkStack, NetworkMockBuilder, SocketMock, TestClock,
@@ -18,6 +29,8 @@ use alloc::vec;
 use bytes::Bytes;
 use core::cell::RefCell;
 use embedded_time::duration::Extensions;
+use redis_protocol::resp2::types::BytesFrame as Resp2Frame;
+use redis_protocol::resp3::types::{BytesAttributes, BytesFrame as Resp3Frame};
 
 #[test]
 fn test_resp2_init_no_authentication() {
@@ -237,6 +250,7 @@ fn test_timeout_expired() {
         timeout_duration: 150.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     let result = client.init(Some(Credentials::password_only("secret123")));
@@ -267,6 +281,7 @@ fn test_timeout_timer_error() {
         timeout_duration: 150.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     let result = client.init(Some(Credentials::password_only("secret123")));
@@ -299,6 +314,7 @@ fn test_timeout_not_expired() {
         timeout_duration: 250.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     client.init(Some(Credentials::password_only("secret123"))).unwrap();
@@ -333,6 +349,53 @@ fn test_set_error_response() {
     assert_eq!(ErrorResponse("Error".to_string()), result);
 }
 
+#[test]
+fn test_resp3_error_response_attributes_retained() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .response("|1\r\n+key\r\n:42\r\n-ERR fail\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {});
+
+    let result = client.send(SetCommand::new("test_key", "test")).unwrap().wait().unwrap_err();
+
+    let mut attributes = BytesAttributes::default();
+    attributes.insert(
+        Resp3Frame::SimpleString {
+            data: "key".into(),
+            attributes: None,
+        },
+        Resp3Frame::Number {
+            data: 42,
+            attributes: None,
+        },
+    );
+    assert_eq!(
+        ErrorResponseAttributes("ERR fail".to_string(), attributes),
+        result
+    );
+}
+
+#[test]
+fn test_resp3_error_response_without_attributes() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .response("-ERR failure\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {});
+
+    let result = client.send(SetCommand::new("test_key", "test")).unwrap().wait().unwrap_err();
+    assert_eq!(ErrorResponse("ERR failure".to_string()), result);
+}
+
 #[test]
 fn test_set_unknown_response() {
     let clock = TestClock::new(vec![]);
@@ -521,6 +584,7 @@ fn test_futures_invalidated_on_timeout() {
         timeout_duration: 150.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     let first = client.send(SetCommand::new("timeout", "future")).unwrap();
@@ -666,6 +730,7 @@ fn test_future_dropped_invalidated() {
         timeout_duration: 150.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     let first = client.send(SetCommand::new("timeout", "future")).unwrap();
@@ -683,6 +748,57 @@ fn test_future_dropped_invalidated() {
     assert_eq!(0, client.network.get_pending_frame_count());
 }
 
+/// Tests cancelled future, which response has already arrived.
+/// Response data is discarded immediately, so no dropped future remains.
+#[test]
+fn test_future_cancelled_response_available() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .send(164, "")
+        .response_ok()
+        .response_no_data()
+        .response_ok()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.send(SetCommand::new("key", "value")).unwrap().cancel();
+    assert_eq!(0, client.network.get_dropped_future_count());
+    assert_eq!(0, client.network.get_pending_frame_count());
+
+    client.send(SetCommand::new("key", "value")).unwrap().wait().unwrap();
+}
+
+/// Tests cancelled future, which response has not arrived yet.
+/// The future is handled like a dropped future.
+#[test]
+fn test_future_cancelled_response_pending() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .send(164, "")
+        .response_no_data() // Called at cancel(), no data arrived yet
+        .response_ok() // Data of first (cancelled) future, called at second send()
+        .response_no_data()
+        .response_ok()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.send(SetCommand::new("key", "value")).unwrap().cancel();
+    assert_eq!(1, client.network.get_dropped_future_count());
+
+    let second = client.send(SetCommand::new("key", "value")).unwrap();
+    assert_eq!(0, client.network.get_dropped_future_count());
+    assert_eq!(0, client.network.get_pending_frame_count());
+    second.wait().unwrap();
+}
+
 #[test]
 fn test_close_timeout() {
     let clock = TestClock::new(vec![
@@ -710,6 +826,7 @@ fn test_close_timeout() {
         timeout_duration: 150.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     {
@@ -769,6 +886,7 @@ fn test_memory_limit_reached() {
         timeout_duration: 0.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     let error = client.get("key").unwrap().wait().unwrap_err();
@@ -800,6 +918,7 @@ fn test_memory_limit_not_reached() {
         timeout_duration: 0.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     let data = client.get("key").unwrap().wait().unwrap().unwrap().to_bytes();
@@ -824,6 +943,22 @@ fn test_shorthand_get_str_argument() {
     );
 }
 
+#[test]
+fn test_shorthand_get_empty_string() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response("$0\r\n\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client.get("key").unwrap().wait().unwrap();
+    assert_eq!("", response.unwrap().as_str().unwrap());
+}
+
 #[test]
 fn test_shorthand_get_string_argument() {
     let clock = TestClock::new(vec![]);
@@ -856,6 +991,93 @@ fn test_shorthand_get_bytes_argument() {
     assert_eq!("test_response", response.unwrap().unwrap().as_str().unwrap());
 }
 
+#[test]
+fn test_shorthand_getdel() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$6\r\nGETDEL\r\n$3\r\nkey\r\n")
+        .response_string("test_response")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client.getdel("key").unwrap().wait();
+    assert_eq!("test_response", response.unwrap().unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_read_tail_absolute_range() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$6\r\nSTRLEN\r\n$3\r\nlog\r\n")
+        .response(":120\r\n")
+        .send(
+            164,
+            "*4\r\n$8\r\nGETRANGE\r\n$3\r\nlog\r\n$3\r\n110\r\n$3\r\n119\r\n",
+        )
+        .response_string("last line\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!("last line\n", client.read_tail("log", 10).unwrap());
+}
+
+#[test]
+fn test_read_tail_shorter_value() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$6\r\nSTRLEN\r\n$3\r\nlog\r\n")
+        .response(":4\r\n")
+        .send(164, "*4\r\n$8\r\nGETRANGE\r\n$3\r\nlog\r\n$1\r\n0\r\n$1\r\n3\r\n")
+        .response_string("boot")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!("boot", client.read_tail("log", 100).unwrap());
+}
+
+#[test]
+fn test_read_tail_missing_key() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$6\r\nSTRLEN\r\n$3\r\nlog\r\n")
+        .response(":0\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.read_tail("log", 10).unwrap().is_empty());
+}
+
+#[test]
+fn test_shorthand_getrange() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$8\r\nGETRANGE\r\n$3\r\nkey\r\n$2\r\n-3\r\n$2\r\n-1\r\n",
+        )
+        .response_string("abc")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client.getrange("key", -3, -1).unwrap().wait().unwrap();
+    assert_eq!("abc", response.as_str().unwrap());
+}
+
 #[test]
 fn test_shorthand_get_multi() {
     let clock = TestClock::new(vec![]);
@@ -929,263 +1151,2934 @@ fn test_shorthand_set_bytes_argument() {
 }
 
 #[test]
-fn test_shorthand_publish() {
+fn test_shorthand_incr() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(164, "*3\r\n$7\r\nPUBLISH\r\n$6\r\ncolors\r\n$6\r\norange\r\n")
-        .response(":3\r\n")
+        .send(164, "*2\r\n$4\r\nINCR\r\n$3\r\nkey\r\n")
+        .response(":11\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
     let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
 
-    let response = client.publish("colors", "orange").unwrap().wait().unwrap();
-    assert_eq!(3, response);
+    assert_eq!(11, client.incr("key").unwrap().wait().unwrap());
 }
 
 #[test]
-fn test_shorthand_ping() {
+fn test_shorthand_decr() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(164, "*1\r\n$4\r\nPING\r\n")
-        .response_string("PONG")
+        .send(164, "*2\r\n$4\r\nDECR\r\n$3\r\nkey\r\n")
+        .response(":-1\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
     let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
 
-    client.ping().unwrap().wait().unwrap();
+    assert_eq!(-1, client.decr("key").unwrap().wait().unwrap());
 }
 
 #[test]
-fn test_shorthand_bgsave_non_scheduled() {
+fn test_shorthand_incrby_negative() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(164, "*1\r\n$6\r\nBGSAVE\r\n")
-        .response_string("Background saving started")
+        .send(164, "*3\r\n$6\r\nINCRBY\r\n$3\r\nkey\r\n$2\r\n-5\r\n")
+        .response(":-5\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
     let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
 
-    client.bgsave(false).unwrap().wait().unwrap();
+    assert_eq!(-5, client.incrby("key", -5).unwrap().wait().unwrap());
 }
 
 #[test]
-fn test_shorthand_bgsave_scheduled() {
+fn test_shorthand_decrby() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(164, "*2\r\n$6\r\nBGSAVE\r\n$8\r\nSCHEDULE\r\n")
-        .response_string("Background saving sch")
+        .send(164, "*3\r\n$6\r\nDECRBY\r\n$3\r\nkey\r\n$1\r\n3\r\n")
+        .response(":7\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
     let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
 
-    client.bgsave(true).unwrap().wait().unwrap();
+    assert_eq!(7, client.decrby("key", 3).unwrap().wait().unwrap());
 }
 
 #[test]
-fn test_shorthand_hset_str_argument() {
+fn test_incr_not_integer_error_response() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(
-            164,
-            "*4\r\n$4\r\nHSET\r\n$7\r\nmy_hash\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n",
-        )
-        .response(":1\r\n")
+        .send(164, "*2\r\n$4\r\nINCR\r\n$3\r\nkey\r\n")
+        .response("-ERR value is not an integer or ")
+        .response("out of range\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
     let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
 
-    client.hset("my_hash", "color", "green").unwrap().wait().unwrap();
+    assert_eq!(
+        ErrorResponse("ERR value is not an integer or out of range".to_string()),
+        client.incr("key").unwrap().wait().unwrap_err()
+    );
 }
 
 #[test]
-fn test_shorthand_hset_string_argument() {
+fn test_estimate_key_count_two_pages() {
     let clock = TestClock::new(vec![]);
 
+    // Table of 4 buckets: Cursor 2 => 1/4 visited, cursor 1 => 1/2 visited
     let mut network = NetworkMockBuilder::default()
-        .send(
-            164,
-            "*4\r\n$4\r\nHSET\r\n$7\r\nmy_hash\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n",
-        )
-        .response(":1\r\n")
+        .send(164, "*2\r\n$4\r\nSCAN\r\n$1\r\n0\r\n")
+        .response("*2\r\n$1\r\n2\r\n*2\r\n$1\r\na\r\n$1\r\nb\r\n")
+        .send(164, "*2\r\n$4\r\nSCAN\r\n$1\r\n2\r\n")
+        .response("*2\r\n$1\r\n1\r\n*2\r\n$1\r\nc\r\n$1\r\nd\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
     let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
 
-    client
-        .hset("my_hash".to_string(), "color".to_string(), "green".to_string())
-        .unwrap()
-        .wait()
-        .unwrap();
+    assert_eq!(8, client.estimate_key_count(2).unwrap());
 }
 
 #[test]
-fn test_shorthand_hset_bytes_argument() {
+fn test_estimate_key_count_complete_iteration() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(
-            164,
-            "*4\r\n$4\r\nHSET\r\n$7\r\nmy_hash\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n",
-        )
-        .response(":1\r\n")
+        .send(164, "*2\r\n$4\r\nSCAN\r\n$1\r\n0\r\n")
+        .response("*2\r\n$1\r\n2\r\n*1\r\n$1\r\na\r\n")
+        .send(164, "*2\r\n$4\r\nSCAN\r\n$1\r\n2\r\n")
+        .response("*2\r\n$1\r\n0\r\n*1\r\n$1\r\nb\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
     let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
 
-    client
-        .hset(
-            Bytes::from_static(b"my_hash"),
-            Bytes::from_static(b"color"),
-            Bytes::from_static(b"green"),
-        )
-        .unwrap()
-        .wait()
-        .unwrap();
+    assert_eq!(2, client.estimate_key_count(5).unwrap());
 }
 
 #[test]
-fn test_shorthand_hget_str_argument() {
+fn test_shorthand_lpop() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(164, "*3\r\n$4\r\nHGET\r\n$7\r\nmy_hash\r\n$5\r\nfield\r\n")
-        .response_string("test_response")
+        .send(164, "*2\r\n$4\r\nLPOP\r\n$7\r\nmy_list\r\n")
+        .response_string("head")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
     let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
 
-    assert_eq!(
-        "test_response",
-        client
-            .hget("my_hash", "field")
-            .unwrap()
-            .wait()
-            .unwrap()
-            .unwrap()
-            .as_str()
-            .unwrap()
-    );
+    let response = client.lpop("my_list").unwrap().wait().unwrap().unwrap();
+    assert_eq!("head", response.as_str().unwrap());
 }
 
 #[test]
-fn test_shorthand_hget_string_argument() {
+fn test_shorthand_rpop_empty_list() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(164, "*3\r\n$4\r\nHGET\r\n$7\r\nmy_hash\r\n$5\r\nfield\r\n")
-        .response_string("test_response")
+        .send(164, "*2\r\n$4\r\nRPOP\r\n$7\r\nmy_list\r\n")
+        .response("_\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
-    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {});
 
-    let response = client.hget("my_hash".to_string(), "field".to_string()).unwrap().wait();
-    assert_eq!("test_response", response.unwrap().unwrap().as_str().unwrap());
+    assert!(client.rpop("my_list").unwrap().wait().unwrap().is_none());
 }
 
 #[test]
-fn test_shorthand_hget_bytes_argument() {
+fn test_shorthand_lpush() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(164, "*3\r\n$4\r\nHGET\r\n$7\r\nmy_hash\r\n$5\r\nfield\r\n")
-        .response_string("test_response")
+        .send(164, "*3\r\n$5\r\nLPUSH\r\n$7\r\nmy_list\r\n$4\r\nhead\r\n")
+        .response(":1\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
     let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
 
-    let response = client
-        .hget(Bytes::from_static(b"my_hash"), Bytes::from_static(b"field"))
-        .unwrap()
-        .wait();
-    assert_eq!("test_response", response.unwrap().unwrap().as_str().unwrap());
+    assert_eq!(1, client.lpush("my_list", "head").unwrap().wait().unwrap());
 }
 
 #[test]
-fn test_shorthand_hgetall_str_argument() {
+fn test_shorthand_rpush() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(164, "*2\r\n$7\r\nHGETALL\r\n$7\r\nmy_hash\r\n")
-        .response("*2\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n")
+        .send(164, "*3\r\n$5\r\nRPUSH\r\n$7\r\nmy_list\r\n$4\r\ntail\r\n")
+        .response(":2\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
-    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {});
 
-    assert_eq!(
-        "green",
-        client
-            .hgetall("my_hash")
-            .unwrap()
-            .wait()
-            .unwrap()
-            .unwrap()
-            .get_str("color")
-            .unwrap()
-    );
+    assert_eq!(2, client.rpush("my_list", "tail").unwrap().wait().unwrap());
 }
 
 #[test]
-fn test_shorthand_hgetall_string_argument() {
+fn test_shorthand_llen() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(164, "*2\r\n$7\r\nHGETALL\r\n$7\r\nmy_hash\r\n")
-        .response("*2\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n")
+        .send(164, "*2\r\n$4\r\nLLEN\r\n$7\r\nmy_list\r\n")
+        .response(":2\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
     let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
 
-    assert_eq!(
-        "green",
-        client
-            .hgetall("my_hash".to_string())
-            .unwrap()
-            .wait()
-            .unwrap()
-            .unwrap()
-            .get_str("color")
-            .unwrap()
+    assert_eq!(2, client.llen("my_list").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_lrange() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$6\r\nLRANGE\r\n$7\r\nmy_list\r\n$1\r\n0\r\n$2\r\n-1\r\n",
+        )
+        .response("*1\r\n$4\r\nhead\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client.lrange("my_list", 0, -1).unwrap().wait().unwrap();
+    assert_eq!(vec!["head"], response);
+}
+
+#[test]
+fn test_shorthand_mget_mixed_resp2() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$4\r\nMGET\r\n$1\r\na\r\n$1\r\nb\r\n")
+        .response("*2\r\n$3\r\nfoo\r\n$-1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client.mget(["a".into(), "b".into()]).unwrap().wait().unwrap();
+    assert_eq!("foo", response[0].as_ref().unwrap().as_str().unwrap());
+    assert!(response[1].is_none());
+}
+
+#[test]
+fn test_shorthand_mget_mixed_resp3() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$4\r\nMGET\r\n$1\r\na\r\n$1\r\nb\r\n")
+        .response("*2\r\n$3\r\nfoo\r\n_\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {});
+
+    let response = client.mget(["a".into(), "b".into()]).unwrap().wait().unwrap();
+    assert_eq!("foo", response[0].as_ref().unwrap().as_str().unwrap());
+    assert!(response[1].is_none());
+}
+
+#[test]
+fn test_shorthand_mset() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*5\r\n$4\r\nMSET\r\n$1\r\na\r\n$1\r\n1\r\n$1\r\nb\r\n$1\r\n2\r\n",
+        )
+        .response_ok()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client
+        .mset([("a".into(), "1".into()), ("b".into(), "2".into())])
+        .unwrap()
+        .wait()
+        .unwrap();
+}
+
+#[test]
+fn test_shorthand_no_touch() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$6\r\nCLIENT\r\n$8\r\nNO-TOUCH\r\n$2\r\nON\r\n")
+        .response_ok()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.no_touch(true).unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_shorthand_setrange() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$8\r\nSETRANGE\r\n$3\r\nkey\r\n$1\r\n6\r\n$5\r\nRedis\r\n",
+        )
+        .response(":11\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(11, client.setrange("key", 6, "Redis").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_setnx() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$5\r\nSETNX\r\n$3\r\nkey\r\n$5\r\nvalue\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.setnx("key", "value").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_setex() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$5\r\nSETEX\r\n$3\r\nkey\r\n$2\r\n60\r\n$5\r\nvalue\r\n",
+        )
+        .response_ok()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.setex("key", 60, "value").unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_shorthand_strlen() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$6\r\nSTRLEN\r\n$3\r\nkey\r\n")
+        .response(":0\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(0, client.strlen("key").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_publish() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$7\r\nPUBLISH\r\n$6\r\ncolors\r\n$6\r\norange\r\n")
+        .response(":3\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client.publish("colors", "orange").unwrap().wait().unwrap();
+    assert_eq!(3, response);
+}
+
+#[test]
+fn test_shorthand_ping() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*1\r\n$4\r\nPING\r\n")
+        .response_string("PONG")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.ping().unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_shorthand_bgsave_non_scheduled() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*1\r\n$6\r\nBGSAVE\r\n")
+        .response_string("Background saving started")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.bgsave(false).unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_shorthand_bgsave_scheduled() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$6\r\nBGSAVE\r\n$8\r\nSCHEDULE\r\n")
+        .response_string("Background saving sch")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.bgsave(true).unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_shorthand_hincrby() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$7\r\nHINCRBY\r\n$7\r\nmy_hash\r\n$7\r\ncounter\r\n$2\r\n-3\r\n",
+        )
+        .response(":7\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        7,
+        client.hincrby("my_hash", "counter", -3).unwrap().wait().unwrap()
+    );
+}
+
+#[test]
+fn test_shorthand_hkeys() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$5\r\nHKEYS\r\n$7\r\nmy_hash\r\n")
+        .response("*1\r\n$5\r\ncolor\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        vec![Bytes::from_static(b"color")],
+        client.hkeys("my_hash").unwrap().wait().unwrap()
+    );
+}
+
+#[test]
+fn test_shorthand_hvals_missing_hash() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$5\r\nHVALS\r\n$7\r\nmy_hash\r\n")
+        .response("*0\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.hvals("my_hash").unwrap().wait().unwrap().is_empty());
+}
+
+#[test]
+fn test_shorthand_hmget() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$5\r\nHMGET\r\n$7\r\nmy_hash\r\n$5\r\ncolor\r\n$4\r\nsize\r\n",
+        )
+        .response("*2\r\n$-1\r\n$5\r\ngreen\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client.hmget("my_hash", "color", "size").unwrap().wait().unwrap();
+    assert!(response[0].is_none());
+    assert_eq!("green", response[1].as_ref().unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_shorthand_hlen() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$4\r\nHLEN\r\n$7\r\nmy_hash\r\n")
+        .response(":2\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(2, client.hlen("my_hash").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_hset_str_argument() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$4\r\nHSET\r\n$7\r\nmy_hash\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n",
+        )
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.hset("my_hash", "color", "green").unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_shorthand_hset_string_argument() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$4\r\nHSET\r\n$7\r\nmy_hash\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n",
+        )
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client
+        .hset("my_hash".to_string(), "color".to_string(), "green".to_string())
+        .unwrap()
+        .wait()
+        .unwrap();
+}
+
+#[test]
+fn test_shorthand_hset_bytes_argument() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$4\r\nHSET\r\n$7\r\nmy_hash\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n",
+        )
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client
+        .hset(
+            Bytes::from_static(b"my_hash"),
+            Bytes::from_static(b"color"),
+            Bytes::from_static(b"green"),
+        )
+        .unwrap()
+        .wait()
+        .unwrap();
+}
+
+#[test]
+fn test_shorthand_hdel() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$4\r\nHDEL\r\n$7\r\nmy_hash\r\n$5\r\ncolor\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(1, client.hdel("my_hash", "color").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_hexists() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$7\r\nHEXISTS\r\n$7\r\nmy_hash\r\n$5\r\ncolor\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.hexists("my_hash", "color").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_hsetnx() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$6\r\nHSETNX\r\n$7\r\nmy_hash\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n",
+        )
+        .response(":0\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(!client.hsetnx("my_hash", "color", "green").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_sismember_resp2_integer() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$9\r\nSISMEMBER\r\n$6\r\nmy_set\r\n$5\r\ngreen\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.sismember("my_set", "green").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_sismember_resp3_boolean() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$9\r\nSISMEMBER\r\n$6\r\nmy_set\r\n$5\r\ngreen\r\n")
+        .response("#t\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {});
+
+    assert!(client.sismember("my_set", "green").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_scard() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$5\r\nSCARD\r\n$6\r\nmy_set\r\n")
+        .response(":2\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(2, client.scard("my_set").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_smembers_resp3_set() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$8\r\nSMEMBERS\r\n$6\r\nmy_set\r\n")
+        .response("~2\r\n$3\r\nred\r\n$5\r\ngreen\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {});
+
+    let mut members = client.smembers("my_set").unwrap().wait().unwrap();
+    members.sort();
+    assert_eq!(vec!["green", "red"], members);
+}
+
+#[test]
+fn test_shorthand_zadd() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$4\r\nZADD\r\n$6\r\nscores\r\n$4\r\n12.5\r\n$5\r\nfirst\r\n",
+        )
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(1, client.zadd("scores", 12.5, "first").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_zscore() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$6\r\nZSCORE\r\n$6\r\nscores\r\n$5\r\nfirst\r\n")
+        .response("$3\r\n3.5\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        Some(3.5),
+        client.zscore("scores", "first").unwrap().wait().unwrap()
+    );
+}
+
+#[test]
+fn test_shorthand_zrem() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$4\r\nZREM\r\n$6\r\nscores\r\n$5\r\nfirst\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(1, client.zrem("scores", "first").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_hget_str_argument() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$4\r\nHGET\r\n$7\r\nmy_hash\r\n$5\r\nfield\r\n")
+        .response_string("test_response")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        "test_response",
+        client
+            .hget("my_hash", "field")
+            .unwrap()
+            .wait()
+            .unwrap()
+            .unwrap()
+            .as_str()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_shorthand_hget_string_argument() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$4\r\nHGET\r\n$7\r\nmy_hash\r\n$5\r\nfield\r\n")
+        .response_string("test_response")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client.hget("my_hash".to_string(), "field".to_string()).unwrap().wait();
+    assert_eq!("test_response", response.unwrap().unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_shorthand_hget_bytes_argument() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$4\r\nHGET\r\n$7\r\nmy_hash\r\n$5\r\nfield\r\n")
+        .response_string("test_response")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client
+        .hget(Bytes::from_static(b"my_hash"), Bytes::from_static(b"field"))
+        .unwrap()
+        .wait();
+    assert_eq!("test_response", response.unwrap().unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_shorthand_hgetall_str_argument() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$7\r\nHGETALL\r\n$7\r\nmy_hash\r\n")
+        .response("*2\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        "green",
+        client
+            .hgetall("my_hash")
+            .unwrap()
+            .wait()
+            .unwrap()
+            .unwrap()
+            .get_str("color")
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_shorthand_hgetall_string_argument() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$7\r\nHGETALL\r\n$7\r\nmy_hash\r\n")
+        .response("*2\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        "green",
+        client
+            .hgetall("my_hash".to_string())
+            .unwrap()
+            .wait()
+            .unwrap()
+            .unwrap()
+            .get_str("color")
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_shorthand_hgetall_bytes_argument() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$7\r\nHGETALL\r\n$7\r\nmy_hash\r\n")
+        .response("*2\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        "green",
+        client
+            .hgetall(Bytes::from_static(b"my_hash"))
+            .unwrap()
+            .wait()
+            .unwrap()
+            .unwrap()
+            .get_str("color")
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_shorthand_sinterstore() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$11\r\nSINTERSTORE\r\n$4\r\ndest\r\n$1\r\na\r\n$1\r\nb\r\n",
+        )
+        .response(":2\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client.sinterstore("dest", ["a".into(), "b".into()]).unwrap().wait().unwrap();
+    assert_eq!(2, response);
+}
+
+#[test]
+fn test_shorthand_sunionstore() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$11\r\nSUNIONSTORE\r\n$4\r\ndest\r\n$1\r\na\r\n$1\r\nb\r\n",
+        )
+        .response(":5\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client.sunionstore("dest", ["a".into(), "b".into()]).unwrap().wait().unwrap();
+    assert_eq!(5, response);
+}
+
+#[test]
+fn test_shorthand_sdiffstore() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$10\r\nSDIFFSTORE\r\n$4\r\ndest\r\n$1\r\na\r\n$1\r\nb\r\n",
+        )
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client.sdiffstore("dest", ["a".into(), "b".into()]).unwrap().wait().unwrap();
+    assert_eq!(1, response);
+}
+
+#[test]
+fn test_shorthand_zrangebyscore() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$13\r\nZRANGEBYSCORE\r\n$3\r\nkey\r\n$4\r\n-inf\r\n$2\r\n(2\r\n",
+        )
+        .response("*1\r\n$3\r\none\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client
+        .zrangebyscore("key", ScoreBound::NegInfinity, ScoreBound::Exclusive(2.0))
+        .unwrap()
+        .wait()
+        .unwrap();
+    assert_eq!(vec!["one"], response);
+}
+
+#[test]
+fn test_shorthand_zrangebylex() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$11\r\nZRANGEBYLEX\r\n$3\r\nkey\r\n$2\r\n[a\r\n$1\r\n+\r\n",
+        )
+        .response("*2\r\n$1\r\na\r\n$1\r\nb\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let response = client
+        .zrangebylex("key", LexBound::inclusive("a"), LexBound::Max)
+        .unwrap()
+        .wait()
+        .unwrap();
+    assert_eq!(vec!["a", "b"], response);
+}
+
+#[test]
+fn test_swap_value_previous_existing() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*4\r\n$3\r\nSET\r\n$3\r\nkey\r\n$3\r\nnew\r\n$3\r\nGET\r\n")
+        .response("$3\r\nold\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let previous = client.swap_value("key", "new").unwrap();
+    assert_eq!("old", previous.unwrap());
+}
+
+#[test]
+fn test_swap_value_previous_missing() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*4\r\n$3\r\nSET\r\n$3\r\nkey\r\n$3\r\nnew\r\n$3\r\nGET\r\n")
+        .response_null_resp2()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.swap_value("key", "new").unwrap().is_none());
+}
+
+#[test]
+fn test_swap_value_error_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*4\r\n$3\r\nSET\r\n$3\r\nkey\r\n$3\r\nnew\r\n$3\r\nGET\r\n")
+        .response_error()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        ErrorResponse("Error".to_string()),
+        client.swap_value("key", "new").unwrap_err()
+    );
+}
+
+#[test]
+fn test_get_or_set_existing_value() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*5\r\n$3\r\nSET\r\n$3\r\nkey\r\n$3\r\ndef\r\n$2\r\nNX\r\n$3\r\nGET\r\n",
+        )
+        .response("$3\r\nold\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let (value, created) = client.get_or_set("key", "def", None).unwrap();
+    assert_eq!("old", value);
+    assert!(!created);
+}
+
+#[test]
+fn test_get_or_set_created_default() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*7\r\n$3\r\nSET\r\n$3\r\nkey\r\n$3\r\ndef\r\n$2\r\nPX\r\n$3\r\n100\r\n$2\r\nNX\r\n$3\r\nGET\r\n",
+        )
+        .response_null_resp2()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let (value, created) = client.get_or_set("key", "def", Some(100)).unwrap();
+    assert_eq!("def", value);
+    assert!(created);
+}
+
+#[test]
+fn test_shorthand_swapdb() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$6\r\nSWAPDB\r\n$1\r\n0\r\n$1\r\n1\r\n")
+        .response_ok()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.swapdb(0, 1).unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_incr_bounded_wraps_to_zero() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*5\r\n$4\r\nEVAL\r\n$131\r\nlocal value = redis.call('INCR', KEYS[1]) \
+             if value > tonumber(ARGV[1]) then redis.call('SET', KEYS[1], 0) return 0 end \
+             return value\r\n$1\r\n1\r\n$7\r\ncounter\r\n$2\r\n10\r\n",
+        )
+        .response(":0\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(0, client.incr_bounded("counter", 10).unwrap());
+}
+
+#[test]
+fn test_set_if_ttl_greater_written() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*6\r\n$4\r\nEVAL\r\n$173\r\nlocal ttl = redis.call('PTTL', KEYS[1]) \
+             if ttl == -1 or (ttl >= 0 and ttl >= tonumber(ARGV[2])) then return 0 end \
+             redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2]) return 1\r\n\
+             $1\r\n1\r\n$3\r\nkey\r\n$5\r\nvalue\r\n$4\r\n5000\r\n",
+        )
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.set_if_ttl_greater("key", "value", 5000).unwrap());
+}
+
+#[test]
+fn test_set_if_ttl_greater_skipped() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().send(164, "").response(":0\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(!client.set_if_ttl_greater("key", "value", 1000).unwrap());
+}
+
+#[test]
+fn test_set_if_ttl_greater_invalid_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().send(164, "").response(":2\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        CommandErrors::CommandResponseViolation,
+        client.set_if_ttl_greater("key", "value", 1000).unwrap_err()
+    );
+}
+
+#[test]
+fn test_shorthand_client_list() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$6\r\nCLIENT\r\n$4\r\nLIST\r\n")
+        .response("$15\r\nid=7 addr=a:1 \n\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let clients = client.client_list().unwrap().wait().unwrap();
+    assert_eq!(1, clients.len());
+    assert_eq!(7, clients[0].id().unwrap());
+}
+
+#[test]
+fn test_shorthand_exists() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$6\r\nEXISTS\r\n$1\r\na\r\n$1\r\nb\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        1,
+        client.exists(["a".into(), "b".into()]).unwrap().wait().unwrap()
+    );
+}
+
+#[test]
+fn test_exists_all_true() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().send(164, "").response(":2\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.exists_all(["a".into(), "b".into()]).unwrap());
+}
+
+#[test]
+fn test_exists_all_false() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().send(164, "").response(":1\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(!client.exists_all(["a".into(), "b".into()]).unwrap());
+}
+
+#[test]
+fn test_exists_all_duplicate_keys_missing_key() {
+    let clock = TestClock::new(vec![]);
+
+    // Key "a" exists and is counted twice, while "b" is missing
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*4\r\n$6\r\nEXISTS\r\n$1\r\na\r\n$1\r\na\r\n$1\r\nb\r\n")
+        .response(":2\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(!client.exists_all(["a".into(), "a".into(), "b".into()]).unwrap());
+}
+
+#[test]
+fn test_exists_all_duplicate_keys_existing() {
+    let clock = TestClock::new(vec![]);
+
+    // Existing key "a" is counted twice
+    let mut network = NetworkMockBuilder::default().send(164, "").response(":3\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.exists_all(["a".into(), "a".into(), "b".into()]).unwrap());
+}
+
+#[test]
+fn test_exists_all_empty_keys() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.exists_all([]).unwrap());
+    assert!(!client.exists_any([]).unwrap());
+}
+
+#[test]
+fn test_exists_any_true() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().send(164, "").response(":1\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.exists_any(["a".into(), "b".into()]).unwrap());
+}
+
+#[test]
+fn test_exists_any_false() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().send(164, "").response(":0\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(!client.exists_any(["a".into(), "b".into()]).unwrap());
+}
+
+#[test]
+fn test_send_empty_command_rejected() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let result = client.send(CommandBuilder::default().to_command());
+    assert_eq!(CommandErrors::EncodingCommandFailed, result.err().unwrap());
+}
+
+#[test]
+fn test_send_empty_command_rejected_resp3() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {});
+
+    let result = client.send(CommandBuilder::default().to_command());
+    assert_eq!(CommandErrors::EncodingCommandFailed, result.err().unwrap());
+}
+
+#[test]
+fn test_pipeline_empty_command_rejected() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let pipeline = Pipeline::new()
+        .push(CommandBuilder::new("GET").arg_static("key"))
+        .push(CommandBuilder::default());
+    assert_eq!(
+        CommandErrors::EncodingCommandFailed,
+        client.pipeline(pipeline).unwrap_err()
+    );
+}
+
+#[test]
+fn test_pipeline_raw_frames_in_order() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .send(164, "*2\r\n$4\r\nINCR\r\n$7\r\ncounter\r\n")
+        .response("$5\r\nvalue\r\n:3\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let pipeline = Pipeline::new()
+        .push(CommandBuilder::new("GET").arg_static("key"))
+        .push(CommandBuilder::new("INCR").arg_static("counter"));
+    let frames = client.pipeline(pipeline).unwrap();
+
+    assert_eq!(2, frames.len());
+    assert_eq!(Resp2Frame::BulkString(Bytes::from_static(b"value")), frames[0]);
+    assert_eq!(Resp2Frame::Integer(3), frames[1]);
+}
+
+#[test]
+fn test_pipeline_partial_send_failure_invalidates_futures() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .send_error()
+        .response("$5\r\nstale\r\n") // Response of the first pipeline command, discarded on next send
+        .response_no_data()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response("$5\r\nfresh\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let pipeline = Pipeline::new()
+        .push(CommandBuilder::new("GET").arg_static("key"))
+        .push(CommandBuilder::new("INCR").arg_static("counter"));
+    assert_eq!(CommandErrors::TcpError, client.pipeline(pipeline).unwrap_err());
+
+    let response = client.get("key").unwrap().wait().unwrap().unwrap();
+    assert_eq!("fresh", response.as_str().unwrap());
+    assert_eq!(0, client.network.get_pending_frame_count());
+}
+
+#[test]
+fn test_pipeline_with_capacity_no_reallocation() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .send(164, "*2\r\n$4\r\nINCR\r\n$7\r\ncounter\r\n")
+        .response("$5\r\nvalue\r\n:3\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let memory = MemoryParameters {
+        frame_capacity: 0,
+        ..MemoryParameters::default()
+    };
+    let client = Client::from_socket(&mut network, &mut socket, Some(&clock), Resp2 {}, memory);
+
+    let pipeline = Pipeline::with_capacity(2)
+        .push(CommandBuilder::new("GET").arg_static("key"))
+        .push(CommandBuilder::new("INCR").arg_static("counter"));
+    let frames = client.pipeline(pipeline).unwrap();
+
+    assert_eq!(2, frames.len());
+    assert_eq!(Resp2Frame::Integer(3), frames[1]);
+    assert_eq!(2, client.network.get_frame_capacity());
+}
+
+#[test]
+fn test_pipeline_encoding_single_allocation() {
+    let clock = TestClock::new(vec![]);
+    let mut network = NetworkMockBuilder::default().into_mock();
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let frames: Vec<Resp2Frame> = vec![
+        CommandBuilder::new("GET").arg_static("key").into(),
+        CommandBuilder::new("INCR").arg_static("counter").into(),
+    ];
+    let parts = client.network.encode_all(&frames).unwrap();
+
+    assert_eq!(b"*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n", &parts[0][..]);
+    assert_eq!(b"*2\r\n$4\r\nINCR\r\n$7\r\ncounter\r\n", &parts[1][..]);
+
+    // All parts share one contiguous allocation
+    assert_eq!(
+        unsafe { parts[0].as_ptr().add(parts[0].len()) },
+        parts[1].as_ptr()
+    );
+    assert_eq!(0, parts[1].capacity() - parts[1].len());
+}
+
+#[test]
+fn test_typed_pipeline_get_and_incr() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .send(164, "*2\r\n$4\r\nINCR\r\n$7\r\ncounter\r\n")
+        .response("$5\r\nvalue\r\n:3\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let get = GetCommand::static_key("key");
+    let incr = CommandBuilder::new("INCR").arg_static("counter").to_command().expect_integer();
+    let (value, counter) = client.typed_pipeline((get, incr)).unwrap();
+
+    assert_eq!("value", value.unwrap().as_str().unwrap());
+    assert_eq!(3, counter);
+}
+
+#[test]
+fn test_typed_pipeline_reserves_frame_capacity() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .send(164, "*2\r\n$4\r\nINCR\r\n$7\r\ncounter\r\n")
+        .response("$5\r\nvalue\r\n:3\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let memory = MemoryParameters {
+        frame_capacity: 0,
+        ..MemoryParameters::default()
+    };
+    let client = Client::from_socket(&mut network, &mut socket, Some(&clock), Resp2 {}, memory);
+
+    let get = GetCommand::static_key("key");
+    let incr = CommandBuilder::new("INCR").arg_static("counter").to_command().expect_integer();
+    client.typed_pipeline((get, incr)).unwrap();
+
+    assert_eq!(2, client.network.get_frame_capacity());
+}
+
+#[test]
+fn test_pipeline_timer_failure_nothing_sent() {
+    // Clock fails on starting the timeouts
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().into_mock();
+    let mut socket = SocketMock::new(164);
+    let mut client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+    client.timeout_duration = 1000.microseconds();
+
+    let pipeline = Pipeline::new()
+        .push(CommandBuilder::new("GET").arg_static("key"))
+        .push(CommandBuilder::new("INCR").arg_static("counter"));
+    assert_eq!(CommandErrors::TimerError, client.pipeline(pipeline).unwrap_err());
+    assert_eq!(0, client.network.get_pending_frame_count());
+}
+
+#[test]
+fn test_typed_pipeline_error_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .send(164, "")
+        .response("$-1\r\n-ERR not an integer\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let get = GetCommand::static_key("key");
+    let incr = CommandBuilder::new("INCR").arg_static("counter").to_command().expect_integer();
+    let result = client.typed_pipeline((get, incr));
+
+    assert_eq!(
+        CommandErrors::ErrorResponse("ERR not an integer".to_string()),
+        result.unwrap_err()
+    );
+}
+
+#[test]
+fn test_from_socket_get() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_string("value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = Client::from_socket(
+        &mut network,
+        &mut socket,
+        Some(&clock),
+        Resp2 {},
+        MemoryParameters::default(),
+    );
+
+    let response = client.get("key").unwrap().wait().unwrap();
+    assert_eq!("value", response.unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_from_socket_resp3_no_hello_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().into_mock();
+    let mut socket = SocketMock::new(164);
+    let client = Client::from_socket(
+        &mut network,
+        &mut socket,
+        Some(&clock),
+        Resp3 {},
+        MemoryParameters::default(),
+    );
+
+    assert!(client.try_hello_response().is_none());
+}
+
+#[test]
+fn test_shorthand_expire() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$6\r\nEXPIRE\r\n$3\r\nkey\r\n$2\r\n60\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.expire("key", 60).unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_ttl() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nTTL\r\n$3\r\nkey\r\n")
+        .response(":-1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(TtlResponse::NoExpiry, client.ttl("key").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_ttl_non_integer_response_violation() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$4\r\nPTTL\r\n$3\r\nkey\r\n")
+        .response_string("120")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        CommandResponseViolation,
+        client.pttl("key").unwrap().wait().unwrap_err()
+    );
+}
+
+#[test]
+fn test_shorthand_persist_without_timeout() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$7\r\nPERSIST\r\n$3\r\nkey\r\n")
+        .response(":0\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(!client.persist("key").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_persist_missing_key() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$7\r\nPERSIST\r\n$7\r\nmissing\r\n")
+        .response(":0\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(!client.persist("missing").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_ttl_remaining_ratio() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$4\r\nPTTL\r\n$3\r\nkey\r\n")
+        .response(":15000\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(Some(0.25), client.ttl_remaining_ratio("key", 60_000).unwrap());
+}
+
+#[test]
+fn test_ttl_remaining_ratio_no_expiry() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$4\r\nPTTL\r\n$3\r\nkey\r\n")
+        .response(":-1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(None, client.ttl_remaining_ratio("key", 60_000).unwrap());
+}
+
+#[test]
+fn test_ttl_remaining_ratio_missing_key() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$4\r\nPTTL\r\n$3\r\nkey\r\n")
+        .response(":-2\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(None, client.ttl_remaining_ratio("key", 60_000).unwrap());
+}
+
+#[test]
+fn test_touch_ttl_existing_key() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$7\r\nPEXPIRE\r\n$3\r\nkey\r\n$4\r\n5000\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.touch_ttl("key", 5000).unwrap());
+}
+
+#[test]
+fn test_touch_ttl_missing_key() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().send(164, "").response(":0\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(!client.touch_ttl("key", 5000).unwrap());
+}
+
+#[test]
+fn test_shorthand_wait_replicas() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$4\r\nWAIT\r\n$1\r\n2\r\n$3\r\n100\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(1, client.wait_replicas(2, 100).unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_wait_aof() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*4\r\n$7\r\nWAITAOF\r\n$1\r\n1\r\n$1\r\n2\r\n$3\r\n100\r\n")
+        .response("*2\r\n:1\r\n:2\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!((1, 2), client.wait_aof(1, 2, 100).unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_shorthand_xgroup_create() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*6\r\n$6\r\nXGROUP\r\n$6\r\nCREATE\r\n$1\r\ns\r\n$1\r\ng\r\n$1\r\n$\r\n$8\r\nMKSTREAM\r\n",
+        )
+        .response_ok()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.xgroup_create("s", "g", "$").unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_shorthand_xreadgroup() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*9\r\n$10\r\nXREADGROUP\r\n$5\r\nGROUP\r\n$1\r\ng\r\n$1\r\nc\r\n$5\r\nCOUNT\r\n$1\r\n5\r\n$7\r\nSTREAMS\r\n$1\r\ns\r\n$1\r\n>\r\n",
+        )
+        .response("*1\r\n*2\r\n$1\r\ns\r\n*1\r\n*2\r\n")
+        .response("$3\r\n1-0\r\n*2\r\n$1\r\na\r\n$1\r\nb\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let entries = client.xreadgroup("g", "c", "s", 5).unwrap().wait().unwrap();
+    assert_eq!(1, entries.len());
+    assert_eq!("1-0", entries[0].id);
+    assert_eq!(
+        Some(vec![(Bytes::from_static(b"a"), Bytes::from_static(b"b"))]),
+        entries[0].fields
+    );
+}
+
+#[test]
+fn test_shorthand_xack() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*4\r\n$4\r\nXACK\r\n$1\r\ns\r\n$1\r\ng\r\n$3\r\n1-0\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(1, client.xack("s", "g", "1-0").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_wait_replication_retry_reaches_count() {
+    let clock = TestClock::new(vec![100; 16]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$4\r\nWAIT\r\n$1\r\n2\r\n$2\r\n50\r\n")
+        .response(":0\r\n")
+        .send(164, "*3\r\n$4\r\nWAIT\r\n$1\r\n2\r\n$2\r\n50\r\n")
+        .response(":2\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = Client {
+        network: Network::new(
+            RefCell::new(&mut network),
+            RefCell::new(&mut socket),
+            Resp2 {},
+            MemoryParameters::default(),
+        ),
+        timeout_duration: 1000.microseconds(),
+        clock: Some(&clock),
+        hello_response: None,
+        assumed_version: None,
+    };
+
+    assert_eq!(2, client.wait_replication(2, 50).unwrap());
+}
+
+#[test]
+fn test_set_durable_replicated() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$5\r\nvalue\r\n")
+        .response_ok()
+        .send(164, "*3\r\n$4\r\nWAIT\r\n$1\r\n2\r\n$3\r\n100\r\n")
+        .response(":2\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.set_durable("key", "value", 2, 100).unwrap();
+}
+
+#[test]
+fn test_set_durable_insufficient_replicas() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$5\r\nvalue\r\n")
+        .response_ok()
+        .send(164, "*3\r\n$4\r\nWAIT\r\n$1\r\n2\r\n$3\r\n100\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let result = client.set_durable("key", "value", 2, 100);
+    assert_eq!(CommandErrors::InsufficientReplicas(1), result.unwrap_err());
+}
+
+#[test]
+fn test_wait_replication_best_count_on_timeout() {
+    #[cfg(not(feature = "metrics"))]
+    let clock = TestClock::new(vec![
+        100,  // Replication timer creation
+        100,  // First WAIT timer creation
+        200,  // Replication timer check
+        200,  // Second WAIT timer creation
+        2000, // Replication timer check
+    ]);
+
+    #[cfg(feature = "metrics")]
+    let clock = TestClock::new(vec![
+        100,  // Replication timer creation
+        100,  // First WAIT timer creation
+        150,  // First WAIT completion
+        200,  // Replication timer check
+        200,  // Second WAIT timer creation
+        250,  // Second WAIT completion
+        2000, // Replication timer check
+    ]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .response(":1\r\n")
+        .send(164, "")
+        .response(":0\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = Client {
+        network: Network::new(
+            RefCell::new(&mut network),
+            RefCell::new(&mut socket),
+            Resp2 {},
+            MemoryParameters::default(),
+        ),
+        timeout_duration: 1000.microseconds(),
+        clock: Some(&clock),
+        hello_response: None,
+        assumed_version: None,
+    };
+
+    assert_eq!(1, client.wait_replication(2, 50).unwrap());
+}
+
+#[test]
+fn test_wait_replication_single_shot_without_timeout() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().send(164, "").response(":0\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(0, client.wait_replication(2, 50).unwrap());
+}
+
+#[test]
+fn test_rpush_capped_exceeding_max() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*6\r\n$4\r\nEVAL\r\n$180\r\nlocal length = redis.call('RPUSH', KEYS[1], ARGV[1]) \
+             local max = tonumber(ARGV[2]) if length <= max then return length end \
+             redis.call('LTRIM', KEYS[1], length - max, -1) return max\r\n$1\r\n1\r\n\
+             $5\r\nqueue\r\n$5\r\nvalue\r\n$1\r\n3\r\n",
+        )
+        .response(":3\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(3, client.rpush_capped("queue", "value", 3).unwrap());
+}
+
+#[test]
+fn test_lpush_capped_exceeding_max() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*6\r\n$4\r\nEVAL\r\n$183\r\nlocal length = redis.call('LPUSH', KEYS[1], ARGV[1]) \
+             local max = tonumber(ARGV[2]) if length <= max then return length end \
+             redis.call('LTRIM', KEYS[1], 0, max - 1 - length) return max\r\n$1\r\n1\r\n\
+             $5\r\nqueue\r\n$5\r\nvalue\r\n$1\r\n3\r\n",
+        )
+        .response(":3\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(3, client.lpush_capped("queue", "value", 3).unwrap());
+}
+
+#[test]
+fn test_migrate_all_preserves_ttl() {
+    let clock = TestClock::new(vec![]);
+
+    let mut source_network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$4\r\nSCAN\r\n$1\r\n0\r\n$5\r\nMATCH\r\n$5\r\nkey:*\r\n",
+        )
+        .response("*2\r\n$1\r\n7\r\n*1\r\n$5\r\nkey:a\r\n")
+        .send(164, "*2\r\n$4\r\nDUMP\r\n$5\r\nkey:a\r\n")
+        .response("$4\r\ndmpA\r\n")
+        .send(164, "*2\r\n$4\r\nPTTL\r\n$5\r\nkey:a\r\n")
+        .response(":5000\r\n")
+        .send(
+            164,
+            "*4\r\n$4\r\nSCAN\r\n$1\r\n7\r\n$5\r\nMATCH\r\n$5\r\nkey:*\r\n",
+        )
+        .response("*2\r\n$1\r\n0\r\n*1\r\n$5\r\nkey:b\r\n")
+        .send(164, "*2\r\n$4\r\nDUMP\r\n$5\r\nkey:b\r\n")
+        .response("$4\r\ndmpB\r\n")
+        .send(164, "*2\r\n$4\r\nPTTL\r\n$5\r\nkey:b\r\n")
+        .response(":-1\r\n")
+        .into_mock();
+
+    let mut destination_network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*5\r\n$7\r\nRESTORE\r\n$5\r\nkey:a\r\n$4\r\n5000\r\n$4\r\ndmpA\r\n$7\r\nREPLACE\r\n",
+        )
+        .response_ok()
+        .send(
+            164,
+            "*5\r\n$7\r\nRESTORE\r\n$5\r\nkey:b\r\n$1\r\n0\r\n$4\r\ndmpB\r\n$7\r\nREPLACE\r\n",
+        )
+        .response_ok()
+        .send(164, "*2\r\n$4\r\nPTTL\r\n$5\r\nkey:a\r\n")
+        .response(":5000\r\n")
+        .send(164, "*2\r\n$4\r\nPTTL\r\n$5\r\nkey:b\r\n")
+        .response(":-1\r\n")
+        .into_mock();
+
+    let mut source_socket = SocketMock::new(164);
+    let mut destination_socket = SocketMock::new(164);
+    let source = create_mocked_client(&mut source_network, &mut source_socket, &clock, Resp2 {});
+    let destination = create_mocked_client(
+        &mut destination_network,
+        &mut destination_socket,
+        &clock,
+        Resp2 {},
+    );
+
+    assert_eq!(2, source.migrate_all(&destination, "key:*").unwrap());
+    assert_eq!(
+        TtlResponse::Duration(5000),
+        destination.pttl("key:a").unwrap().wait().unwrap()
+    );
+    assert_eq!(
+        TtlResponse::NoExpiry,
+        destination.pttl("key:b").unwrap().wait().unwrap()
+    );
+}
+
+#[test]
+fn test_migrate_all_skips_deleted_keys() {
+    let clock = TestClock::new(vec![]);
+
+    let mut source_network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$4\r\nSCAN\r\n$1\r\n0\r\n$5\r\nMATCH\r\n$5\r\nkey:*\r\n",
+        )
+        .response("*2\r\n$1\r\n0\r\n*1\r\n$5\r\nkey:a\r\n")
+        .send(164, "*2\r\n$4\r\nDUMP\r\n$5\r\nkey:a\r\n")
+        .response_null_resp2()
+        .into_mock();
+
+    let mut destination_network = NetworkMockBuilder::default().into_mock();
+
+    let mut source_socket = SocketMock::new(164);
+    let mut destination_socket = SocketMock::new(164);
+    let source = create_mocked_client(&mut source_network, &mut source_socket, &clock, Resp2 {});
+    let destination = create_mocked_client(
+        &mut destination_network,
+        &mut destination_socket,
+        &clock,
+        Resp2 {},
+    );
+
+    assert_eq!(0, source.migrate_all(&destination, "key:*").unwrap());
+}
+
+#[test]
+fn test_shorthand_rename() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$6\r\nRENAME\r\n$3\r\nold\r\n$3\r\nnew\r\n")
+        .response_ok()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.rename("old", "new").unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_shorthand_rename_missing_source() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$6\r\nRENAME\r\n$3\r\nold\r\n$3\r\nnew\r\n")
+        .response("-ERR no such key\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let result = client.rename("old", "new").unwrap().wait().unwrap_err();
+    assert_eq!(ErrorResponse("ERR no such key".to_string()), result);
+}
+
+#[test]
+fn test_shorthand_renamenx() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$8\r\nRENAMENX\r\n$3\r\nold\r\n$3\r\nnew\r\n")
+        .response(":0\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(!client.renamenx("old", "new").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_rename_with_ttl_sets_new_ttl() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*6\r\n$4\r\nEVAL\r\n$143\r\nif redis.call('EXISTS', KEYS[1]) == 0 then return 0 end \
+             redis.call('RENAME', KEYS[1], KEYS[2]) redis.call('PEXPIRE', KEYS[2], ARGV[1]) return 1\r\n\
+             $1\r\n2\r\n$3\r\nold\r\n$3\r\nnew\r\n$4\r\n5000\r\n",
+        )
+        .response(":1\r\n")
+        .send(164, "*2\r\n$4\r\nPTTL\r\n$3\r\nnew\r\n")
+        .response(":5000\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.rename_with_ttl("old", "new", 5000).unwrap());
+    assert_eq!(
+        TtlResponse::Duration(5000),
+        client.pttl("new").unwrap().wait().unwrap()
+    );
+}
+
+#[test]
+fn test_rename_with_ttl_missing_source() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().send(164, "").response(":0\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(!client.rename_with_ttl("old", "new", 5000).unwrap());
+}
+
+#[test]
+fn test_resync_to_ping_discards_garbage_frames() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send_ping(164)
+        .response("+OK\r\n:5\r\n$3\r\nfoo\r\n")
+        .response_no_data()
+        .response_echo("$1\r\nx\r\n")
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_string("value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.resync_to_ping().unwrap();
+
+    let response = client.get("key").unwrap().wait().unwrap();
+    assert_eq!("value", response.unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_resync_to_ping_invalidates_pending_futures() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .send_ping(164)
+        .response_echo("$5\r\nvalue\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let future = client.get("key").unwrap();
+    client.resync_to_ping().unwrap();
+
+    assert_eq!(CommandErrors::InvalidFuture, future.wait().unwrap_err());
+}
+
+#[test]
+fn test_resync_to_ping_drops_malformed_data() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send_ping(164)
+        .response("_garbage\r\n")
+        .response_echo("")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.resync_to_ping().unwrap();
+}
+
+#[test]
+fn test_resync_to_ping_echo_in_garbage_chunk() {
+    let clock = TestClock::new(vec![]);
+
+    // Garbage and echo are received by a single chunk
+    let mut network = NetworkMockBuilder::default()
+        .send_ping(164)
+        .response_echo("_x\r\n?!")
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_string("value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.resync_to_ping().unwrap();
+
+    let response = client.get("key").unwrap().wait().unwrap();
+    assert_eq!("value", response.unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_resync_to_ping_timeout() {
+    let clock = TestClock::new(vec![
+        100, // Timer creation
+        200, // First expiration check
+        300, // Second expiration check
+    ]);
+
+    let mut network = NetworkMockBuilder::default().send_ping(164).response("+OK\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = Client {
+        network: Network::new(
+            RefCell::new(&mut network),
+            RefCell::new(&mut socket),
+            Resp2 {},
+            MemoryParameters::default(),
+        ),
+        timeout_duration: 150.microseconds(),
+        clock: Some(&clock),
+        hello_response: None,
+        assumed_version: None,
+    };
+
+    assert_eq!(CommandErrors::Timeout, client.resync_to_ping().unwrap_err());
+}
+
+#[test]
+fn test_call_incr() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$4\r\nINCR\r\n$1\r\nk\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(RedisValue::Int(1), client.call("INCR", &["k".into()]).unwrap());
+}
+
+#[test]
+fn test_call_error_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*1\r\n$7\r\nUNKNOWN\r\n")
+        .response_error()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {});
+
+    assert_eq!(
+        ErrorResponse("Error".to_string()),
+        client.call("UNKNOWN", &[]).unwrap_err()
+    );
+}
+
+#[test]
+fn test_send_bytes_get() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_string("value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let raw = Bytes::from_static(b"*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n");
+    let frame = client.send_bytes(raw).unwrap().wait().unwrap();
+
+    assert_eq!(Resp2Frame::BulkString(Bytes::from_static(b"value")), frame);
+}
+
+#[test]
+fn test_send_bytes_mapped_between_commands() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .send(164, "*1\r\n$4\r\nPING\r\n")
+        .send(164, "")
+        .response("$1\r\na\r\n+PONG\r\n$1\r\nb\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let first = client.get("first").unwrap();
+    let raw = client.send_bytes(Bytes::from_static(b"*1\r\n$4\r\nPING\r\n")).unwrap();
+    let second = client.get("second").unwrap();
+
+    assert_eq!("b", second.wait().unwrap().unwrap().as_str().unwrap());
+    assert_eq!(
+        Resp2Frame::SimpleString(Bytes::from_static(b"PONG")),
+        raw.wait().unwrap()
+    );
+    assert_eq!("a", first.wait().unwrap().unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_send_bytes_incomplete_frame() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().into_mock();
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let result = client.send_bytes(Bytes::from_static(b"*2\r\n$3\r\nGET\r\n"));
+    assert_eq!(CommandErrors::EncodingCommandFailed, result.err().unwrap());
+}
+
+#[test]
+fn test_send_bytes_multiple_frames() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().into_mock();
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let result = client.send_bytes(Bytes::from_static(b"*1\r\n$4\r\nPING\r\n*1\r\n$4\r\nPING\r\n"));
+    assert_eq!(CommandErrors::EncodingCommandFailed, result.err().unwrap());
+}
+
+#[test]
+fn test_profile_timings() {
+    #[cfg(not(feature = "metrics"))]
+    let clock = TestClock::new(vec![100, 150, 400, 1400]);
+
+    // Metrics read the clock additionally on sending and completion
+    #[cfg(feature = "metrics")]
+    let clock = TestClock::new(vec![100, 150, 400, 400, 1400, 1400]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_string("value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let (response, timings) = client.profile(GetCommand::static_key("key")).unwrap();
+
+    assert_eq!("value", response.unwrap().as_str().unwrap());
+    assert_eq!(50.microseconds(), timings.encode);
+    assert_eq!(250.microseconds(), timings.send);
+    assert_eq!(1000.microseconds(), timings.wait);
+}
+
+#[test]
+fn test_profile_no_clock() {
+    let mut network = NetworkMockBuilder::default().into_mock();
+    let mut socket = SocketMock::new(164);
+    let client: Client<'_, MockNetworkStack, TestClock, Resp2> = Client::from_socket(
+        &mut network,
+        &mut socket,
+        None,
+        Resp2 {},
+        MemoryParameters::default(),
+    );
+
+    let result = client.profile(GetCommand::static_key("key"));
+    assert_eq!(TimerError, result.err().unwrap());
+}
+
+#[test]
+fn test_shorthand_append() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$6\r\nAPPEND\r\n$3\r\nkey\r\n$5\r\nvalue\r\n")
+        .response(":8\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(8, client.append("key", "value").unwrap().wait().unwrap());
+}
+
+#[test]
+fn test_set_chunked_three_chunks() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$4\r\nlarg\r\n")
+        .response_ok()
+        .send(164, "*3\r\n$6\r\nAPPEND\r\n$3\r\nkey\r\n$4\r\ne va\r\n")
+        .response(":8\r\n")
+        .send(164, "*3\r\n$6\r\nAPPEND\r\n$3\r\nkey\r\n$3\r\nlue\r\n")
+        .response(":11\r\n")
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_string("large value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let mut source = &b"large value"[..];
+    let length = client
+        .set_chunked(
+            "key",
+            |buffer: &mut [u8]| {
+                let count = buffer.len().min(source.len());
+                buffer[..count].copy_from_slice(&source[..count]);
+                source = &source[count..];
+                count
+            },
+            4,
+        )
+        .unwrap();
+    assert_eq!(11, length);
+
+    let response = client.get("key").unwrap().wait().unwrap().unwrap();
+    assert_eq!("large value", response.as_str().unwrap());
+}
+
+#[test]
+fn test_set_chunked_empty_value() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$0\r\n\r\n")
+        .response_ok()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(0, client.set_chunked("key", |_: &mut [u8]| 0, 4).unwrap());
+}
+
+#[test]
+fn test_set_chunked_zero_chunk_size() {
+    let clock = TestClock::new(vec![]);
+    let mut network = NetworkMockBuilder::default().into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(
+        CommandErrors::EncodingCommandFailed,
+        client.set_chunked("key", |_: &mut [u8]| 0, 0).unwrap_err()
+    );
+}
+
+#[test]
+fn test_send_with_policy_retry_on_timeout() {
+    #[cfg(not(feature = "metrics"))]
+    let clock = TestClock::new(vec![
+        100, // Timer creation
+        200, // First receive() call
+        300, // Second receive() call
+        350, // Resync timer creation
+        360, // Resync receive() call
+        400, // Timer creation
+        500, // First receive() call
+    ]);
+
+    // Metrics read the clock additionally on completion of each attempt
+    #[cfg(feature = "metrics")]
+    let clock = TestClock::new(vec![100, 200, 300, 300, 350, 360, 400, 500, 500]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_no_data()
+        .response_no_data()
+        .response_no_data()
+        .send_ping(164)
+        .response_echo("")
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_string("value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = Client {
+        network: Network::new(
+            RefCell::new(&mut network),
+            RefCell::new(&mut socket),
+            Resp2 {},
+            MemoryParameters::default(),
+        ),
+        timeout_duration: 150.microseconds(),
+        clock: Some(&clock),
+        hello_response: None,
+        assumed_version: None,
+    };
+
+    let policy = RetryPolicy {
+        attempts: 3,
+        on: &[Timeout, TcpError],
+    };
+    let response = client.send_with_policy(GetCommand::static_key("key"), policy).unwrap();
+    assert_eq!("value", response.unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_send_with_policy_retry_discards_late_reply() {
+    #[cfg(not(feature = "metrics"))]
+    let clock = TestClock::new(vec![
+        100, // Timer creation
+        200, // First receive() call
+        300, // Second receive() call
+        350, // Resync timer creation
+        360, // Resync receive() call
+        400, // Timer creation
+        500, // First receive() call
+    ]);
+
+    // Metrics read the clock additionally on completion of each attempt
+    #[cfg(feature = "metrics")]
+    let clock = TestClock::new(vec![100, 200, 300, 300, 350, 360, 400, 500, 500]);
+
+    // Reply of the first attempt arrives after the timeout, right before the resync echo
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_no_data()
+        .response_no_data()
+        .response_no_data()
+        .send_ping(164)
+        .response_echo("$5\r\nstale\r\n")
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_string("value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = Client {
+        network: Network::new(
+            RefCell::new(&mut network),
+            RefCell::new(&mut socket),
+            Resp2 {},
+            MemoryParameters::default(),
+        ),
+        timeout_duration: 150.microseconds(),
+        clock: Some(&clock),
+        hello_response: None,
+        assumed_version: None,
+    };
+
+    let policy = RetryPolicy {
+        attempts: 2,
+        on: &[Timeout],
+    };
+    let response = client.send_with_policy(GetCommand::static_key("key"), policy).unwrap();
+    assert_eq!("value", response.unwrap().as_str().unwrap());
+}
+
+#[test]
+fn test_send_with_policy_resync_failure() {
+    #[cfg(not(feature = "metrics"))]
+    let clock = TestClock::new(vec![
+        100, // Timer creation
+        200, // First receive() call
+        300, // Second receive() call
+        350, // Resync timer creation
+        400, // Resync receive() call
+        600, // Resync receive() call
+    ]);
+
+    // Metrics read the clock additionally on completion of the first attempt
+    #[cfg(feature = "metrics")]
+    let clock = TestClock::new(vec![100, 200, 300, 300, 350, 400, 600]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_no_data()
+        .response_no_data()
+        .send_ping(164)
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = Client {
+        network: Network::new(
+            RefCell::new(&mut network),
+            RefCell::new(&mut socket),
+            Resp2 {},
+            MemoryParameters::default(),
+        ),
+        timeout_duration: 150.microseconds(),
+        clock: Some(&clock),
+        hello_response: None,
+        assumed_version: None,
+    };
+
+    let policy = RetryPolicy {
+        attempts: 3,
+        on: &[Timeout],
+    };
+    let error = client.send_with_policy(GetCommand::static_key("key"), policy).unwrap_err();
+    assert_eq!(Timeout, error);
+}
+
+#[test]
+fn test_send_with_policy_attempts_exhausted() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_error()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_error()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let policy = RetryPolicy {
+        attempts: 2,
+        on: &[ErrorResponse(String::new())],
+    };
+    let error = client.send_with_policy(GetCommand::static_key("key"), policy).unwrap_err();
+    assert_eq!(ErrorResponse("Error".to_string()), error);
+}
+
+#[test]
+fn test_send_with_policy_not_matching_error() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_error()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let policy = RetryPolicy {
+        attempts: 3,
+        on: &[Timeout, TcpError],
+    };
+    let error = client.send_with_policy(GetCommand::static_key("key"), policy).unwrap_err();
+    assert_eq!(ErrorResponse("Error".to_string()), error);
+}
+
+#[test]
+fn test_command_errors_display() {
+    assert_eq!("no response within the expected time frame", Timeout.to_string());
+    assert_eq!("network error", TcpError.to_string());
+    assert_eq!(
+        "Redis error response: ERR unknown command",
+        ErrorResponse("ERR unknown command".to_string()).to_string()
     );
 }
 
 #[test]
-fn test_shorthand_hgetall_bytes_argument() {
+fn test_counter_array_incr_several_indices() {
     let clock = TestClock::new(vec![]);
 
     let mut network = NetworkMockBuilder::default()
-        .send(164, "*2\r\n$7\r\nHGETALL\r\n$7\r\nmy_hash\r\n")
-        .response("*2\r\n$5\r\ncolor\r\n$5\r\ngreen\r\n")
+        .send(
+            164,
+            "*8\r\n$8\r\nBITFIELD\r\n$8\r\ncounters\r\n$8\r\nOVERFLOW\r\n$4\r\nWRAP\r\n$6\r\nINCRBY\r\n$2\r\nu4\r\n$2\r\n#0\r\n$1\r\n1\r\n",
+        )
+        .response("*1\r\n:1\r\n")
+        .send(
+            164,
+            "*8\r\n$8\r\nBITFIELD\r\n$8\r\ncounters\r\n$8\r\nOVERFLOW\r\n$4\r\nWRAP\r\n$6\r\nINCRBY\r\n$2\r\nu4\r\n$2\r\n#5\r\n$1\r\n1\r\n",
+        )
+        .response("*1\r\n:1\r\n")
+        .send(
+            164,
+            "*8\r\n$8\r\nBITFIELD\r\n$8\r\ncounters\r\n$8\r\nOVERFLOW\r\n$4\r\nWRAP\r\n$6\r\nINCRBY\r\n$2\r\nu4\r\n$2\r\n#5\r\n$1\r\n1\r\n",
+        )
+        .response("*1\r\n:2\r\n")
+        .send(164, "*5\r\n$8\r\nBITFIELD\r\n$8\r\ncounters\r\n$3\r\nGET\r\n$2\r\nu4\r\n$2\r\n#0\r\n")
+        .response("*1\r\n:1\r\n")
+        .send(164, "*5\r\n$8\r\nBITFIELD\r\n$8\r\ncounters\r\n$3\r\nGET\r\n$2\r\nu4\r\n$2\r\n#5\r\n")
+        .response("*1\r\n:2\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let counters = CounterArray::new("counters", 4);
+    assert_eq!(Some(1), counters.incr(&client, 0).unwrap());
+    assert_eq!(Some(1), counters.incr(&client, 5).unwrap());
+    assert_eq!(Some(2), counters.incr(&client, 5).unwrap());
+
+    assert_eq!(1, counters.get(&client, 0).unwrap());
+    assert_eq!(2, counters.get(&client, 5).unwrap());
+}
+
+#[test]
+fn test_counter_array_set_saturating() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*8\r\n$8\r\nBITFIELD\r\n$8\r\ncounters\r\n$8\r\nOVERFLOW\r\n$3\r\nSAT\r\n$3\r\nSET\r\n$2\r\nu8\r\n$2\r\n#2\r\n$3\r\n255\r\n",
+        )
+        .response("*1\r\n:7\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let counters = CounterArray::new("counters", 8).overflow(BitfieldOverflow::Sat);
+    assert_eq!(Some(7), counters.set(&client, 2, 255).unwrap());
+}
+
+#[test]
+fn test_counter_array_incr_overflow_fail() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*8\r\n$8\r\nBITFIELD\r\n$8\r\ncounters\r\n$8\r\nOVERFLOW\r\n$4\r\nFAIL\r\n$6\r\nINCRBY\r\n$2\r\nu2\r\n$2\r\n#1\r\n$1\r\n1\r\n",
+        )
+        .response("*1\r\n$-1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let counters = CounterArray::new("counters", 2).overflow(BitfieldOverflow::Fail);
+    assert_eq!(None, counters.incr(&client, 1).unwrap());
+}
+
+#[test]
+fn test_counter_array_get_invalid_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().send(164, "").response("*0\r\n").into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let counters = CounterArray::new("counters", 2);
+    assert_eq!(CommandResponseViolation, counters.get(&client, 1).unwrap_err());
+}
+
+#[test]
+fn test_error_response_permission_denied() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .response("-NOPERM User test has no perm")
+        .response("issions to run the 'get' ")
+        .response("command\r\n")
         .into_mock();
 
     let mut socket = SocketMock::new(164);
     let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
 
+    let error = client.get("key").unwrap().wait().unwrap_err();
+    assert_eq!(Some("NOPERM"), error.error_code());
+    assert!(error.is_permission_denied());
+}
+
+#[test]
+fn test_error_code() {
     assert_eq!(
-        "green",
-        client
-            .hgetall(Bytes::from_static(b"my_hash"))
-            .unwrap()
-            .wait()
-            .unwrap()
-            .unwrap()
-            .get_str("color")
-            .unwrap()
+        Some("WRONGTYPE"),
+        ErrorResponse("WRONGTYPE Operation against a key holding the wrong kind of value".to_string())
+            .error_code()
+    );
+    assert!(!ErrorResponse("WRONGTYPE Operation".to_string()).is_permission_denied());
+    assert_eq!(None, ErrorResponse("Error".to_string()).error_code());
+    assert_eq!(None, ErrorResponse(String::new()).error_code());
+    assert_eq!(None, Timeout.error_code());
+    assert!(!Timeout.is_permission_denied());
+}
+
+#[test]
+fn test_logger_log() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*10\r\n$4\r\nXADD\r\n$4\r\nlogs\r\n$6\r\nMAXLEN\r\n$1\r\n~\r\n$3\r\n100\r\n$1\r\n*\r\n$5\r\nlevel\r\n$4\r\nWARN\r\n$3\r\nmsg\r\n$11\r\nbattery low\r\n",
+        )
+        .response("$3\r\n1-0\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let logger = Logger::new(&client, "logs", 100);
+    assert_eq!(
+        "1-0",
+        logger.log(LogLevel::Warn, [("msg", "battery low")]).unwrap()
+    );
+}
+
+#[test]
+fn test_sorted_index_add_remove() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$4\r\nZADD\r\n$5\r\nindex\r\n$1\r\n0\r\n$6\r\ntemp:1\r\n",
+        )
+        .response(":1\r\n")
+        .send(
+            164,
+            "*4\r\n$4\r\nZADD\r\n$5\r\nindex\r\n$1\r\n0\r\n$6\r\ntemp:1\r\n",
+        )
+        .response(":0\r\n")
+        .send(164, "*3\r\n$4\r\nZREM\r\n$5\r\nindex\r\n$6\r\ntemp:1\r\n")
+        .response(":1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let index = SortedStringIndex::new("index");
+    assert!(index.add(&client, "temp:1").unwrap());
+    assert!(!index.add(&client, "temp:1").unwrap());
+    assert!(index.remove(&client, "temp:1").unwrap());
+}
+
+#[test]
+fn test_sorted_index_range_prefix() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send_bytes(
+            164,
+            b"*4\r\n$11\r\nZRANGEBYLEX\r\n$5\r\nindex\r\n$6\r\n[temp:\r\n$7\r\n[temp:\xff\r\n",
+        )
+        .response("*2\r\n$6\r\ntemp:1\r\n$6\r\ntemp:2\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let index = SortedStringIndex::new("index");
+    assert_eq!(
+        vec!["temp:1", "temp:2"],
+        index.range_prefix(&client, "temp:").unwrap()
     );
 }
+
+#[test]
+fn test_sorted_index_range_empty_prefix() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*4\r\n$11\r\nZRANGEBYLEX\r\n$5\r\nindex\r\n$1\r\n-\r\n$1\r\n+\r\n",
+        )
+        .response("*1\r\n$6\r\ntemp:1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {});
+
+    let index = SortedStringIndex::new("index");
+    assert_eq!(vec!["temp:1"], index.range_prefix(&client, "").unwrap());
+}
+
+#[test]
+fn test_del_many_pipelined_two_batches() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$3\r\nDEL\r\n$4\r\nkey1\r\n$4\r\nkey2\r\n")
+        .send(164, "*2\r\n$3\r\nDEL\r\n$4\r\nkey3\r\n")
+        .response(":2\r\n:1\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = Client {
+        network: Network::new(
+            RefCell::new(&mut network),
+            RefCell::new(&mut socket),
+            Resp2 {},
+            MemoryParameters::default(),
+        )
+        .with_max_command_size(40),
+        timeout_duration: 0.microseconds(),
+        clock: Some(&clock),
+        hello_response: None,
+        assumed_version: None,
+    };
+
+    let keys = [
+        Bytes::from_static(b"key1"),
+        Bytes::from_static(b"key2"),
+        Bytes::from_static(b"key3"),
+    ];
+    assert_eq!(3, client.del_many_pipelined(&keys).unwrap());
+}
+
+#[test]
+fn test_del_many_pipelined_key_exceeding_limit() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nDEL\r\n$4\r\nkey1\r\n")
+        .send(164, "*2\r\n$3\r\nDEL\r\n$4\r\nkey2\r\n")
+        .response(":1\r\n:0\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = Client {
+        network: Network::new(
+            RefCell::new(&mut network),
+            RefCell::new(&mut socket),
+            Resp3 {},
+            MemoryParameters::default(),
+        )
+        .with_max_command_size(10),
+        timeout_duration: 0.microseconds(),
+        clock: Some(&clock),
+        hello_response: None,
+        assumed_version: None,
+    };
+
+    let keys = [Bytes::from_static(b"key1"), Bytes::from_static(b"key2")];
+    assert_eq!(1, client.del_many_pipelined(&keys).unwrap());
+}
+
+#[test]
+fn test_del_many_pipelined_empty() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default().into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert_eq!(0, client.del_many_pipelined(&[]).unwrap());
+}
+
+#[test]
+fn test_del_many_pipelined_error_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nDEL\r\n$4\r\nkey1\r\n")
+        .response_error()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let result = client.del_many_pipelined(&[Bytes::from_static(b"key1")]);
+    assert_eq!(ErrorResponse("Error".to_string()), result.unwrap_err());
+}
@@ -0,0 +1,129 @@
+use crate::network::debug::frame_to_json;
+use alloc::vec;
+use bytes::Bytes;
+use redis_protocol::resp2::types::BytesFrame as Resp2Frame;
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, FrameMap, RespVersion};
+
+#[test]
+fn test_resp2_scalars() {
+    assert_eq!("\"OK\"", frame_to_json(&Resp2Frame::SimpleString("OK".into())));
+    assert_eq!(
+        "\"value\"",
+        frame_to_json(&Resp2Frame::BulkString("value".into()))
+    );
+    assert_eq!("-5", frame_to_json(&Resp2Frame::Integer(-5)));
+    assert_eq!("null", frame_to_json(&Resp2Frame::Null));
+    assert_eq!(
+        "{\"error\":\"ERR unknown\"}",
+        frame_to_json(&Resp2Frame::Error("ERR unknown".into()))
+    );
+}
+
+#[test]
+fn test_resp2_nested_array() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString("a".into()),
+        Resp2Frame::Array(vec![Resp2Frame::Integer(1), Resp2Frame::Null]),
+        Resp2Frame::Array(vec![]),
+    ]);
+
+    assert_eq!("[\"a\",[1,null],[]]", frame_to_json(&frame));
+}
+
+#[test]
+fn test_binary_string_as_hex() {
+    let frame = Resp2Frame::BulkString(Bytes::from_static(b"\xc3\x28\x00"));
+
+    assert_eq!("{\"hex\":\"c32800\"}", frame_to_json(&frame));
+}
+
+#[test]
+fn test_string_escaping() {
+    let frame = Resp2Frame::BulkString("say \"hi\"\\\r\n\x01".into());
+
+    assert_eq!("\"say \\\"hi\\\"\\\\\\r\\n\\u0001\"", frame_to_json(&frame));
+}
+
+#[test]
+fn test_resp3_scalars() {
+    assert_eq!(
+        "true",
+        frame_to_json(&Resp3Frame::Boolean {
+            data: true,
+            attributes: None
+        })
+    );
+    assert_eq!(
+        "1.5",
+        frame_to_json(&Resp3Frame::Double {
+            data: 1.5,
+            attributes: None
+        })
+    );
+    assert_eq!(
+        "\"inf\"",
+        frame_to_json(&Resp3Frame::Double {
+            data: f64::INFINITY,
+            attributes: None
+        })
+    );
+    assert_eq!(
+        "{\"error\":\"WRONGTYPE\"}",
+        frame_to_json(&Resp3Frame::BlobError {
+            data: "WRONGTYPE".into(),
+            attributes: None
+        })
+    );
+    assert_eq!(
+        "{\"hello\":3}",
+        frame_to_json(&Resp3Frame::Hello {
+            version: RespVersion::RESP3,
+            auth: None,
+            setname: None
+        })
+    );
+}
+
+#[test]
+fn test_resp3_map_nested() {
+    let frame = Resp3Frame::Map {
+        data: FrameMap::from([(
+            Resp3Frame::BlobString {
+                data: "sensor".into(),
+                attributes: None,
+            },
+            Resp3Frame::Array {
+                data: vec![
+                    Resp3Frame::Number {
+                        data: 21,
+                        attributes: None,
+                    },
+                    Resp3Frame::Null,
+                ],
+                attributes: None,
+            },
+        )]),
+        attributes: None,
+    };
+
+    assert_eq!("{\"sensor\":[21,null]}", frame_to_json(&frame));
+}
+
+#[test]
+fn test_resp3_map_non_string_key() {
+    let frame = Resp3Frame::Map {
+        data: FrameMap::from([(
+            Resp3Frame::Number {
+                data: 7,
+                attributes: None,
+            },
+            Resp3Frame::SimpleString {
+                data: "seven".into(),
+                attributes: None,
+            },
+        )]),
+        attributes: None,
+    };
+
+    assert_eq!("{\"7\":\"seven\"}", frame_to_json(&frame));
+}
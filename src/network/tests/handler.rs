@@ -1,14 +1,20 @@
+use crate::commands::eval::EvalCommand;
 use crate::network::client::CommandErrors;
 use crate::network::handler::ConnectionError::{
     AuthenticationError, ProtocolSwitchError, TcpConnectionFailed, TcpSocketError,
 };
-use crate::network::handler::{ConnectionHandler, Credentials};
+use crate::network::handler::{ConnectionHandler, Credentials, DetectedProtocol};
+use crate::network::response::MemoryParameters;
 use crate::network::tests::mocks::{NetworkMockBuilder, TestClock};
+use crate::network::version::ServerVersion;
 use alloc::string::ToString;
 use alloc::vec;
+use core::error::Error;
 use core::net::SocketAddr;
 use core::str::FromStr;
 use embedded_time::duration::Extensions;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 #[test]
 fn test_connect_new_socket_fails() {
@@ -103,6 +109,96 @@ fn test_resp3_connect_hello_failed() {
     );
 }
 
+#[test]
+fn test_detect_protocol_resp3() {
+    let clock = TestClock::new(vec![]);
+
+    // Connection is reused after detection
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send_hello(167)
+        .response_hello()
+        .into_mock();
+
+    let mut handler = ConnectionHandler::auto(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+
+    assert_eq!(
+        DetectedProtocol::Resp3,
+        handler.detect_protocol(&mut stack, Some(&clock)).unwrap()
+    );
+    assert!(handler.hello_response().is_some());
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+}
+
+#[test]
+fn test_detect_protocol_resp2() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send_hello(167)
+        .response_error()
+        .close(167)
+        .into_mock();
+
+    let mut handler = ConnectionHandler::auto(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+
+    assert_eq!(
+        DetectedProtocol::Resp2,
+        handler.detect_protocol(&mut stack, Some(&clock)).unwrap()
+    );
+}
+
+#[test]
+fn test_detect_protocol_auth_error() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "")
+        .response_error()
+        .into_mock();
+
+    let mut handler = ConnectionHandler::auto(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.auth(Credentials::password_only("secret"));
+
+    assert_eq!(
+        AuthenticationError(CommandErrors::ErrorResponse("Error".to_string())),
+        handler.detect_protocol(&mut stack, Some(&clock)).unwrap_err()
+    );
+}
+
+#[test]
+fn test_resp3_connect_hello_exceeds_memory_limit() {
+    let clock = TestClock::new(vec![]);
+
+    // Receiving stops once the third chunk exceeds the limit
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send_hello(167)
+        .response_hello_modules(20, 3)
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.memory(MemoryParameters {
+        buffer_size: 32,
+        frame_capacity: 8,
+        memory_limit: Some(64),
+    });
+    let error = handler.connect(&mut stack, Some(&clock)).unwrap_err();
+
+    assert_eq!(ProtocolSwitchError(CommandErrors::MemoryFull), error);
+    assert_eq!(
+        "protocol switch (HELLO) failed: response exceeds the memory limit, \
+         consider raising MemoryParameters::memory_limit",
+        error.to_string()
+    );
+}
+
 #[test]
 fn test_resp3_connect_hello_response() {
     let clock = TestClock::new(vec![]);
@@ -129,6 +225,40 @@ fn test_resp3_connect_hello_response() {
     assert!(result.get_hello_response().modules.is_empty());
 }
 
+#[test]
+fn test_resp3_handler_hello_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send_hello(167)
+        .response_hello()
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    assert!(handler.hello_response().is_none());
+
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+
+    let response = handler.hello_response().unwrap();
+    assert_eq!("redis", response.server);
+    assert_eq!("6.0.0", response.version);
+    assert_eq!(3, response.protocol);
+}
+
+#[test]
+fn test_resp2_handler_hello_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default().socket(167).connect(167).into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+
+    assert!(handler.hello_response().is_none());
+}
+
 #[test]
 fn test_resp2_connect_auth_failed_socket_closed() {
     let clock = TestClock::new(vec![]);
@@ -304,3 +434,673 @@ fn test_connect_socket_ping_successful() {
     handler.connect(&mut stack, Some(&clock)).unwrap();
     handler.connect(&mut stack, Some(&clock)).unwrap();
 }
+
+#[test]
+fn test_on_wait_invoked_on_pending_data() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*1\r\n$4\r\nPING\r\n")
+        .response_no_data()
+        .response_no_data()
+        .response_string("PONG")
+        .into_mock();
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let callback_counter = counter.clone();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.on_wait(move || {
+        callback_counter.fetch_add(1, Ordering::Relaxed);
+    });
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    client.ping().unwrap().wait().unwrap();
+
+    assert_eq!(2, counter.load(Ordering::Relaxed));
+}
+
+#[test]
+fn test_on_wait_not_invoked_on_immediate_data() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*1\r\n$4\r\nPING\r\n")
+        .response_string("PONG")
+        .into_mock();
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let callback_counter = counter.clone();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.on_wait(move || {
+        callback_counter.fetch_add(1, Ordering::Relaxed);
+    });
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    client.ping().unwrap().wait().unwrap();
+
+    assert_eq!(0, counter.load(Ordering::Relaxed));
+}
+
+#[test]
+fn test_on_timeout_invoked_once() {
+    let clock = TestClock::new(vec![
+        100, // Timer creation
+        200, // First receive() call
+        300, // Second receive() call
+    ]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*1\r\n$4\r\nPING\r\n")
+        .response_no_data()
+        .response_no_data()
+        .into_mock();
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let callback_counter = counter.clone();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.timeout(150.microseconds());
+    handler.on_timeout(move |info| {
+        assert_eq!(0, info.index);
+        callback_counter.fetch_add(1, Ordering::Relaxed);
+    });
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    let result = client.ping().unwrap().wait();
+
+    assert_eq!(CommandErrors::Timeout, result.unwrap_err());
+    assert_eq!(1, counter.load(Ordering::Relaxed));
+}
+
+#[test]
+fn test_on_timeout_invoked_by_resync() {
+    let clock = TestClock::new(vec![
+        100, // Timer creation
+        200, // First expiration check
+        300, // Second expiration check
+    ]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send_ping(167)
+        .response_no_data()
+        .into_mock();
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let callback_counter = counter.clone();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.timeout(150.microseconds());
+    handler.on_timeout(move |info| {
+        assert_eq!(0, info.index);
+        callback_counter.fetch_add(1, Ordering::Relaxed);
+    });
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+
+    assert_eq!(CommandErrors::Timeout, client.resync_to_ping().unwrap_err());
+    assert_eq!(1, counter.load(Ordering::Relaxed));
+}
+
+#[test]
+fn test_resp2_rejects_resp3_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*1\r\n$4\r\nPING\r\n")
+        .response("%1\r\n+a\r\n+b\r\n")
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    let result = client.ping().unwrap().wait();
+
+    assert_eq!(CommandErrors::ProtocolViolation, result.unwrap_err());
+}
+
+#[test]
+fn test_inline_responses_enabled() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*1\r\n$4\r\nPING\r\n")
+        .response("PONG\r\n")
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.inline_responses();
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    client.ping().unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_inline_responses_disabled_protocol_violation() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*1\r\n$4\r\nPING\r\n")
+        .response("PONG\r\n")
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    assert_eq!(
+        CommandErrors::ProtocolViolation,
+        client.ping().unwrap().wait().unwrap_err()
+    );
+}
+
+#[test]
+fn test_validate_keys_rejects_crlf() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default().socket(167).connect(167).into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.validate_keys();
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    assert_eq!(
+        CommandErrors::InvalidKey,
+        client.set("first\r\nsecond", "value").err().unwrap()
+    );
+    assert_eq!(
+        CommandErrors::InvalidKey,
+        client.del(["valid".into(), "line\n".into()]).err().unwrap()
+    );
+}
+
+#[test]
+fn test_validate_keys_rejects_crlf_second_key() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default().socket(167).connect(167).into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.validate_keys();
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    assert_eq!(
+        CommandErrors::InvalidKey,
+        client
+            .mset([
+                ("first".into(), "value".into()),
+                ("second\r\n".into(), "value".into())
+            ])
+            .err()
+            .unwrap()
+    );
+    assert_eq!(
+        CommandErrors::InvalidKey,
+        client.rename("source", "target\n").err().unwrap()
+    );
+}
+
+#[test]
+fn test_validate_keys_mset_accepts_values_with_crlf() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(
+            167,
+            "*5\r\n$4\r\nMSET\r\n$5\r\nfirst\r\n$3\r\na\r\n\r\n$6\r\nsecond\r\n$3\r\nb\r\n\r\n",
+        )
+        .response("+OK\r\n")
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.validate_keys();
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    client
+        .mset([
+            ("first".into(), "a\r\n".into()),
+            ("second".into(), "b\r\n".into()),
+        ])
+        .unwrap()
+        .wait()
+        .unwrap();
+}
+
+#[test]
+fn test_validate_keys_rejects_crlf_eval_key() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default().socket(167).connect(167).into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.validate_keys();
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    let command = EvalCommand::new("return 1").key("first").key("second\r\n").arg("value");
+    assert_eq!(
+        CommandErrors::InvalidKey,
+        client.send(command.to_command()).err().unwrap()
+    );
+}
+
+#[test]
+fn test_validate_keys_eval_accepts_script_and_args_with_crlf() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(
+            167,
+            "*5\r\n$4\r\nEVAL\r\n$21\r\nlocal a = 1\r\nreturn a\r\n$1\r\n1\r\n$3\r\nkey\r\n$3\r\na\r\n\r\n",
+        )
+        .response("+OK\r\n")
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.validate_keys();
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    let command = EvalCommand::new("local a = 1\r\nreturn a").key("key").arg("a\r\n");
+    client.send(command.to_command()).unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_validate_keys_rejects_crlf_stream_keys() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default().socket(167).connect(167).into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.validate_keys();
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    assert_eq!(
+        CommandErrors::InvalidKey,
+        client.xgroup_create("stream\n", "group", "$").err().unwrap()
+    );
+    assert_eq!(
+        CommandErrors::InvalidKey,
+        client.xreadgroup("group", "consumer", "stream\r\n", 10).err().unwrap()
+    );
+}
+
+#[test]
+fn test_validate_keys_accepts_values_with_crlf() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*3\r\n$3\r\nSET\r\n$3\r\nlog\r\n$6\r\na\r\nb\r\n\r\n")
+        .response("+OK\r\n")
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.validate_keys();
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    client.set("log", "a\r\nb\r\n").unwrap().wait().unwrap();
+}
+
+#[test]
+fn test_validate_keys_disabled() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*2\r\n$3\r\nGET\r\n$3\r\na\r\n\r\n")
+        .response("$-1\r\n")
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    assert!(client.get("a\r\n").unwrap().wait().unwrap().is_none());
+}
+
+#[test]
+fn test_assume_version_resp2_uses_fallback() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*3\r\n$6\r\nGETSET\r\n$3\r\nkey\r\n$3\r\nnew\r\n")
+        .response_string("old")
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.assume_version(6, 0, 16);
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    assert_eq!(Some(ServerVersion::new(6, 0, 16)), client.server_version());
+    assert_eq!("old", client.swap_value("key", "new").unwrap().unwrap());
+}
+
+#[test]
+fn test_assume_version_get_or_set_uses_script() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(
+            167,
+            "*5\r\n$4\r\nEVAL\r\n$196\r\nlocal value = redis.call('GET', KEYS[1]) if value then return value end \
+             if ARGV[2] then redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2]) \
+             else redis.call('SET', KEYS[1], ARGV[1]) end return false\r\n$1\r\n1\r\n$3\r\nkey\r\n$3\r\ndef\r\n",
+        )
+        .response_string("old")
+        .send(
+            167,
+            "*6\r\n$4\r\nEVAL\r\n$196\r\nlocal value = redis.call('GET', KEYS[1]) if value then return value end \
+             if ARGV[2] then redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2]) \
+             else redis.call('SET', KEYS[1], ARGV[1]) end return false\r\n\
+             $1\r\n1\r\n$3\r\nkey\r\n$3\r\ndef\r\n$3\r\n100\r\n",
+        )
+        .response_null_resp2()
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.assume_version(6, 2, 0);
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    assert_eq!(
+        ("old".into(), false),
+        client.get_or_set("key", "def", None).unwrap()
+    );
+    assert_eq!(
+        ("def".into(), true),
+        client.get_or_set("key", "def", Some(100)).unwrap()
+    );
+}
+
+#[test]
+fn test_assume_version_resp2_newer_version() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*4\r\n$3\r\nSET\r\n$3\r\nkey\r\n$3\r\nnew\r\n$3\r\nGET\r\n")
+        .response_string("old")
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.assume_version(7, 2, 0);
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    assert_eq!("old", client.swap_value("key", "new").unwrap().unwrap());
+}
+
+#[test]
+fn test_server_version_unknown() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default().socket(167).connect(167).into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+
+    assert!(client.server_version().is_none());
+}
+
+#[test]
+fn test_assume_version_overridden_by_hello() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send_hello(167)
+        .response_hello()
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.assume_version(7, 2, 0);
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    assert_eq!(Some(ServerVersion::new(6, 0, 0)), client.server_version());
+}
+
+#[test]
+fn test_refresh_hello_replaces_cached_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send_hello(167)
+        .response_hello()
+        .send_hello(167)
+        .response_hello_version("7.2.4")
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+    assert_eq!("6.0.0", handler.hello_response().unwrap().version);
+
+    handler.refresh_hello(&mut stack, Some(&clock)).unwrap();
+    assert_eq!("7.2.4", handler.hello_response().unwrap().version);
+
+    let client = handler.connect(&mut stack, Some(&clock)).unwrap();
+    assert_eq!("7.2.4", client.get_hello_response().version);
+}
+
+#[test]
+fn test_refresh_hello_error_keeps_cached_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send_hello(167)
+        .response_hello()
+        .send_hello(167)
+        .response_error()
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+
+    let error = handler.refresh_hello(&mut stack, Some(&clock)).unwrap_err();
+    assert_eq!(
+        ProtocolSwitchError(CommandErrors::ErrorResponse("Error".to_string())),
+        error
+    );
+    assert_eq!("6.0.0", handler.hello_response().unwrap().version);
+}
+
+#[test]
+fn test_refresh_hello_not_connected() {
+    let clock = TestClock::new(vec![]);
+    let mut stack = NetworkMockBuilder::default().into_mock();
+
+    let mut handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    assert_eq!(
+        TcpConnectionFailed,
+        handler.refresh_hello(&mut stack, Some(&clock)).unwrap_err()
+    );
+}
+
+#[test]
+fn test_refresh_hello_resp2_no_effect() {
+    let clock = TestClock::new(vec![]);
+    let mut stack = NetworkMockBuilder::default().socket(167).connect(167).into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+
+    handler.refresh_hello(&mut stack, Some(&clock)).unwrap();
+    assert!(handler.hello_response().is_none());
+}
+
+#[test]
+fn test_reauth_sends_auth_and_stores_credentials() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*2\r\n$4\r\nAUTH\r\n$6\r\nsecret\r\n")
+        .response_ok()
+        .send(167, "*2\r\n$4\r\nAUTH\r\n$7\r\nrotated\r\n")
+        .response_ok()
+        .close(167)
+        .socket(168)
+        .connect(168)
+        .send(168, "*2\r\n$4\r\nAUTH\r\n$7\r\nrotated\r\n")
+        .response_ok()
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.auth(Credentials::password_only("secret"));
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+
+    handler
+        .reauth(&mut stack, Some(&clock), Credentials::password_only("rotated"))
+        .unwrap();
+
+    // Reconnect uses the new credentials
+    handler.disconnect(&mut stack);
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+}
+
+#[test]
+fn test_reauth_error_keeps_credentials() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*2\r\n$4\r\nAUTH\r\n$6\r\nsecret\r\n")
+        .response_ok()
+        .send(167, "*2\r\n$4\r\nAUTH\r\n$7\r\nrotated\r\n")
+        .response_error()
+        .close(167)
+        .socket(168)
+        .connect(168)
+        .send(168, "*2\r\n$4\r\nAUTH\r\n$6\r\nsecret\r\n")
+        .response_ok()
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.auth(Credentials::password_only("secret"));
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+
+    let error = handler
+        .reauth(&mut stack, Some(&clock), Credentials::password_only("rotated"))
+        .unwrap_err();
+    assert_eq!(
+        AuthenticationError(CommandErrors::ErrorResponse("Error".to_string())),
+        error
+    );
+
+    handler.disconnect(&mut stack);
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+}
+
+#[test]
+fn test_reauth_not_connected_stores_credentials() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*3\r\n$4\r\nAUTH\r\n$4\r\nuser\r\n$7\r\nrotated\r\n")
+        .response_ok()
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler
+        .reauth(&mut stack, Some(&clock), Credentials::acl("user", "rotated"))
+        .unwrap();
+
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+}
+
+#[test]
+fn test_buffer_pool_reused_by_subsequent_clients() {
+    let clock = TestClock::new(vec![]);
+
+    let mut stack = NetworkMockBuilder::default()
+        .socket(167)
+        .connect(167)
+        .send(167, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response("$5\r\nvalue\r\n")
+        .send(167, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response("$5\r\nother\r\n")
+        .into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.use_buffer_pool();
+
+    let response = handler
+        .connect(&mut stack, Some(&clock))
+        .unwrap()
+        .get("key")
+        .unwrap()
+        .wait()
+        .unwrap()
+        .unwrap();
+    assert_eq!("value", response.as_string().unwrap());
+
+    let response = handler
+        .connect(&mut stack, Some(&clock))
+        .unwrap()
+        .get("key")
+        .unwrap()
+        .wait()
+        .unwrap()
+        .unwrap();
+    assert_eq!("other", response.as_string().unwrap());
+
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+    // Initial connect creates an additional client for initialization
+    assert_eq!(3, handler.buffer_pool_reused());
+}
+
+#[test]
+fn test_buffer_pool_disabled_by_default() {
+    let clock = TestClock::new(vec![]);
+    let mut stack = NetworkMockBuilder::default().socket(167).connect(167).into_mock();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+    handler.connect(&mut stack, Some(&clock)).unwrap();
+
+    assert_eq!(0, handler.buffer_pool_reused());
+}
+
+#[test]
+fn test_connection_error_display() {
+    assert_eq!("TCP connect failed", TcpConnectionFailed.to_string());
+    assert!(TcpConnectionFailed.source().is_none());
+
+    let error = AuthenticationError(CommandErrors::ErrorResponse(
+        "WRONGPASS invalid password".to_string(),
+    ));
+    assert_eq!(
+        "authentication failed: Redis error response: WRONGPASS invalid password",
+        error.to_string()
+    );
+    assert!(error.source().is_some());
+}
@@ -0,0 +1,205 @@
+use crate::commands::builder::CommandBuilder;
+use crate::commands::get::GetCommand;
+use crate::commands::set::SetCommand;
+use crate::network::buffer::Network;
+use crate::network::metrics::{latency_bucket, CommandMetric};
+use crate::network::protocol::Resp2;
+use crate::network::response::MemoryParameters;
+use crate::network::tests::mocks::{
+    create_mocked_client, MockNetworkStack, NetworkMockBuilder, SocketMock, TestClock,
+};
+use crate::network::Client;
+use alloc::vec;
+use core::cell::RefCell;
+use embedded_time::duration::{Extensions, Microseconds};
+
+#[test]
+fn test_counts_per_keyword() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$5\r\nvalue\r\n")
+        .response_ok()
+        .send(164, "*3\r\n$3\r\nSET\r\n$5\r\nother\r\n$5\r\nvalue\r\n")
+        .response_ok()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_string("value")
+        .send(164, "*2\r\n$3\r\nGET\r\n$5\r\nother\r\n")
+        .response_string("value")
+        .send(164, "*2\r\n$3\r\nGET\r\n$7\r\nmissing\r\n")
+        .response_null_resp2()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.send(SetCommand::new("key", "value")).unwrap().wait().unwrap();
+    client.send(SetCommand::new("other", "value")).unwrap().wait().unwrap();
+    client.send(GetCommand::new("key")).unwrap().wait().unwrap();
+    client.send(GetCommand::new("other")).unwrap().wait().unwrap();
+    client.send(GetCommand::new("missing")).unwrap().wait().unwrap();
+
+    let metrics = client.metrics_snapshot();
+    assert_eq!(2, metrics.len());
+    assert_eq!(3, metrics["GET"].count);
+    assert_eq!(2, metrics["SET"].count);
+    assert_eq!(0, metrics["GET"].errors);
+
+    // No latency recorded, as the clock fails
+    assert_eq!([0; 8], metrics["GET"].latency);
+}
+
+#[test]
+fn test_keyword_case_insensitive() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nget\r\n$3\r\nkey\r\n")
+        .response_null_resp2()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    let command = CommandBuilder::new("get").arg_static("key").to_command();
+    client.send(command).unwrap().wait().unwrap();
+
+    assert_eq!(1, client.metrics_snapshot()["GET"].count);
+}
+
+#[test]
+fn test_errors_counted() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_error()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    assert!(client.send(GetCommand::new("key")).unwrap().wait().is_err());
+
+    let metric = &client.metrics_snapshot()["GET"];
+    assert_eq!(1, metric.count);
+    assert_eq!(1, metric.errors);
+}
+
+#[test]
+fn test_latency_recorded() {
+    let clock = TestClock::new(vec![
+        0,     // Timer creation
+        2_500, // First receive() call
+        3_000, // Completion
+    ]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_no_data()
+        .response_string("value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = timeout_client(&mut network, &mut socket, &clock);
+
+    client.send(GetCommand::new("key")).unwrap().wait().unwrap();
+
+    assert_eq!(
+        CommandMetric {
+            count: 1,
+            errors: 0,
+            latency: [0, 1, 0, 0, 0, 0, 0, 0],
+        },
+        client.metrics_snapshot()["GET"]
+    );
+}
+
+#[test]
+fn test_latency_single_clock_read_on_completion() {
+    // Timer creation, the single timeout check and the completion read the clock
+    let clock = TestClock::new(vec![0, 100, 200, 300]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_no_data()
+        .response_string("value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = timeout_client(&mut network, &mut socket, &clock);
+
+    client.send(GetCommand::new("key")).unwrap().wait().unwrap();
+
+    assert_eq!(vec![300], *clock.next_instants.borrow());
+    assert_eq!(1, client.metrics_snapshot()["GET"].latency[0]);
+}
+
+#[test]
+fn test_latency_response_on_first_read() {
+    let clock = TestClock::new(vec![
+        0,     // Timer creation
+        7_000, // Completion
+    ]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_string("value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = timeout_client(&mut network, &mut socket, &clock);
+
+    client.send(GetCommand::new("key")).unwrap().wait().unwrap();
+
+    assert_eq!(1, client.metrics_snapshot()["GET"].latency[2]);
+}
+
+#[test]
+fn test_latency_recorded_without_timeout() {
+    let clock = TestClock::new(vec![
+        0,      // Sending
+        60_000, // Completion
+    ]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_no_data()
+        .response_string("value")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let client = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {});
+
+    client.send(GetCommand::new("key")).unwrap().wait().unwrap();
+
+    assert_eq!(1, client.metrics_snapshot()["GET"].latency[4]);
+}
+
+#[test]
+fn test_latency_bucket() {
+    assert_eq!(0, latency_bucket(0_u32.microseconds()));
+    assert_eq!(0, latency_bucket(1_000_u32.microseconds()));
+    assert_eq!(1, latency_bucket(Microseconds::<u64>::new(1_001)));
+    assert_eq!(6, latency_bucket(1_000_000_u32.microseconds()));
+    assert_eq!(7, latency_bucket(Microseconds::<u64>::new(1_000_001)));
+}
+
+fn timeout_client<'a>(
+    network: &'a mut MockNetworkStack,
+    socket: &'a mut SocketMock,
+    clock: &'a TestClock,
+) -> Client<'a, MockNetworkStack, TestClock, Resp2> {
+    Client {
+        network: Network::new(
+            RefCell::new(network),
+            RefCell::new(socket),
+            Resp2 {},
+            MemoryParameters::default(),
+        ),
+        timeout_duration: 10_000.microseconds(),
+        clock: Some(clock),
+        hello_response: None,
+        assumed_version: None,
+    }
+}
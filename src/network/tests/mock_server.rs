@@ -0,0 +1,133 @@
+use crate::network::handler::ConnectionHandler;
+use crate::network::tests::mocks::TestClock;
+use crate::network::tests::server::MockServer;
+use crate::network::CommandErrors;
+use alloc::vec;
+use core::net::SocketAddr;
+use core::str::FromStr;
+
+#[test]
+fn test_set_get_resp2() {
+    let clock = TestClock::new(vec![]);
+    let mut server = MockServer::default();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    let client = handler.connect(&mut server, Some(&clock)).unwrap();
+
+    client.set("key", "value").unwrap().wait().unwrap();
+    let response = client.get("key").unwrap().wait().unwrap().unwrap();
+    assert_eq!("value", response.as_str().unwrap());
+
+    assert!(client.get("missing").unwrap().wait().unwrap().is_none());
+}
+
+#[test]
+fn test_set_get_resp3() {
+    let clock = TestClock::new(vec![]);
+    let mut server = MockServer::default();
+
+    let mut handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    let client = handler.connect(&mut server, Some(&clock)).unwrap();
+
+    client.set("key", "value").unwrap().wait().unwrap();
+    let response = client.get("key").unwrap().wait().unwrap().unwrap();
+    assert_eq!("value", response.as_str().unwrap());
+
+    assert!(client.get("missing").unwrap().wait().unwrap().is_none());
+}
+
+#[test]
+fn test_data_shared_across_connections() {
+    let clock = TestClock::new(vec![]);
+    let mut server = MockServer::default();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    handler
+        .connect(&mut server, Some(&clock))
+        .unwrap()
+        .set("key", "value")
+        .unwrap()
+        .wait()
+        .unwrap();
+    handler.disconnect(&mut server);
+
+    assert_eq!("value", server.get_value(b"key").unwrap());
+}
+
+#[test]
+fn test_incr_and_del() {
+    let clock = TestClock::new(vec![]);
+    let mut server = MockServer::default();
+
+    let mut handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    let client = handler.connect(&mut server, Some(&clock)).unwrap();
+
+    assert_eq!(1, client.incr("counter").unwrap().wait().unwrap());
+    assert_eq!(2, client.incr("counter").unwrap().wait().unwrap());
+    assert_eq!(
+        1,
+        client.del(["counter".into(), "missing".into()]).unwrap().wait().unwrap()
+    );
+
+    client.set("text", "abc").unwrap().wait().unwrap();
+    assert!(matches!(
+        client.incr("text").unwrap().wait().unwrap_err(),
+        CommandErrors::ErrorResponse(_)
+    ));
+}
+
+#[test]
+fn test_hset_hget() {
+    let clock = TestClock::new(vec![]);
+    let mut server = MockServer::default();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    let client = handler.connect(&mut server, Some(&clock)).unwrap();
+
+    assert_eq!(1, client.hset("hash", "color", "green").unwrap().wait().unwrap());
+    assert_eq!(0, client.hset("hash", "color", "blue").unwrap().wait().unwrap());
+
+    let response = client.hget("hash", "color").unwrap().wait().unwrap().unwrap();
+    assert_eq!("blue", response.as_str().unwrap());
+    assert!(client.hget("hash", "size").unwrap().wait().unwrap().is_none());
+
+    assert!(matches!(
+        client.get("hash").unwrap().wait().unwrap_err(),
+        CommandErrors::ErrorResponse(_)
+    ));
+}
+
+#[test]
+fn test_expire() {
+    let clock = TestClock::new(vec![]);
+    let mut server = MockServer::default();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    {
+        let client = handler.connect(&mut server, Some(&clock)).unwrap();
+        client.set("key", "value").unwrap().wait().unwrap();
+        assert!(client.expire("key", 10).unwrap().wait().unwrap());
+        assert!(!client.expire("missing", 10).unwrap().wait().unwrap());
+    }
+
+    server.advance(9_999);
+    assert_eq!("value", server.get_value(b"key").unwrap());
+
+    server.advance(1);
+    assert!(server.get_value(b"key").is_none());
+}
+
+#[test]
+fn test_unknown_command() {
+    let clock = TestClock::new(vec![]);
+    let mut server = MockServer::default();
+
+    let mut handler = ConnectionHandler::resp2(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+    let client = handler.connect(&mut server, Some(&clock)).unwrap();
+
+    let error = client.call("LPOS", &["list".into(), "a".into()]).unwrap_err();
+    assert_eq!(
+        CommandErrors::ErrorResponse("ERR unknown command 'lpos'".into()),
+        error
+    );
+}
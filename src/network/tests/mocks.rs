@@ -26,6 +26,7 @@ use redis_protocol::resp2::types::BytesFrame as Resp2Frame;
 use redis_protocol::resp3::encode::complete::encode_bytes as resp3_encode_bytes;
 use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, FrameMap};
 use std::io::Write;
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug)]
 pub struct SocketMock {
@@ -83,6 +84,9 @@ mock! {
 
 pub struct NetworkMockBuilder {
     stack: MockNetworkStack,
+
+    /// Argument of the last PING command, s. [send_ping](NetworkMockBuilder::send_ping)
+    ping_argument: Arc<Mutex<String>>,
 }
 
 /// Helper for constructing network layer mock
@@ -142,6 +146,49 @@ impl NetworkMockBuilder {
         self
     }
 
+    /// Asserts that the given binary data is sent, e.g. in case of non UTF-8 arguments
+    pub fn send_bytes(mut self, socket_id: i32, data: &'static [u8]) -> Self {
+        self.stack.expect_send().times(1).returning(move |socket, buffer| {
+            assert_eq!(socket_id, socket.id);
+            assert_eq!(data, buffer);
+
+            nb::Result::Ok(0)
+        });
+        self
+    }
+
+    /// Asserts that a PING command with single argument is sent. The argument is echoed by
+    /// [response_echo](NetworkMockBuilder::response_echo), e.g. for arguments unknown upfront.
+    pub fn send_ping(mut self, socket_id: i32) -> Self {
+        let argument = self.ping_argument.clone();
+
+        self.stack.expect_send().times(1).returning(move |socket, buffer| {
+            assert_eq!(socket_id, socket.id);
+
+            let data = String::from_utf8(buffer.to_vec()).unwrap();
+            let parts: Vec<&str> = data.split("\r\n").collect();
+            assert_eq!(["*2", "$4", "PING"], parts[..3]);
+
+            *argument.lock().unwrap() = parts[4].to_string();
+            nb::Result::Ok(0)
+        });
+        self
+    }
+
+    /// Responds with the given prefix (e.g. garbage data) followed by the echo of the last PING argument
+    pub fn response_echo(mut self, prefix: &'static str) -> Self {
+        let argument = self.ping_argument.clone();
+
+        self.stack.expect_receive().times(1).returning(move |_, mut buffer: &mut [u8]| {
+            let argument = argument.lock().unwrap();
+            let data = format!("{}${}\r\n{}\r\n", prefix, argument.len(), argument);
+
+            let _ = buffer.write(data.as_bytes()).unwrap();
+            nb::Result::Ok(data.len())
+        });
+        self
+    }
+
     /// Asserts that HELLO frame is sent
     pub fn send_hello(mut self, socket_id: i32) -> Self {
         self.stack.expect_send().times(1).returning(move |socket, buffer| {
@@ -258,6 +305,62 @@ impl NetworkMockBuilder {
         self
     }
 
+    /// Simulates a confirmed pattern subscription
+    pub fn psub_confirmation_resp3(mut self, pattern: &'static str, channel_count: usize) -> Self {
+        self.stack.expect_receive().times(1).returning(move |_, mut buffer: &mut [u8]| {
+            let frame = b">3\r\n+psubscribe\r\n";
+            let _ = buffer.write(frame).unwrap();
+            nb::Result::Ok(frame.len())
+        });
+
+        self.stack.expect_receive().times(1).returning(move |_, mut buffer: &mut [u8]| {
+            let frame = format!("+{pattern}\r\n:{channel_count}\r\n");
+            let _ = buffer.write(frame.as_bytes()).unwrap();
+            nb::Result::Ok(frame.len())
+        });
+
+        self
+    }
+
+    /// Simulates a confirmed pattern unsubscription
+    pub fn punsub_confirmation_resp3(mut self, pattern: &'static str, channel_count: usize) -> Self {
+        self.stack.expect_receive().times(1).returning(move |_, mut buffer: &mut [u8]| {
+            let frame = b">3\r\n+punsubscribe\r\n";
+            let _ = buffer.write(frame).unwrap();
+            nb::Result::Ok(frame.len())
+        });
+
+        self.stack.expect_receive().times(1).returning(move |_, mut buffer: &mut [u8]| {
+            let frame = format!("+{pattern}\r\n:{channel_count}\r\n");
+            let _ = buffer.write(frame.as_bytes()).unwrap();
+            nb::Result::Ok(frame.len())
+        });
+
+        self
+    }
+
+    /// Simulates a message published to a channel matching a subscribed pattern
+    pub fn sub_pattern_message(
+        mut self,
+        pattern: &'static str,
+        channel: &'static str,
+        payload: &'static str,
+    ) -> Self {
+        self.stack.expect_receive().times(1).returning(move |_, mut buffer: &mut [u8]| {
+            let frame = format!(">4\r\n+pmessage\r\n+{pattern}\r\n");
+            let _ = buffer.write(frame.as_bytes()).unwrap();
+            nb::Result::Ok(frame.len())
+        });
+
+        self.stack.expect_receive().times(1).returning(move |_, mut buffer: &mut [u8]| {
+            let frame = format!("+{channel}\r\n+{payload}\r\n");
+            let _ = buffer.write(frame.as_bytes()).unwrap();
+            nb::Result::Ok(frame.len())
+        });
+
+        self
+    }
+
     /// Prepares RESP3 Null response
     #[allow(unused)]
     pub fn response_null_resp3(mut self) -> Self {
@@ -314,8 +417,23 @@ impl NetworkMockBuilder {
     }
 
     /// Simulates correct HELLO response
-    pub fn response_hello(mut self) -> Self {
-        let frame = MockFrames::hello();
+    pub fn response_hello(self) -> Self {
+        self.response_hello_version("6.0.0")
+    }
+
+    /// Prepares a HELLO response reporting the given server version
+    pub fn response_hello_version(self, version: &'static str) -> Self {
+        self.response_frame_chunks(MockFrames::hello_version(version), usize::MAX)
+    }
+
+    /// Prepares the first chunks of a HELLO response listing the given number of modules
+    pub fn response_hello_modules(self, module_count: usize, chunk_count: usize) -> Self {
+        self.response_frame_chunks(MockFrames::hello_modules(module_count), chunk_count)
+    }
+
+    /// Prepares the given RESP3 frame as response, split into chunks of 32 bytes.
+    /// Only the given number of chunks is received.
+    fn response_frame_chunks(mut self, frame: Resp3Frame, chunk_count: usize) -> Self {
         let mut bytes = BytesMut::new();
 
         // Extend buffer if needed
@@ -328,7 +446,7 @@ impl NetworkMockBuilder {
         }
 
         let mut byte_chunks = vec![];
-        for chunk in bytes.chunks(32) {
+        for chunk in bytes.chunks(32).take(chunk_count) {
             byte_chunks.push(Bytes::copy_from_slice(chunk));
         }
 
@@ -359,6 +477,7 @@ impl Default for NetworkMockBuilder {
     fn default() -> Self {
         Self {
             stack: MockNetworkStack::new(),
+            ping_argument: Arc::new(Mutex::new(String::new())),
         }
     }
 }
@@ -367,9 +486,47 @@ pub struct MockFrames {}
 
 impl MockFrames {
     pub fn hello() -> Resp3Frame {
+        Self::hello_version("6.0.0")
+    }
+
+    /// HELLO response listing the given number of loaded modules
+    pub fn hello_modules(count: usize) -> Resp3Frame {
+        let mut modules = vec![];
+        for index in 0..count {
+            let mut module = FrameMap::new();
+            module.insert(
+                CmdStr::new("name").to_blob(),
+                Resp3Frame::BlobString {
+                    data: format!("module_{index}").into(),
+                    attributes: None,
+                },
+            );
+            module.insert(CmdStr::new("ver").to_blob(), RespInt::new(10000).to_number());
+            modules.push(Resp3Frame::Map {
+                data: module,
+                attributes: None,
+            });
+        }
+
+        let mut frame = Self::hello();
+        if let Resp3Frame::Map { data, .. } = &mut frame {
+            data.insert(
+                CmdStr::new("modules").to_blob(),
+                Resp3Frame::Array {
+                    data: modules,
+                    attributes: None,
+                },
+            );
+        }
+
+        frame
+    }
+
+    /// HELLO response reporting the given server version
+    pub fn hello_version(version: &'static str) -> Resp3Frame {
         let mut map = FrameMap::new();
         map.insert(CmdStr::new("server").to_blob(), CmdStr::new("redis").to_blob());
-        map.insert(CmdStr::new("version").to_blob(), CmdStr::new("6.0.0").to_blob());
+        map.insert(CmdStr::new("version").to_blob(), CmdStr::new(version).to_blob());
         map.insert(CmdStr::new("proto").to_blob(), RespInt::new(3).to_number());
         map.insert(CmdStr::new("id").to_blob(), RespInt::new(10).to_number());
         map.insert(CmdStr::new("mode").to_blob(), CmdStr::new("standalone").to_blob());
@@ -410,14 +567,14 @@ impl Clock for TestClock {
     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000_000);
 
     fn try_now(&self) -> Result<Instant<Self>, Error> {
-        if self.next_instants.borrow().len() == 0 {
+        if self.next_instants.borrow().is_empty() {
             return Err(Error::Unspecified);
         }
 
         Ok(Instant::new(self.next_instants.borrow_mut().remove(0)))
     }
 
-    fn new_timer<Dur>(&self, duration: Dur) -> Timer<OneShot, Armed, Self, Dur>
+    fn new_timer<Dur>(&self, duration: Dur) -> Timer<'_, OneShot, Armed, Self, Dur>
     where
         Dur: FixedPoint,
         Dur: Duration,
@@ -453,5 +610,6 @@ where
         timeout_duration: 0.microseconds(),
         clock: Some(clock),
         hello_response: None,
+        assumed_version: None,
     }
 }
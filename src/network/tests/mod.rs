@@ -3,10 +3,20 @@ mod benchmarks;
 #[cfg(test)]
 mod client;
 #[cfg(test)]
+mod debug;
+#[cfg(test)]
 mod handler;
+#[cfg(all(test, feature = "metrics"))]
+mod metrics;
+#[cfg(test)]
+mod mock_server;
 #[cfg(any(test, feature = "mock"))]
 pub(crate) mod mocks;
 #[cfg(test)]
 mod response;
+#[cfg(any(test, feature = "mock"))]
+pub(crate) mod server;
 #[cfg(test)]
 mod timeout;
+#[cfg(test)]
+mod version;
@@ -1,6 +1,7 @@
-use crate::network::protocol::Resp2;
+use crate::network::protocol::{Resp2, Resp3};
 use crate::network::response::{MemoryParameters, ResponseBuffer};
 use redis_protocol::resp2::types::{BytesFrame, Resp2Frame as _};
+use redis_protocol::resp3::types::{BytesFrame as Resp3Frame, Resp3Frame as _};
 
 #[test]
 fn test_complete_empty_buffer() {
@@ -243,6 +244,103 @@ fn test_faulty_previous_frame_readable() {
     assert_eq!("OK", frame.to_string().unwrap());
 }
 
+#[test]
+fn test_skip_faulty_preserves_following_frame() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.append(b"+OK\r\n_test\r\n:5\r\n");
+    assert!(buffer.is_faulty());
+
+    assert!(buffer.skip_faulty());
+
+    assert!(!buffer.is_faulty());
+    assert_eq!("OK", buffer.take_next_frame().unwrap().to_string().unwrap());
+    assert_eq!(BytesFrame::Integer(5), buffer.take_next_frame().unwrap());
+}
+
+#[test]
+fn test_skip_faulty_multiple_garbage_sections() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.append(b"_a\r\n:1\r\n?!:2\r\n_b");
+    assert!(buffer.is_faulty());
+
+    assert!(buffer.skip_faulty());
+
+    assert!(!buffer.is_faulty());
+    assert_eq!(BytesFrame::Integer(1), buffer.take_next_frame().unwrap());
+    assert_eq!(BytesFrame::Integer(2), buffer.take_next_frame().unwrap());
+    assert!(buffer.take_next_frame().is_none());
+}
+
+#[test]
+fn test_skip_faulty_keeps_incomplete_frame() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.append(b"_test\r\n$5\r\nval");
+    assert!(buffer.is_faulty());
+
+    assert!(buffer.skip_faulty());
+    assert!(buffer.take_next_frame().is_none());
+
+    buffer.append(b"ue\r\n");
+    assert_eq!("value", buffer.take_next_frame().unwrap().to_string().unwrap());
+}
+
+#[test]
+fn test_skip_faulty_not_faulty() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.append(b"+OK\r\n");
+
+    assert!(!buffer.skip_faulty());
+    assert!(buffer.take_next_frame().is_some());
+}
+
+#[test]
+fn test_inline_response_faulty_by_default() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.append(b"PONG\r\n");
+
+    assert!(buffer.is_faulty());
+    assert!(!buffer.is_complete(0));
+}
+
+#[test]
+fn test_inline_response_accepted() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.accept_inline(true);
+    buffer.append(b"PONG\r\n+OK\r\nOK");
+
+    assert!(!buffer.is_faulty());
+    assert_eq!(
+        BytesFrame::SimpleString("PONG".into()),
+        buffer.take_frame(0).unwrap()
+    );
+    assert_eq!(
+        BytesFrame::SimpleString("OK".into()),
+        buffer.take_frame(1).unwrap()
+    );
+    assert!(!buffer.is_complete(2));
+
+    buffer.append(b"\r\n");
+    assert_eq!(
+        BytesFrame::SimpleString("OK".into()),
+        buffer.take_frame(2).unwrap()
+    );
+}
+
+#[test]
+fn test_inline_response_accepted_resp3() {
+    let mut buffer = ResponseBuffer::new(Resp3 {}, MemoryParameters::default());
+    buffer.accept_inline(true);
+    buffer.append(b"PONG\r\n");
+
+    assert_eq!(
+        Resp3Frame::SimpleString {
+            data: "PONG".into(),
+            attributes: None
+        },
+        buffer.take_frame(0).unwrap()
+    );
+}
+
 #[test]
 fn test_take_next_frame_correct_order() {
     let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
@@ -264,3 +362,212 @@ fn test_take_next_frame_correct_offset() {
     assert_eq!("2", buffer.take_next_frame().unwrap().to_string().unwrap());
     assert!(buffer.take_next_frame().is_none());
 }
+
+#[test]
+fn test_binary_crlf_payload_split_before_crlf() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.append(b"$8\r\nab");
+    buffer.append(b"\r\n");
+
+    assert!(!buffer.is_faulty());
+    assert!(!buffer.is_complete(0));
+
+    buffer.append(b"\x00\xffcd\r\n");
+
+    assert!(!buffer.is_faulty());
+    assert_eq!(
+        BytesFrame::BulkString(bytes::Bytes::from_static(b"ab\r\n\x00\xffcd")),
+        buffer.take_frame(0).unwrap()
+    );
+}
+
+#[test]
+fn test_binary_crlf_payload_split_inside_crlf() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.append(b"$6\r\n\r");
+    buffer.append(b"\n\r\n\r");
+    buffer.append(b"\n\r\n");
+
+    assert!(!buffer.is_faulty());
+    assert_eq!(
+        BytesFrame::BulkString(bytes::Bytes::from_static(b"\r\n\r\n\r\n")),
+        buffer.take_frame(0).unwrap()
+    );
+}
+
+#[test]
+fn test_binary_crlf_payload_byte_by_byte() {
+    let data = b"$9\r\nA\r\nB\r\n\r\nC\r\n+OK\r\n";
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+
+    for byte in data.iter() {
+        buffer.append(&[*byte]);
+        assert!(!buffer.is_faulty());
+    }
+
+    assert_eq!(
+        BytesFrame::BulkString(bytes::Bytes::from_static(b"A\r\nB\r\n\r\nC")),
+        buffer.take_frame(0).unwrap()
+    );
+    assert_eq!("OK", buffer.take_frame(1).unwrap().to_string().unwrap());
+}
+
+#[test]
+fn test_binary_crlf_payload_resp3_byte_by_byte() {
+    let data = b"$9\r\nA\r\nB\r\n\r\nC\r\n";
+    let mut buffer = ResponseBuffer::new(Resp3 {}, MemoryParameters::default());
+
+    for byte in data.iter() {
+        buffer.append(&[*byte]);
+        assert!(!buffer.is_faulty());
+    }
+
+    assert_eq!(
+        Resp3Frame::BlobString {
+            data: bytes::Bytes::from_static(b"A\r\nB\r\n\r\nC"),
+            attributes: None
+        },
+        buffer.take_frame(0).unwrap()
+    );
+}
+
+#[test]
+fn test_binary_crlf_payload_followed_by_malformed_frame() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.append(b"$4\r\na\r\nb");
+    buffer.append(b"\r\n_test\r\n");
+
+    assert!(buffer.is_faulty());
+    assert_eq!(
+        BytesFrame::BulkString(bytes::Bytes::from_static(b"a\r\nb")),
+        buffer.take_frame(0).unwrap()
+    );
+}
+
+#[test]
+fn test_resp3_map_on_resp2_faulty() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.append(b"+OK\r\n%1\r\n+a\r\n+b\r\n");
+
+    assert!(buffer.is_faulty());
+    assert_eq!(
+        BytesFrame::SimpleString("OK".into()),
+        buffer.take_frame(0).unwrap()
+    );
+    assert!(!buffer.is_complete(1));
+}
+
+#[test]
+fn test_resp3_prefix_on_resp2_faulty() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.append(b"%");
+
+    assert!(buffer.is_faulty());
+}
+
+#[test]
+fn test_resp2_frames_accepted() {
+    let mut buffer = ResponseBuffer::new(Resp2 {}, MemoryParameters::default());
+    buffer.append(b"+OK\r\n-ERR\r\n:1\r\n$1\r\na\r\n*1\r\n$-1\r\n");
+
+    assert!(!buffer.is_faulty());
+    assert!(buffer.is_complete(4));
+}
+
+#[test]
+fn test_resp3_frames_accepted() {
+    let mut buffer = ResponseBuffer::new(Resp3 {}, MemoryParameters::default());
+    buffer.append(b"%1\r\n+a\r\n+b\r\n_\r\n#t\r\n");
+
+    assert!(!buffer.is_faulty());
+    assert!(buffer.is_complete(2));
+}
+
+#[test]
+fn test_resp3_streamed_array_assembled() {
+    let mut buffer = ResponseBuffer::new(Resp3 {}, MemoryParameters::default());
+    buffer.append(b"*?\r\n:1\r\n+a\r\n.\r\n+OK\r\n");
+
+    assert!(!buffer.is_faulty());
+    assert!(buffer.is_complete(1));
+    assert_eq!(
+        Resp3Frame::Array {
+            data: vec![
+                Resp3Frame::Number {
+                    data: 1,
+                    attributes: None
+                },
+                Resp3Frame::SimpleString {
+                    data: "a".into(),
+                    attributes: None
+                },
+            ],
+            attributes: None
+        },
+        buffer.take_frame(0).unwrap()
+    );
+    assert_eq!("OK", buffer.take_frame(1).unwrap().to_string().unwrap());
+}
+
+#[test]
+fn test_resp3_streamed_string_assembled() {
+    let mut buffer = ResponseBuffer::new(Resp3 {}, MemoryParameters::default());
+    buffer.append(b"$?\r\n;4\r\nHell\r\n;1\r\no\r\n;0\r\n");
+
+    assert!(buffer.is_complete(0));
+    assert_eq!("Hello", buffer.take_frame(0).unwrap().to_string().unwrap());
+}
+
+#[test]
+fn test_resp3_streamed_map_byte_by_byte() {
+    let mut buffer = ResponseBuffer::new(Resp3 {}, MemoryParameters::default());
+    let data = b"%?\r\n+key\r\n:5\r\n.\r\n:2\r\n";
+
+    for byte in &data[..data.len() - 4] {
+        assert!(!buffer.is_complete(0));
+        buffer.append(&[*byte]);
+    }
+    assert!(buffer.is_complete(0));
+    assert!(!buffer.is_complete(1));
+
+    buffer.append(&data[data.len() - 4..]);
+    assert!(buffer.is_complete(1));
+    assert!(matches!(buffer.take_frame(0).unwrap(), Resp3Frame::Map { .. }));
+    assert_eq!(
+        2,
+        buffer.take_frame(1).unwrap().to_string().unwrap().parse::<i64>().unwrap()
+    );
+}
+
+#[test]
+fn test_resp3_streamed_nested_faulty() {
+    let mut buffer = ResponseBuffer::new(Resp3 {}, MemoryParameters::default());
+    buffer.append(b"*?\r\n*?\r\n:1\r\n.\r\n.\r\n");
+
+    assert!(buffer.is_faulty());
+    assert!(!buffer.is_complete(0));
+}
+
+#[test]
+fn test_reset_for_reuse() {
+    let parameters = MemoryParameters {
+        buffer_size: 16,
+        frame_capacity: 2,
+        memory_limit: None,
+    };
+    let mut buffer = ResponseBuffer::new(Resp2 {}, parameters.clone());
+    buffer.accept_inline(true);
+    buffer.append(b"+OK\r\n+OK\r\n+OK\r\n+OK\r\n$40\r\n0123456789");
+    assert!(buffer.is_complete(3));
+
+    buffer.reset(&parameters);
+
+    assert!(!buffer.is_complete(0));
+    assert_eq!(0, buffer.frame_offset());
+    assert_eq!(0, buffer.pending_frame_count());
+    assert!(buffer.frame_capacity() < 4);
+
+    // Inline decoding is disabled again
+    buffer.append(b"PONG\r\n");
+    assert!(buffer.is_faulty());
+}
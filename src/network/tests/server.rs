@@ -0,0 +1,419 @@
+use crate::network::tests::mocks::MockTcpError::Error1;
+use crate::network::tests::mocks::{MockTcpError, SocketMock};
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use bytes::Bytes;
+use core::net::SocketAddr;
+use core::str::from_utf8;
+use embedded_nal::TcpClientStack;
+use redis_protocol::resp2;
+use redis_protocol::resp2::types::BytesFrame as Resp2Frame;
+
+/// Server version reported by HELLO
+const VERSION: &str = "7.0.0";
+
+/// In-memory Redis server implementing a subset of commands
+///
+/// In contrast to [NetworkMockBuilder](crate::network::NetworkMockBuilder), which requires scripting every byte
+/// sent and received, the mock server processes the commands on its own. So tests can exercise full command
+/// round-trips without a real Redis server.
+///
+/// Supported commands: GET, SET, DEL, EXPIRE, INCR, HSET, HGET, PING and HELLO. Any other command is answered by
+/// an error response. Connections start in RESP2 mode and are switched to RESP3 by `HELLO 3`.
+///
+/// Time does not pass on its own. Expiration is simulated by [advance](MockServer::advance).
+/// ```
+///# use core::str::FromStr;
+///# use core::net::SocketAddr;
+///# use std_embedded_time::StandardClock;
+///# use embedded_redis::network::{ConnectionHandler, MockServer};
+///#
+/// let mut server = MockServer::default();
+/// let clock = StandardClock::default();
+///
+/// let mut connection_handler = ConnectionHandler::resp3(SocketAddr::from_str("127.0.0.1:6379").unwrap());
+/// let client = connection_handler.connect(&mut server, Some(&clock)).unwrap();
+///
+/// client.set("key", "value").unwrap().wait().unwrap();
+/// let response = client.get("key").unwrap().wait().unwrap().unwrap();
+/// assert_eq!("value", response.as_str().unwrap());
+/// ```
+#[derive(Debug, Default)]
+pub struct MockServer {
+    /// Keyspace, shared by all connections
+    store: Store,
+
+    /// Open connections by socket ID
+    connections: BTreeMap<i32, Connection>,
+
+    /// ID of the last created socket
+    last_socket: i32,
+}
+
+impl MockServer {
+    /// Advances the simulated time, so keys with elapsed TTL expire
+    pub fn advance(&mut self, milliseconds: u64) {
+        self.store.now += milliseconds;
+    }
+
+    /// Returns the string value of the given key. None if the key is missing, expired or of other type.
+    pub fn get_value(&self, key: &[u8]) -> Option<Bytes> {
+        match self.store.entries.get(key) {
+            Some(entry) if !entry.is_expired(self.store.now) => match &entry.value {
+                Value::String(value) => Some(value.clone()),
+                Value::Hash(_) => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Decodes and executes all complete commands of the given connection
+    fn process(&mut self, socket_id: i32) -> Result<(), MockTcpError> {
+        let connection = self.connections.get_mut(&socket_id).ok_or(Error1)?;
+
+        while let Some(args) = connection.next_command()? {
+            let reply = self.store.execute(&args, &mut connection.resp3);
+            reply.encode(connection.resp3, &mut connection.output);
+        }
+
+        Ok(())
+    }
+}
+
+impl TcpClientStack for MockServer {
+    type TcpSocket = SocketMock;
+    type Error = MockTcpError;
+
+    fn socket(&mut self) -> Result<Self::TcpSocket, Self::Error> {
+        self.last_socket += 1;
+        self.connections.insert(self.last_socket, Connection::default());
+        Ok(SocketMock::new(self.last_socket))
+    }
+
+    fn connect(&mut self, _socket: &mut Self::TcpSocket, _remote: SocketAddr) -> nb::Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn send(&mut self, socket: &mut Self::TcpSocket, buffer: &[u8]) -> nb::Result<usize, Self::Error> {
+        let connection = self.connections.get_mut(&socket.id).ok_or(Error1)?;
+        connection.input.extend_from_slice(buffer);
+
+        self.process(socket.id)?;
+        Ok(buffer.len())
+    }
+
+    fn receive(&mut self, socket: &mut Self::TcpSocket, buffer: &mut [u8]) -> nb::Result<usize, Self::Error> {
+        let connection = self.connections.get_mut(&socket.id).ok_or(Error1)?;
+        if connection.output.is_empty() {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        let length = buffer.len().min(connection.output.len());
+        buffer[..length].copy_from_slice(&connection.output[..length]);
+        connection.output.drain(..length);
+
+        Ok(length)
+    }
+
+    fn close(&mut self, socket: Self::TcpSocket) -> Result<(), Self::Error> {
+        self.connections.remove(&socket.id);
+        Ok(())
+    }
+}
+
+/// State of a single client connection
+#[derive(Debug, Default)]
+struct Connection {
+    /// Received data, which is not processed yet
+    input: Vec<u8>,
+
+    /// Encoded replies, which are not received by the client yet
+    output: Vec<u8>,
+
+    /// True if switched to RESP3 by HELLO
+    resp3: bool,
+}
+
+impl Connection {
+    /// Takes the arguments of the next complete command. None if no command is complete yet.
+    /// Supports both RESP arrays and inline commands (e.g. `HELLO 3`).
+    fn next_command(&mut self) -> Result<Option<Vec<Bytes>>, MockTcpError> {
+        if self.input.is_empty() {
+            return Ok(None);
+        }
+
+        if self.input[0] != b'*' {
+            let end = match self.input.windows(2).position(|window| window == b"\r\n") {
+                None => return Ok(None),
+                Some(position) => position,
+            };
+
+            let line: Vec<u8> = self.input.drain(..end + 2).take(end).collect();
+            let args = line
+                .split(|byte| *byte == b' ')
+                .filter(|arg| !arg.is_empty())
+                .map(Bytes::copy_from_slice)
+                .collect();
+
+            return Ok(Some(args));
+        }
+
+        let data = Bytes::copy_from_slice(&self.input);
+        let (frame, length) = match resp2::decode::decode_bytes(&data).map_err(|_| Error1)? {
+            None => return Ok(None),
+            Some(decoded) => decoded,
+        };
+        self.input.drain(..length);
+
+        match frame {
+            Resp2Frame::Array(items) => items
+                .into_iter()
+                .map(|item| match item {
+                    Resp2Frame::BulkString(arg) | Resp2Frame::SimpleString(arg) => Ok(arg),
+                    _ => Err(Error1),
+                })
+                .collect::<Result<Vec<Bytes>, MockTcpError>>()
+                .map(Some),
+            _ => Err(Error1),
+        }
+    }
+}
+
+/// Keyspace of the mock server
+#[derive(Debug, Default)]
+struct Store {
+    entries: BTreeMap<Bytes, Entry>,
+
+    /// Simulated time in milliseconds
+    now: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    value: Value,
+
+    /// Simulated time of expiration in milliseconds
+    expires_at: Option<u64>,
+}
+
+impl Entry {
+    fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+#[derive(Debug)]
+enum Value {
+    String(Bytes),
+    Hash(BTreeMap<Bytes, Bytes>),
+}
+
+impl Store {
+    /// Executes the given command. HELLO may switch the protocol of the connection.
+    fn execute(&mut self, args: &[Bytes], resp3: &mut bool) -> Reply {
+        let name = match args.first() {
+            None => return Reply::Error("ERR empty command".to_string()),
+            Some(name) => String::from_utf8_lossy(name).to_ascii_uppercase(),
+        };
+        self.purge_expired();
+
+        match (name.as_str(), args.len()) {
+            ("PING", 1) => Reply::Status("PONG"),
+            ("PING", 2) => Reply::Bulk(args[1].clone()),
+            ("HELLO", 1) => Reply::Hello,
+            ("HELLO", 2) => match args[1].as_ref() {
+                b"2" => self.switch_protocol(resp3, false),
+                b"3" => self.switch_protocol(resp3, true),
+                _ => Reply::Error("NOPROTO unsupported protocol version".to_string()),
+            },
+            ("GET", 2) => self.get(&args[1]),
+            ("SET", 3) => {
+                self.insert(args[1].clone(), Value::String(args[2].clone()));
+                Reply::Status("OK")
+            }
+            ("DEL", length) if length > 1 => {
+                let removed = args[1..].iter().filter(|key| self.entries.remove(*key).is_some()).count();
+                Reply::Integer(removed as i64)
+            }
+            ("EXPIRE", 3) => self.expire(&args[1], &args[2]),
+            ("INCR", 2) => self.incr(&args[1]),
+            ("HSET", length) if length >= 4 && length % 2 == 0 => self.hset(&args[1], &args[2..]),
+            ("HGET", 3) => self.hget(&args[1], &args[2]),
+            ("PING" | "HELLO" | "GET" | "SET" | "DEL" | "EXPIRE" | "INCR" | "HSET" | "HGET", _) => {
+                Reply::Error(format!(
+                    "ERR wrong number of arguments for '{}' command",
+                    name.to_ascii_lowercase()
+                ))
+            }
+            _ => Reply::Error(format!("ERR unknown command '{}'", name.to_ascii_lowercase())),
+        }
+    }
+
+    fn switch_protocol(&self, resp3: &mut bool, enabled: bool) -> Reply {
+        *resp3 = enabled;
+        Reply::Hello
+    }
+
+    fn get(&self, key: &Bytes) -> Reply {
+        match self.entries.get(key).map(|entry| &entry.value) {
+            None => Reply::Null,
+            Some(Value::String(value)) => Reply::Bulk(value.clone()),
+            Some(Value::Hash(_)) => Reply::wrong_type(),
+        }
+    }
+
+    fn expire(&mut self, key: &Bytes, seconds: &Bytes) -> Reply {
+        let seconds = match parse_integer(seconds) {
+            None => return Reply::not_an_integer(),
+            Some(seconds) => seconds,
+        };
+
+        if !self.entries.contains_key(key) {
+            return Reply::Integer(0);
+        }
+
+        if seconds <= 0 {
+            self.entries.remove(key);
+        } else if let Some(entry) = self.entries.get_mut(key) {
+            entry.expires_at = Some(self.now.saturating_add(seconds as u64 * 1000));
+        }
+
+        Reply::Integer(1)
+    }
+
+    fn incr(&mut self, key: &Bytes) -> Reply {
+        let current = match self.entries.get(key).map(|entry| &entry.value) {
+            None => 0,
+            Some(Value::Hash(_)) => return Reply::wrong_type(),
+            Some(Value::String(value)) => match parse_integer(value) {
+                None => return Reply::not_an_integer(),
+                Some(number) => number,
+            },
+        };
+
+        let next = match current.checked_add(1) {
+            None => return Reply::Error("ERR increment or decrement would overflow".to_string()),
+            Some(next) => next,
+        };
+
+        let value = Value::String(Bytes::from(next.to_string()));
+        match self.entries.get_mut(key) {
+            // INCR keeps the TTL of existing keys
+            Some(entry) => entry.value = value,
+            None => self.insert(key.clone(), value),
+        }
+
+        Reply::Integer(next)
+    }
+
+    fn hset(&mut self, key: &Bytes, pairs: &[Bytes]) -> Reply {
+        if !self.entries.contains_key(key) {
+            self.insert(key.clone(), Value::Hash(BTreeMap::new()));
+        }
+
+        let hash = match self.entries.get_mut(key).map(|entry| &mut entry.value) {
+            Some(Value::Hash(hash)) => hash,
+            _ => return Reply::wrong_type(),
+        };
+
+        let added = pairs
+            .chunks(2)
+            .filter(|pair| hash.insert(pair[0].clone(), pair[1].clone()).is_none())
+            .count();
+
+        Reply::Integer(added as i64)
+    }
+
+    fn hget(&self, key: &Bytes, field: &Bytes) -> Reply {
+        match self.entries.get(key).map(|entry| &entry.value) {
+            None => Reply::Null,
+            Some(Value::Hash(hash)) => hash.get(field).cloned().map_or(Reply::Null, Reply::Bulk),
+            Some(Value::String(_)) => Reply::wrong_type(),
+        }
+    }
+
+    /// Inserts or replaces the value. Any previous TTL is discarded.
+    fn insert(&mut self, key: Bytes, value: Value) {
+        self.entries.insert(
+            key,
+            Entry {
+                value,
+                expires_at: None,
+            },
+        );
+    }
+
+    fn purge_expired(&mut self) {
+        let now = self.now;
+        self.entries.retain(|_, entry| !entry.is_expired(now));
+    }
+}
+
+/// Parses a decimal integer argument
+fn parse_integer(value: &[u8]) -> Option<i64> {
+    from_utf8(value).ok()?.parse().ok()
+}
+
+/// Reply of the mock server, encoded depending on the protocol of the connection
+#[derive(Debug)]
+enum Reply {
+    Status(&'static str),
+    Error(String),
+    Integer(i64),
+    Bulk(Bytes),
+    Null,
+    Hello,
+}
+
+impl Reply {
+    fn wrong_type() -> Self {
+        Reply::Error("WRONGTYPE Operation against a key holding the wrong kind of value".to_string())
+    }
+
+    fn not_an_integer() -> Self {
+        Reply::Error("ERR value is not an integer or out of range".to_string())
+    }
+
+    fn encode(&self, resp3: bool, output: &mut Vec<u8>) {
+        match self {
+            Reply::Status(status) => output.extend_from_slice(format!("+{}\r\n", status).as_bytes()),
+            Reply::Error(message) => output.extend_from_slice(format!("-{}\r\n", message).as_bytes()),
+            Reply::Integer(number) => output.extend_from_slice(format!(":{}\r\n", number).as_bytes()),
+            Reply::Bulk(data) => encode_bulk(data, output),
+            Reply::Null if resp3 => output.extend_from_slice(b"_\r\n"),
+            Reply::Null => output.extend_from_slice(b"$-1\r\n"),
+            Reply::Hello => encode_hello(resp3, output),
+        }
+    }
+}
+
+fn encode_bulk(data: &[u8], output: &mut Vec<u8>) {
+    output.extend_from_slice(format!("${}\r\n", data.len()).as_bytes());
+    output.extend_from_slice(data);
+    output.extend_from_slice(b"\r\n");
+}
+
+/// Encodes the server information as map (RESP3) or flat array (RESP2)
+fn encode_hello(resp3: bool, output: &mut Vec<u8>) {
+    output.extend_from_slice(if resp3 { b"%7\r\n" } else { b"*14\r\n" });
+
+    for (key, value) in [("server", "redis"), ("version", VERSION)] {
+        encode_bulk(key.as_bytes(), output);
+        encode_bulk(value.as_bytes(), output);
+    }
+
+    encode_bulk(b"proto", output);
+    output.extend_from_slice(if resp3 { b":3\r\n" } else { b":2\r\n" });
+    encode_bulk(b"id", output);
+    output.extend_from_slice(b":1\r\n");
+
+    for (key, value) in [("mode", "standalone"), ("role", "master")] {
+        encode_bulk(key.as_bytes(), output);
+        encode_bulk(value.as_bytes(), output);
+    }
+
+    encode_bulk(b"modules", output);
+    output.extend_from_slice(b"*0\r\n");
+}
@@ -0,0 +1,29 @@
+use crate::network::version::ServerVersion;
+
+#[test]
+fn test_parse_full_version() {
+    assert_eq!(Some(ServerVersion::new(7, 2, 4)), ServerVersion::parse("7.2.4"));
+}
+
+#[test]
+fn test_parse_missing_components() {
+    assert_eq!(Some(ServerVersion::new(7, 0, 0)), ServerVersion::parse("7"));
+    assert_eq!(Some(ServerVersion::new(6, 2, 0)), ServerVersion::parse("6.2"));
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert_eq!(None, ServerVersion::parse(""));
+    assert_eq!(None, ServerVersion::parse("7.x.1"));
+    assert_eq!(None, ServerVersion::parse("7.2.4.1"));
+}
+
+#[test]
+fn test_at_least() {
+    let version = ServerVersion::new(6, 2, 7);
+
+    assert!(version.at_least(6, 0));
+    assert!(version.at_least(6, 2));
+    assert!(!version.at_least(6, 3));
+    assert!(!version.at_least(7, 0));
+}
@@ -1,6 +1,5 @@
 use embedded_time::duration::{Extensions, Microseconds};
-use embedded_time::timer::param::{OneShot, Running};
-use embedded_time::{Clock, Timer};
+use embedded_time::{Clock, Instant};
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum TimeoutError {
@@ -10,33 +9,57 @@ pub enum TimeoutError {
 
 #[derive(Debug)]
 pub struct Timeout<'a, C: Clock> {
-    timer: Option<Timer<'a, OneShot, Running, C, Microseconds>>,
+    /// Clock and expiration instant. None if no timeout is used.
+    timer: Option<(&'a C, Instant<C>)>,
+
+    /// Clock and creation instant, used for measuring latencies. Also present if no timeout is used.
+    #[cfg(feature = "metrics")]
+    started: Option<(&'a C, Instant<C>)>,
 }
 
 impl<'a, C: Clock> Timeout<'a, C> {
     pub fn new(clock: Option<&'a C>, duration: Microseconds) -> Result<Timeout<'a, C>, TimeoutError> {
         if clock.is_none() || duration == 0.microseconds() {
-            return Ok(Self { timer: None });
+            return Ok(Self {
+                timer: None,
+                #[cfg(feature = "metrics")]
+                started: clock.and_then(|clock| Some((clock, clock.try_now().ok()?))),
+            });
         }
 
-        let timer = clock.unwrap().new_timer(duration).start();
-        if timer.is_err() {
-            return Err(TimeoutError::TimerStartFailed);
-        }
+        let clock = clock.unwrap();
+        let now = clock.try_now().map_err(|_| TimeoutError::TimerStartFailed)?;
+        let expiration = now.checked_add(duration).ok_or(TimeoutError::TimerStartFailed)?;
 
         Ok(Self {
-            timer: Some(timer.unwrap()),
+            timer: Some((clock, expiration)),
+            #[cfg(feature = "metrics")]
+            started: Some((clock, now)),
         })
     }
 
+    /// Returns false if no timer is used, so the timeout never expires
+    pub fn is_active(&self) -> bool {
+        self.timer.is_some()
+    }
+
     pub fn expired(&self) -> Result<bool, TimeoutError> {
-        if self.timer.is_none() {
-            return Ok(false);
-        }
+        let (clock, expiration) = match &self.timer {
+            None => return Ok(false),
+            Some(timer) => timer,
+        };
 
-        match self.timer.as_ref().unwrap().is_expired() {
-            Ok(result) => Ok(result),
-            Err(_) => Err(TimeoutError::TimerError),
-        }
+        let now = clock.try_now().map_err(|_| TimeoutError::TimerError)?;
+        Ok(now >= *expiration)
+    }
+
+    /// Reads the clock and returns the time elapsed since creation.
+    ///
+    /// None if no clock is used or the clock failed.
+    #[cfg(feature = "metrics")]
+    pub fn elapsed(&self) -> Option<Microseconds<C::T>> {
+        let (clock, started) = self.started.as_ref()?;
+        let duration = clock.try_now().ok()?.checked_duration_since(started)?;
+        Microseconds::try_from(duration).ok()
     }
 }
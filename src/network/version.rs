@@ -0,0 +1,34 @@
+/// Version of the Redis server, used for selecting version dependent command variants
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ServerVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+impl ServerVersion {
+    pub fn new(major: u16, minor: u16, patch: u16) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Parses version strings of format `major.minor.patch`, e.g. as contained in HELLO response.
+    /// Missing minor or patch components are treated as zero.
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.trim().split('.');
+
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().map(|part| part.parse().ok()).unwrap_or(Some(0))?;
+        let patch = parts.next().map(|part| part.parse().ok()).unwrap_or(Some(0))?;
+
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self::new(major, minor, patch))
+    }
+
+    /// Returns true if the version is equal or newer than the given major and minor version
+    pub fn at_least(&self, major: u16, minor: u16) -> bool {
+        *self >= Self::new(major, minor, 0)
+    }
+}
@@ -1,11 +1,18 @@
 use crate::commands::builder::CommandBuilder;
 use crate::commands::hello::HelloCommand;
+use crate::commands::punsubscribe::PUnsubscribeCommand;
 use crate::commands::Command;
 use crate::network::protocol::Protocol;
 use crate::network::timeout::Timeout;
 use crate::network::{Client, CommandErrors};
+#[cfg(target_has_atomic = "ptr")]
+use crate::subscription::handle::SubscriptionHandle;
+use crate::subscription::handle::{SharedStatus, SubscriptionStatus};
 use crate::subscription::messages::{DecodeError, Message as PushMessage, ToPushMessage};
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
 use bytes::Bytes;
+use core::fmt::{Display, Formatter};
 use embedded_nal::TcpClientStack;
 use embedded_time::Clock;
 
@@ -23,8 +30,60 @@ pub enum Error {
     /// Subscription or Unsubscription was not confirmed by Redis within time limit. Its recommended to close/reconnect the socket to avoid
     /// subsequent errors based on invalid state.
     Timeout,
+    /// Received a push message of unknown type, s. [UnknownPushPolicy::Error]
+    UnknownPush,
+    /// Command is not allowed on RESP2 connections in subscriber mode, s. [Subscription::send_command]
+    CommandNotAllowed,
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::CommandError(error) => write!(f, "sending (un)subscribe command failed: {error}"),
+            Error::ClockError => write!(f, "clock/timer failure"),
+            Error::TcpError => write!(f, "network error"),
+            Error::DecodeError => write!(f, "failed decoding push message"),
+            Error::Timeout => write!(f, "(un)subscription not confirmed within the expected time frame"),
+            Error::UnknownPush => write!(f, "received push message of unknown type"),
+            Error::CommandNotAllowed => write!(f, "command not allowed in RESP2 subscriber mode"),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::CommandError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Handling of push messages of unknown type (e.g. client tracking invalidation or module specific pushes)
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum UnknownPushPolicy {
+    /// Unknown push messages are silently dropped
+    #[default]
+    Ignore,
+    /// [receive](Subscription::receive) returns [Error::UnknownPush]. The message itself is dropped.
+    Error,
+    /// Unknown push messages are collected and may be taken by [take_unknown_pushes](Subscription::take_unknown_pushes)
+    Collect,
+}
+
+/// Commands accepted by Redis on RESP2 connections in subscriber mode
+const SUBSCRIBER_MODE_COMMANDS: [&[u8]; 9] = [
+    b"SUBSCRIBE",
+    b"SSUBSCRIBE",
+    b"SUNSUBSCRIBE",
+    b"PSUBSCRIBE",
+    b"UNSUBSCRIBE",
+    b"PUNSUBSCRIBE",
+    b"PING",
+    b"RESET",
+    b"QUIT",
+];
+
 /// A published subscription message
 #[derive(Debug, Clone)]
 pub struct Message {
@@ -33,9 +92,31 @@ pub struct Message {
 
     /// The actual payload
     pub payload: Bytes,
+
+    /// The pattern matching the channel in case of pattern subscriptions, s. [Client::psubscribe]
+    pub pattern: Option<Bytes>,
 }
 
-/// Client for handling subscriptions
+impl Message {
+    /// Converts published push messages. Returns None for any other push message.
+    fn from_push(message: PushMessage) -> Option<Self> {
+        match message {
+            PushMessage::Publish(channel, payload) => Some(Self {
+                channel,
+                payload,
+                pattern: None,
+            }),
+            PushMessage::PatternPublish(pattern, channel, payload) => Some(Self {
+                channel,
+                payload,
+                pattern: Some(pattern),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Client for handling subscriptions of channels (s. [Client::subscribe]) or patterns (s. [Client::psubscribe])
 ///
 /// L: Number of subscribed topics
 #[derive(Debug)]
@@ -50,8 +131,23 @@ where
     /// List of subscribed topics
     channels: [Bytes; L],
 
-    /// Confirmed + active subscription
-    subscribed: bool,
+    /// True if the topics are patterns (PSUBSCRIBE) instead of channels (SUBSCRIBE)
+    patterns: bool,
+
+    /// Topics removed by [punsubscribe](Self::punsubscribe)
+    removed: [bool; L],
+
+    /// Confirmation and number of topics currently subscribed to. Shared with `SubscriptionHandle`.
+    status: SharedStatus,
+
+    /// Handling of unknown push messages
+    unknown_push_policy: UnknownPushPolicy,
+
+    /// Unknown push messages collected in case of [UnknownPushPolicy::Collect]
+    unknown_pushes: Vec<P::FrameType>,
+
+    /// Messages received while waiting for the subscription confirmation
+    pending: VecDeque<Message>,
 }
 
 impl<'a, N, C, P, const L: usize> Subscription<'a, N, C, P, L>
@@ -67,36 +163,94 @@ where
         Self {
             client,
             channels: topics,
-            subscribed: false,
+            patterns: false,
+            removed: [false; L],
+            status: SharedStatus::from(SubscriptionStatus::new(L)),
+            unknown_push_policy: UnknownPushPolicy::default(),
+            unknown_pushes: Vec::new(),
+            pending: VecDeque::new(),
         }
     }
 
     /// Receives a message. Returns None in case no message is pending
+    ///
+    /// Push messages of unknown type are handled according to the [UnknownPushPolicy].
     pub fn receive(&mut self) -> Result<Option<Message>, Error> {
+        if let Some(message) = self.pending.pop_front() {
+            return Ok(Some(message));
+        }
+
         loop {
-            let message = self.receive_message()?;
+            let frame = match self.receive_frame()? {
+                None => return Ok(None),
+                Some(frame) => frame,
+            };
 
-            if message.is_none() {
-                return Ok(None);
+            match decode(&frame)? {
+                message @ (PushMessage::Publish(..) | PushMessage::PatternPublish(..)) => {
+                    return Ok(Message::from_push(message))
+                }
+                PushMessage::UnknownPush => match self.unknown_push_policy {
+                    UnknownPushPolicy::Ignore => {}
+                    UnknownPushPolicy::Error => return Err(Error::UnknownPush),
+                    UnknownPushPolicy::Collect => self.unknown_pushes.push(frame),
+                },
+                _ => {}
             }
+        }
+    }
 
-            if let PushMessage::Publish(channel, payload) = message.unwrap() {
-                return Ok(Some(Message { channel, payload }));
-            }
+    /// Sets the handling of push messages of unknown type. Defaults to [UnknownPushPolicy::Ignore].
+    pub fn unknown_push_policy(&mut self, policy: UnknownPushPolicy) -> &mut Self {
+        self.unknown_push_policy = policy;
+        self
+    }
+
+    /// Takes all unknown push messages collected so far, s. [UnknownPushPolicy::Collect]
+    pub fn take_unknown_pushes(&mut self) -> Vec<P::FrameType> {
+        core::mem::take(&mut self.unknown_pushes)
+    }
+
+    /// Returns a handle for querying the subscription status, e.g. from other parts of the application
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn handle(&self) -> SubscriptionHandle {
+        SubscriptionHandle::new(self.status.clone())
+    }
+
+    /// Sends the given command on the subscribed connection without waiting for a reply.
+    ///
+    /// Under RESP2, Redis only accepts subscriber mode commands (SUBSCRIBE, SSUBSCRIBE, SUNSUBSCRIBE, PSUBSCRIBE,
+    /// UNSUBSCRIBE, PUNSUBSCRIBE, PING, RESET and QUIT) on subscribed connections. Any other command is rejected
+    /// by [Error::CommandNotAllowed] without being sent. RESP3 connections are not restricted.
+    ///
+    /// Replies are not returned. As RESP2 replies in subscriber mode are arrays (e.g. `["pong", ""]`), they are
+    /// handled by [receive](Self::receive) according to the [UnknownPushPolicy]. RESP3 replies are regular
+    /// (non-push) frames, which are silently discarded by [receive](Self::receive).
+    pub fn send_command(&mut self, command: CommandBuilder) -> Result<(), Error> {
+        if !self.client.network.get_protocol().supports_push() && !is_subscriber_mode_command(&command) {
+            return Err(Error::CommandNotAllowed);
         }
+
+        self.client.network.send_frame(command.into()).map_err(Error::CommandError)
+    }
+
+    /// Turns the topics to patterns, so PSUBSCRIBE is used instead of SUBSCRIBE
+    pub(crate) fn patterns(mut self) -> Self {
+        self.patterns = true;
+        self
     }
 
     /// Starts the subscription and waits for confirmation
     pub(crate) fn subscribe(mut self) -> Result<Self, Error> {
-        let mut cmd = CommandBuilder::new("SUBSCRIBE");
+        let mut cmd = CommandBuilder::new(if self.patterns { "PSUBSCRIBE" } else { "SUBSCRIBE" });
         for topic in &self.channels {
             cmd = cmd.arg(topic);
         }
 
         self.client.network.send_frame(cmd.into()).map_err(Error::CommandError)?;
-        self.wait_for_confirmation(|message| message == PushMessage::SubConfirmation(self.channels.len()))?;
+        self.wait_for_subscription()?;
 
-        self.subscribed = true;
+        self.status.set_active(true);
         Ok(self)
     }
 
@@ -107,17 +261,126 @@ where
         self.close()
     }
 
+    /// Unsubscribes from the given patterns and waits for the confirmation of each pattern.
+    /// Messages published in the meanwhile are buffered for [receive](Self::receive).
+    ///
+    /// Patterns which are not part of this subscription are confirmed by Redis as well, but have no effect.
+    /// Once all patterns are removed, the subscription is inactive. Does nothing in case of empty list.
+    ///
+    /// *If this fails, it's recommended to close the connection to avoid subsequent errors caused by invalid state*
+    pub fn punsubscribe<const M: usize>(&mut self, patterns: [Bytes; M]) -> Result<(), Error> {
+        if M == 0 {
+            return Ok(());
+        }
+
+        let command = PUnsubscribeCommand::new(patterns);
+        self.client.network.send_frame(command.encode()).map_err(Error::CommandError)?;
+        self.wait_for_pattern_unsubscription(command.patterns())?;
+
+        if self.patterns {
+            for pattern in command.patterns() {
+                for index in 0..L {
+                    if self.channels[index] == pattern {
+                        self.removed[index] = true;
+                    }
+                }
+            }
+
+            let remaining = self.removed.iter().filter(|removed| !**removed).count();
+            self.status.set_topic_count(remaining);
+            if remaining == 0 {
+                self.status.set_active(false);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Unsubscribes from all topics and waits for confirmation
     pub(crate) fn close(&mut self) -> Result<(), Error> {
-        self.subscribed = false;
-        let cmd = CommandBuilder::new("UNSUBSCRIBE");
+        self.status.set_active(false);
 
-        self.client.network.send_frame(cmd.into()).map_err(Error::CommandError)?;
-        self.wait_for_confirmation(|message| message == PushMessage::UnSubConfirmation(0))?;
+        if self.patterns {
+            let cmd = CommandBuilder::new("PUNSUBSCRIBE");
+            self.client.network.send_frame(cmd.into()).map_err(Error::CommandError)?;
+            self.wait_for_confirmation(|message| {
+                matches!(message, PushMessage::PatternUnSubConfirmation(_, 0))
+            })?;
+        } else {
+            let cmd = CommandBuilder::new("UNSUBSCRIBE");
+            self.client.network.send_frame(cmd.into()).map_err(Error::CommandError)?;
+            self.wait_for_confirmation(|message| message == PushMessage::UnSubConfirmation(0))?;
+        }
 
         Ok(())
     }
 
+    /// Waits for the confirmation of each channel. Confirmations may arrive in any order.
+    /// Messages published in the meanwhile are buffered for [receive](Self::receive).
+    fn wait_for_subscription(&mut self) -> Result<(), Error> {
+        let timeout =
+            Timeout::new(self.client.clock, self.client.timeout_duration).map_err(|_| Error::ClockError)?;
+        let mut confirmed = [false; L];
+
+        while !timeout.expired().map_err(|_| Error::ClockError)? {
+            let topic = match self.receive_message()? {
+                Some(PushMessage::SubConfirmation(channel, _)) if !self.patterns => Some(channel),
+                Some(PushMessage::PatternSubConfirmation(pattern, _)) if self.patterns => Some(pattern),
+                Some(message) => {
+                    self.pending.extend(Message::from_push(message));
+                    None
+                }
+                None => None,
+            };
+
+            if let Some(topic) = topic {
+                let position = (0..L).find(|index| !confirmed[*index] && self.channels[*index] == topic);
+                if let Some(index) = position {
+                    confirmed[index] = true;
+                }
+            }
+
+            if confirmed.iter().all(|channel| *channel) {
+                return Ok(());
+            }
+        }
+
+        Err(Error::Timeout)
+    }
+
+    /// Waits for the confirmation of each given pattern. Confirmations may arrive in any order.
+    /// Messages published in the meanwhile are buffered for [receive](Self::receive).
+    fn wait_for_pattern_unsubscription(&mut self, patterns: &[Bytes]) -> Result<(), Error> {
+        let timeout =
+            Timeout::new(self.client.clock, self.client.timeout_duration).map_err(|_| Error::ClockError)?;
+        let mut confirmed: Vec<bool> = patterns.iter().map(|_| false).collect();
+
+        while !timeout.expired().map_err(|_| Error::ClockError)? {
+            let pattern = match self.receive_message()? {
+                Some(PushMessage::PatternUnSubConfirmation(pattern, _)) => Some(pattern),
+                Some(message) => {
+                    self.pending.extend(Message::from_push(message));
+                    None
+                }
+                None => None,
+            };
+
+            if let Some(pattern) = pattern {
+                let position =
+                    (0..patterns.len()).find(|index| !confirmed[*index] && patterns[*index] == pattern);
+                if let Some(index) = position {
+                    confirmed[index] = true;
+                }
+            }
+
+            if confirmed.iter().all(|pattern| *pattern) {
+                return Ok(());
+            }
+        }
+
+        Err(Error::Timeout)
+    }
+
     /// Waits for the confirmation of all topics
     fn wait_for_confirmation<F: Fn(PushMessage) -> bool>(&self, is_confirmation: F) -> Result<(), Error> {
         let timeout =
@@ -136,6 +399,14 @@ where
 
     /// Receives and decodes the next message. Returns None in case no message is pending or not complete yet.
     fn receive_message(&self) -> Result<Option<PushMessage>, Error> {
+        match self.receive_frame()? {
+            None => Ok(None),
+            Some(frame) => Ok(Some(decode(&frame)?)),
+        }
+    }
+
+    /// Receives all pending data and returns the next frame, if complete
+    fn receive_frame(&self) -> Result<Option<P::FrameType>, Error> {
         // Receive all pending data
         loop {
             if let Err(error) = self.client.network.receive_chunk() {
@@ -146,27 +417,34 @@ where
             }
         }
 
-        let frame = self.client.network.take_next_frame();
-        if frame.is_none() {
-            return Ok(None);
-        }
-
-        match frame.unwrap().decode_push() {
-            Ok(message) => Ok(Some(message)),
-            Err(error) => match error {
-                DecodeError::ProtocolViolation => Err(Error::DecodeError),
-                DecodeError::IntegerOverflow => Err(Error::DecodeError),
-            },
-        }
+        Ok(self.client.network.take_next_frame())
     }
 
     /// Prevents the automatic unsubscription when client is dropped
     #[cfg(test)]
     pub(crate) fn set_unsubscribed(&mut self) {
-        self.subscribed = false;
+        self.status.set_active(false);
+    }
+}
+
+/// Returns true if the command keyword is allowed in RESP2 subscriber mode
+fn is_subscriber_mode_command(command: &CommandBuilder) -> bool {
+    match command.elements.first() {
+        None => false,
+        Some(keyword) => SUBSCRIBER_MODE_COMMANDS
+            .iter()
+            .any(|allowed| keyword.eq_ignore_ascii_case(allowed)),
     }
 }
 
+/// Decodes the given frame to a push message
+fn decode<F: ToPushMessage>(frame: &F) -> Result<PushMessage, Error> {
+    frame.decode_push().map_err(|error| match error {
+        DecodeError::ProtocolViolation => Error::DecodeError,
+        DecodeError::IntegerOverflow => Error::DecodeError,
+    })
+}
+
 impl<N, C, P, const L: usize> Drop for Subscription<'_, N, C, P, L>
 where
     N: TcpClientStack,
@@ -177,7 +455,7 @@ where
     <P as Protocol>::FrameType: ToPushMessage,
 {
     fn drop(&mut self) {
-        if self.subscribed {
+        if self.status.is_active() {
             let _ = self.close();
         }
     }
@@ -0,0 +1,68 @@
+use crate::commands::builder::CommandBuilder;
+use crate::commands::custom::CustomCommand;
+use crate::subscription::client::Message;
+use bytes::Bytes;
+
+/// Initial ID, all stream entries are newer
+static STREAM_START: Bytes = Bytes::from_static(b"0-0");
+
+/// Reconnect token for resuming a stream backed subscription
+///
+/// Pub/Sub messages published while the subscriber is disconnected are lost. Thus, the publisher may append the
+/// message to a stream (XADD) and publish the ID of the new entry. The cursor tracks the last seen ID, so missed
+/// entries can be read from the stream after reconnecting.
+#[derive(Debug, Clone, Default)]
+pub struct StreamCursor {
+    /// Last seen stream entry ID. None if no message was tracked yet.
+    last_id: Option<Bytes>,
+}
+
+impl StreamCursor {
+    /// Restores a cursor, e.g. after a reconnect or reboot
+    pub fn resume<I>(last_id: I) -> Self
+    where
+        Bytes: From<I>,
+    {
+        Self {
+            last_id: Some(last_id.into()),
+        }
+    }
+
+    /// Tracks the ID of the given message. The message payload is expected to be a stream entry ID.
+    /// Returns false (and leaves the cursor untouched) if the payload is not a valid ID.
+    pub fn track(&mut self, message: &Message) -> bool {
+        if !is_stream_id(&message.payload) {
+            return false;
+        }
+
+        self.last_id = Some(message.payload.clone());
+        true
+    }
+
+    /// Returns the last seen stream entry ID
+    pub fn last_id(&self) -> Option<&Bytes> {
+        self.last_id.as_ref()
+    }
+
+    /// Returns a XREAD command for reading all entries of the given stream newer than the last seen ID.
+    /// If no ID was tracked yet, the stream is read from the beginning.
+    ///
+    /// The command returns the raw response frame.
+    pub fn xread<K>(&self, stream: K) -> CustomCommand
+    where
+        Bytes: From<K>,
+    {
+        CommandBuilder::new("XREAD")
+            .arg_static("STREAMS")
+            .arg(&stream.into())
+            .arg(self.last_id.as_ref().unwrap_or(&STREAM_START))
+            .to_command()
+    }
+}
+
+/// Returns true if the given bytes represent a stream entry ID (`<milliseconds>-<sequence>` or `<milliseconds>`)
+fn is_stream_id(data: &Bytes) -> bool {
+    let mut parts = data.splitn(2, |byte| *byte == b'-');
+
+    parts.all(|part| !part.is_empty() && part.iter().all(u8::is_ascii_digit))
+}
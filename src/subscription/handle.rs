@@ -0,0 +1,86 @@
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Status of a subscription
+///
+/// Atomics are only loaded and stored, as read-modify-write operations are not available on all targets.
+#[derive(Debug)]
+pub(crate) struct SubscriptionStatus {
+    /// Confirmed + active subscription
+    active: AtomicBool,
+
+    /// Number of subscribed topics
+    topics: AtomicUsize,
+}
+
+impl SubscriptionStatus {
+    pub(crate) fn new(topics: usize) -> Self {
+        Self {
+            active: AtomicBool::new(false),
+            topics: AtomicUsize::new(topics),
+        }
+    }
+
+    pub(crate) fn is_active(&self) -> bool {
+        self.active.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn set_active(&self, active: bool) {
+        self.active.store(active, Ordering::Release);
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    pub(crate) fn topic_count(&self) -> usize {
+        self.topics.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn set_topic_count(&self, count: usize) {
+        self.topics.store(count, Ordering::Release);
+    }
+}
+
+/// Status as owned by the subscription. Shared with [SubscriptionHandle] if `Arc` is available, which
+/// requires atomic pointer operations for reference counting.
+#[cfg(target_has_atomic = "ptr")]
+pub(crate) type SharedStatus = Arc<SubscriptionStatus>;
+
+#[cfg(not(target_has_atomic = "ptr"))]
+pub(crate) type SharedStatus = SubscriptionStatus;
+
+/// Read-only view on the status of a [Subscription](crate::subscription::Subscription)
+///
+/// As subscribing consumes the client, the handle allows other parts of the application to query whether the
+/// subscription is still active, without access to the connection. The handle remains valid after the
+/// subscription is dropped and reports it as inactive. The handle is `Send` + `Sync`, so it may be moved to
+/// other tasks.
+///
+/// *The status is shared by `Arc`, so the handle is only available on targets supporting atomic pointer
+/// operations (`target_has_atomic = "ptr"`)*
+#[cfg(target_has_atomic = "ptr")]
+#[derive(Debug, Clone)]
+pub struct SubscriptionHandle {
+    /// Status shared with the subscription
+    status: Arc<SubscriptionStatus>,
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl SubscriptionHandle {
+    pub(crate) fn new(status: Arc<SubscriptionStatus>) -> Self {
+        Self { status }
+    }
+
+    /// Returns true if the subscription is confirmed and not unsubscribed yet
+    pub fn is_active(&self) -> bool {
+        self.status.is_active()
+    }
+
+    /// Returns the number of subscribed channels respectively patterns. Zero if the subscription is not active.
+    pub fn channel_count(&self) -> usize {
+        if self.is_active() {
+            self.status.topic_count()
+        } else {
+            0
+        }
+    }
+}
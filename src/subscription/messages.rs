@@ -5,13 +5,26 @@ use redis_protocol::resp3::types::BytesFrame as Resp3Frame;
 /// A decoded PubSub message
 #[derive(Debug, PartialEq, Eq)]
 pub enum Message {
-    /// Confirmation of a subscription. The integer represents the number of channels currently subscribed to.
-    SubConfirmation(usize),
+    /// Confirmation of a subscription. First value represents the confirmed channel, the second value the number
+    /// of channels currently subscribed to.
+    SubConfirmation(Bytes, usize),
     /// Confirmation of a unsubscribe command. The integer represents the number of channels currently subscribed to.
     UnSubConfirmation(usize),
     /// An actual published message. First value represents the channel, the second value is the actual message payload.
     Publish(Bytes, Bytes),
-    /// Unknown push sub message type (neither subscribe, unsubscribe nor message)
+    /// Confirmation of a pattern subscription. First value represents the confirmed pattern, the second value the
+    /// number of channels and patterns currently subscribed to.
+    PatternSubConfirmation(Bytes, usize),
+    /// Confirmation of a pattern unsubscription. First value represents the removed pattern, the second value the
+    /// number of channels and patterns currently subscribed to.
+    PatternUnSubConfirmation(Bytes, usize),
+    /// A message published to a channel matching a subscribed pattern. Values represent the pattern, the channel and
+    /// the actual message payload.
+    PatternPublish(Bytes, Bytes, Bytes),
+    /// Push message of unknown type (neither (p)subscribe, (p)unsubscribe nor (p)message), e.g. client tracking
+    /// invalidation
+    UnknownPush,
+    /// Frame is not a push message
     Unknown,
 }
 
@@ -27,7 +40,7 @@ pub enum DecodeError {
 /// Decodes frames to messages
 pub trait ToPushMessage {
     /// Tries to decode the frame to a push message
-    fn decode_push(self) -> Result<Message, DecodeError>
+    fn decode_push(&self) -> Result<Message, DecodeError>
     where
         Self: Sized,
     {
@@ -102,12 +115,12 @@ impl ToPushMessage for Resp2Frame {
 }
 
 /// Generic push message decoder for RESP2 + RESP3 frames
-struct Decoder<F: ToPushMessage> {
-    frame: F,
+struct Decoder<'a, F: ToPushMessage> {
+    frame: &'a F,
 }
 
-impl<F: ToPushMessage> Decoder<F> {
-    pub fn new(frame: F) -> Self {
+impl<'a, F: ToPushMessage> Decoder<'a, F> {
+    pub fn new(frame: &'a F) -> Self {
         Self { frame }
     }
 
@@ -117,22 +130,42 @@ impl<F: ToPushMessage> Decoder<F> {
             Some(data) => data,
         };
 
+        if data.is_empty() {
+            return Err(DecodeError::ProtocolViolation);
+        }
+
+        let kind = self.frame.clone_byte_string(&data[0])?;
+        let known = matches!(
+            &kind[..],
+            b"message" | b"subscribe" | b"unsubscribe" | b"pmessage" | b"psubscribe" | b"punsubscribe"
+        );
+
+        // Unknown push types may have a different layout, e.g. invalidation messages consist of two elements
+        if !known {
+            return Ok(Message::UnknownPush);
+        }
+
         if data.len() < 3 {
             return Err(DecodeError::ProtocolViolation);
         }
 
-        match &self.frame.clone_byte_string(&data[0])?[..] {
+        match &kind[..] {
             b"message" => self.decode_message(data),
             b"subscribe" => self.decode_subscribe(data),
             b"unsubscribe" => self.decode_unsubscribe(data),
-            &_ => Ok(Message::Unknown),
+            b"pmessage" => self.decode_pattern_message(data),
+            b"psubscribe" => self.decode_pattern_subscribe(data),
+            _ => self.decode_pattern_unsubscribe(data),
         }
     }
 
     /// Decodes and validates a "subscribe" message
     fn decode_subscribe(&self, data: &[F]) -> Result<Message, DecodeError> {
         let channel_count = self.frame.get_number(&data[2])?;
-        Ok(Message::SubConfirmation(self.cast_channel_count(channel_count)?))
+        Ok(Message::SubConfirmation(
+            self.frame.clone_byte_string(&data[1])?,
+            self.cast_channel_count(channel_count)?,
+        ))
     }
 
     /// Decodes and validates a "unsubscribe" message
@@ -151,6 +184,37 @@ impl<F: ToPushMessage> Decoder<F> {
         ))
     }
 
+    /// Decodes and validates a "psubscribe" message
+    fn decode_pattern_subscribe(&self, data: &[F]) -> Result<Message, DecodeError> {
+        let channel_count = self.frame.get_number(&data[2])?;
+        Ok(Message::PatternSubConfirmation(
+            self.frame.clone_byte_string(&data[1])?,
+            self.cast_channel_count(channel_count)?,
+        ))
+    }
+
+    /// Decodes and validates a "punsubscribe" message
+    fn decode_pattern_unsubscribe(&self, data: &[F]) -> Result<Message, DecodeError> {
+        let channel_count = self.frame.get_number(&data[2])?;
+        Ok(Message::PatternUnSubConfirmation(
+            self.frame.clone_byte_string(&data[1])?,
+            self.cast_channel_count(channel_count)?,
+        ))
+    }
+
+    /// Decodes and validates a "pmessage" message
+    fn decode_pattern_message(&self, data: &[F]) -> Result<Message, DecodeError> {
+        if data.len() < 4 {
+            return Err(DecodeError::ProtocolViolation);
+        }
+
+        Ok(Message::PatternPublish(
+            self.frame.clone_byte_string(&data[1])?,
+            self.frame.clone_byte_string(&data[2])?,
+            self.frame.clone_byte_string(&data[3])?,
+        ))
+    }
+
     /// Safe casting of channel count
     fn cast_channel_count(&self, count: i64) -> Result<usize, DecodeError> {
         if count.is_negative() {
@@ -72,6 +72,100 @@
 //! }
 //! ```
 //!
+//! ## Pattern subscriptions
+//!
+//! Patterns are subscribed by `psubscribe()` (PSUBSCRIBE). Received messages carry the matching pattern.
+//! Single patterns may be removed by [punsubscribe](Subscription::punsubscribe), while receiving continues
+//! on the remaining ones.
+//!
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let server_address = SocketAddr::from_str("127.0.0.1:6379").unwrap();
+//!# let mut connection_handler = ConnectionHandler::resp3(server_address);
+//! let mut subscription = connection_handler
+//!                 .connect(&mut stack, Some(&clock)).unwrap()
+//!                 .psubscribe(["sensor.*".into(), "alarm.*".into()])
+//!                 .unwrap();
+//!
+//! subscription.punsubscribe(["sensor.*".into()]).unwrap();
+//!
+//! if let Some(message) = subscription.receive().unwrap() {
+//!     println!("{:?} matched by {:?}", message.channel, message.pattern);
+//! }
+//! ```
+//!
+//! ## Unknown push messages
+//!
+//! Push messages of unknown type (e.g. client tracking invalidations or module specific pushes) are ignored by default.
+//! Alternatively, [UnknownPushPolicy] allows to either fail or collect them.
+//!
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//! use embedded_redis::subscription::UnknownPushPolicy;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let server_address = SocketAddr::from_str("127.0.0.1:6379").unwrap();
+//!# let mut connection_handler = ConnectionHandler::resp3(server_address);
+//!# let mut client = connection_handler
+//!#                 .connect(&mut stack, Some(&clock)).unwrap()
+//!#                 .subscribe(["first_channel".into()])
+//!#                 .unwrap();
+//!
+//! client.unknown_push_policy(UnknownPushPolicy::Collect);
+//! let _message = client.receive().unwrap();
+//!
+//! for frame in client.take_unknown_pushes() {
+//!     println!("Unknown push: {:?}", frame);
+//! }
+//! ```
+//!
+//! ## RESP2 subscriber mode
+//!
+//! Under RESP2, published messages arrive as regular arrays, so Redis restricts subscribed connections to
+//! SUBSCRIBE, SSUBSCRIBE, SUNSUBSCRIBE, PSUBSCRIBE, UNSUBSCRIBE, PUNSUBSCRIBE, PING, RESET and QUIT.
+//! Even PING replies differently (`["pong", ""]` array instead of `PONG`).
+//! [send_command](Subscription::send_command) enforces this restriction by returning [Error::CommandNotAllowed]
+//! for any other command. RESP3 connections are not restricted, but replies to such commands are discarded.
+//!
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::commands::builder::CommandBuilder;
+//!# use embedded_redis::network::ConnectionHandler;
+//! use embedded_redis::subscription::Error;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let server_address = SocketAddr::from_str("127.0.0.1:6379").unwrap();
+//!# let mut connection_handler = ConnectionHandler::resp2(server_address);
+//!# let mut client = connection_handler
+//!#                 .connect(&mut stack, Some(&clock)).unwrap()
+//!#                 .subscribe(["first_channel".into()])
+//!#                 .unwrap();
+//!
+//! client.send_command(CommandBuilder::new("PING")).unwrap();
+//!
+//! let error = client.send_command(CommandBuilder::new("GET").arg_static("key")).unwrap_err();
+//! assert_eq!(Error::CommandNotAllowed, error);
+//! ```
+//!
 //! ## Unsubscribing
 //!
 //! To leave a clean connection state, unsubscribe from all channels at the end.
@@ -97,10 +191,112 @@
 //! ```
 //!
 //! *Note: `unsubscribe()` is called automatically when the client is dropped*
-pub use client::{Error, Message, Subscription};
+//!
+//! ## Subscription status
+//!
+//! A [SubscriptionHandle] exposes the status of the subscription to other parts of the application, without
+//! access to the connection. The handle is only available on targets supporting atomic pointer operations
+//! (`target_has_atomic = "ptr"`).
+//!
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let server_address = SocketAddr::from_str("127.0.0.1:6379").unwrap();
+//!# let mut connection_handler = ConnectionHandler::resp3(server_address);
+//! let subscription = connection_handler
+//!                 .connect(&mut stack, Some(&clock)).unwrap()
+//!                 .subscribe(["first_channel".into(), "second_channel".into()])
+//!                 .unwrap();
+//!
+//! let handle = subscription.handle();
+//! assert!(handle.is_active());
+//! assert_eq!(2, handle.channel_count());
+//!
+//! subscription.unsubscribe().unwrap();
+//! assert!(!handle.is_active());
+//! ```
+//!
+//! ## Resuming after reconnect
+//!
+//! Messages published while the subscriber is disconnected are lost. If this is not acceptable, the publisher may
+//! append each message to a stream (XADD) and publish the ID of the new entry.
+//! [StreamCursor] tracks the last seen ID, so missed entries may be read by XREAD after reconnecting.
+//!
+//! ```
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!# use embedded_redis::subscription::StreamCursor;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let server_address = SocketAddr::from_str("127.0.0.1:6379").unwrap();
+//!# let mut connection_handler = ConnectionHandler::resp3(server_address);
+//! let mut cursor = StreamCursor::default();
+//!
+//! let mut subscription = connection_handler
+//!                 .connect(&mut stack, Some(&clock)).unwrap()
+//!                 .subscribe(["events".into()])
+//!                 .unwrap();
+//!
+//! if let Some(message) = subscription.receive().unwrap() {
+//!     cursor.track(&message);
+//! }
+//!# drop(subscription);
+//!
+//! // After reconnecting: Reading missed entries, before subscribing again
+//! let client = connection_handler.connect(&mut stack, Some(&clock)).unwrap();
+//! let _missed = client.send(cursor.xread("events_stream")).unwrap().wait().unwrap();
+//! ```
+//!
+//! ## Monitoring
+//!
+//! For debugging purposes, all commands processed by the server may be streamed by MONITOR command.
+//! Like subscriptions, the connection is consumed. Since MONITOR can not be stopped, it's recommended to close
+//! the connection afterward.
+//!
+//! ```no_run
+//!# use core::str::FromStr;
+//!# use core::net::SocketAddr;
+//!# use std_embedded_nal::Stack;
+//!# use std_embedded_time::StandardClock;
+//!# use embedded_redis::network::ConnectionHandler;
+//!#
+//!# let mut stack = Stack::default();
+//!# let clock = StandardClock::default();
+//!#
+//!# let server_address = SocketAddr::from_str("127.0.0.1:6379").unwrap();
+//!# let mut connection_handler = ConnectionHandler::resp2(server_address);
+//! let mut stream = connection_handler
+//!                 .connect(&mut stack, Some(&clock)).unwrap()
+//!                 .monitor()
+//!                 .unwrap();
+//!
+//! if let Some(entry) = stream.receive().unwrap() {
+//!     println!("{:?} executed by {:?}", entry.command, entry.addr);
+//! }
+//! ```
+pub use client::{Error, Message, Subscription, UnknownPushPolicy};
+pub use cursor::StreamCursor;
+#[cfg(target_has_atomic = "ptr")]
+pub use handle::SubscriptionHandle;
+pub use monitor::{MonitorEntry, MonitorStream};
 
 pub(crate) mod client;
+pub(crate) mod cursor;
+pub(crate) mod handle;
 pub(crate) mod messages;
+pub(crate) mod monitor;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,202 @@
+use crate::commands::builder::{CommandBuilder, ToStringOption};
+use crate::commands::hello::HelloCommand;
+use crate::commands::Command;
+use crate::network::protocol::Protocol;
+use crate::network::timeout::Timeout;
+use crate::network::Client;
+use crate::subscription::client::Error;
+use alloc::vec::Vec;
+use bytes::Bytes;
+use embedded_nal::TcpClientStack;
+use embedded_time::Clock;
+
+/// Command processed by the server, as reported by MONITOR
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MonitorEntry {
+    /// Unix timestamp in microseconds
+    pub timestamp: u64,
+
+    /// Database index
+    pub db: u32,
+
+    /// Client address, e.g. `127.0.0.1:60866`, `unix:/tmp/redis.sock` or `lua` for script calls
+    pub addr: Bytes,
+
+    /// Command keyword followed by the arguments
+    pub command: Vec<Bytes>,
+}
+
+impl MonitorEntry {
+    /// Parses a MONITOR line of the following format:
+    ///
+    /// `1339518083.107412 [0 127.0.0.1:60866] "set" "key" "value"`
+    ///
+    /// Returns None in case of an invalid format.
+    pub fn parse(line: &[u8]) -> Option<Self> {
+        let (timestamp, remaining) = split_once(line, b' ')?;
+        let remaining = remaining.strip_prefix(b"[")?;
+        let (db, remaining) = split_once(remaining, b' ')?;
+        let (addr, remaining) = split_once(remaining, b']')?;
+
+        Some(Self {
+            timestamp: parse_timestamp(timestamp)?,
+            db: core::str::from_utf8(db).ok()?.parse().ok()?,
+            addr: Bytes::copy_from_slice(addr),
+            command: parse_arguments(remaining)?,
+        })
+    }
+}
+
+/// Splits at the first occurrence of the given delimiter, which is not included in any part
+fn split_once(data: &[u8], delimiter: u8) -> Option<(&[u8], &[u8])> {
+    let position = data.iter().position(|byte| *byte == delimiter)?;
+    Some((&data[..position], &data[position + 1..]))
+}
+
+/// Converts `seconds.microseconds` to microseconds
+fn parse_timestamp(data: &[u8]) -> Option<u64> {
+    let (seconds, micros) = split_once(data, b'.')?;
+    if micros.len() != 6 {
+        return None;
+    }
+
+    let seconds: u64 = core::str::from_utf8(seconds).ok()?.parse().ok()?;
+    let micros: u64 = core::str::from_utf8(micros).ok()?.parse().ok()?;
+
+    seconds.checked_mul(1_000_000)?.checked_add(micros)
+}
+
+/// Parses the space separated and quoted arguments, while resolving escape sequences
+fn parse_arguments(data: &[u8]) -> Option<Vec<Bytes>> {
+    let mut arguments = Vec::new();
+    let mut bytes = data.iter().copied();
+
+    loop {
+        match bytes.next() {
+            None => break,
+            Some(b' ') => continue,
+            Some(b'"') => {}
+            Some(_) => return None,
+        }
+
+        let mut argument = Vec::new();
+        loop {
+            match bytes.next()? {
+                b'"' => break,
+                b'\\' => argument.push(unescape(&mut bytes)?),
+                byte => argument.push(byte),
+            }
+        }
+
+        arguments.push(Bytes::from(argument));
+    }
+
+    Some(arguments)
+}
+
+/// Resolves the escape sequence following a backslash
+fn unescape<I: Iterator<Item = u8>>(bytes: &mut I) -> Option<u8> {
+    match bytes.next()? {
+        b'n' => Some(b'\n'),
+        b'r' => Some(b'\r'),
+        b't' => Some(b'\t'),
+        b'a' => Some(0x07),
+        b'b' => Some(0x08),
+        b'x' => {
+            let high = (bytes.next()? as char).to_digit(16)?;
+            let low = (bytes.next()? as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        }
+        byte => Some(byte),
+    }
+}
+
+/// Stream of commands processed by the server
+///
+/// As MONITOR can not be stopped, the connection is consumed. It's recommended to close the connection
+/// once the stream is no longer needed.
+#[derive(Debug)]
+pub struct MonitorStream<'a, N: TcpClientStack, C: Clock, P: Protocol>
+where
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    client: Client<'a, N, C, P>,
+}
+
+impl<'a, N, C, P> MonitorStream<'a, N, C, P>
+where
+    N: TcpClientStack,
+    C: Clock,
+    P: Protocol,
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+    <P as Protocol>::FrameType: From<CommandBuilder>,
+    <P as Protocol>::FrameType: ToStringOption,
+{
+    /// Sends MONITOR command and waits for confirmation
+    pub(crate) fn start(client: Client<'a, N, C, P>) -> Result<Self, Error> {
+        let stream = Self { client };
+        stream
+            .client
+            .network
+            .send_frame(CommandBuilder::new("MONITOR").into())
+            .map_err(Error::CommandError)?;
+
+        let timeout = Timeout::new(stream.client.clock, stream.client.timeout_duration)
+            .map_err(|_| Error::ClockError)?;
+
+        while !timeout.expired().map_err(|_| Error::ClockError)? {
+            if let Some(frame) = stream.receive_frame()? {
+                stream
+                    .client
+                    .network
+                    .get_protocol()
+                    .assert_error(&frame)
+                    .map_err(Error::CommandError)?;
+                frame.expect_simple_string("OK").map_err(|_| Error::DecodeError)?;
+                return Ok(stream);
+            }
+        }
+
+        Err(Error::Timeout)
+    }
+
+    /// Receives the next processed command. Returns None in case no entry is pending
+    pub fn receive(&mut self) -> Result<Option<MonitorEntry>, Error> {
+        let frame = match self.receive_frame()? {
+            None => return Ok(None),
+            Some(frame) => frame,
+        };
+
+        let line = frame.to_string_option().ok_or(Error::DecodeError)?;
+        MonitorEntry::parse(line.as_bytes()).map(Some).ok_or(Error::DecodeError)
+    }
+
+    /// Receives all pending data and returns the next frame, if complete
+    fn receive_frame(&self) -> Result<Option<P::FrameType>, Error> {
+        loop {
+            if let Err(error) = self.client.network.receive_chunk() {
+                match error {
+                    nb::Error::Other(_) => return Err(Error::TcpError),
+                    nb::Error::WouldBlock => break,
+                };
+            }
+        }
+
+        Ok(self.client.network.take_next_frame())
+    }
+}
+
+impl<'a, N: TcpClientStack, C: Clock, P: Protocol> Client<'a, N, C, P>
+where
+    HelloCommand: Command<<P as Protocol>::FrameType>,
+{
+    /// Starts monitoring all commands processed by the server. Consumes the client, as the connection
+    /// is exclusively used for streaming the commands afterward.
+    pub fn monitor(self) -> Result<MonitorStream<'a, N, C, P>, Error>
+    where
+        <P as Protocol>::FrameType: From<CommandBuilder>,
+        <P as Protocol>::FrameType: ToStringOption,
+    {
+        MonitorStream::start(self)
+    }
+}
@@ -1,9 +1,14 @@
+use crate::commands::builder::CommandBuilder;
 use crate::network::buffer::Network;
 use crate::network::tests::mocks::{create_mocked_client, NetworkMockBuilder};
 use crate::network::tests::mocks::{SocketMock, TestClock};
-use crate::network::{Client, MemoryParameters, Resp3};
-use crate::subscription::client::Error;
+use crate::network::{Client, CommandErrors, MemoryParameters, Resp2, Resp3};
+use crate::subscription::client::{Error, UnknownPushPolicy};
+use crate::subscription::handle::SubscriptionHandle;
+use alloc::string::ToString;
+use core::error::Error as _;
 use embedded_time::duration::Extensions;
+use redis_protocol::resp3::types::BytesFrame as Resp3Frame;
 use std::cell::RefCell;
 
 #[test]
@@ -64,6 +69,53 @@ fn test_subscribe_confirmation_multi_channel() {
         .set_unsubscribed();
 }
 
+#[test]
+fn test_subscribe_confirmation_out_of_order() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$9\r\nSUBSCRIBE\r\n$5\r\nfirst\r\n$6\r\nsecond\r\n")
+        .sub_confirmation_resp3("second", 1)
+        .response_no_data()
+        .sub_confirmation_resp3("first", 2)
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .subscribe(["first".into(), "second".into()])
+        .unwrap()
+        .set_unsubscribed();
+}
+
+#[test]
+fn test_subscribe_confirmation_message_buffered() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$9\r\nSUBSCRIBE\r\n$5\r\nfirst\r\n$6\r\nsecond\r\n")
+        .sub_confirmation_resp3("first", 1)
+        .response_no_data()
+        .sub_message("first", "payload")
+        .response_no_data()
+        .sub_confirmation_resp3("second", 2)
+        .response_no_data()
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .subscribe(["first".into(), "second".into()])
+        .unwrap();
+
+    let message = client.receive().unwrap().unwrap();
+    assert_eq!("first", core::str::from_utf8(&message.channel[..]).unwrap());
+    assert_eq!("payload", core::str::from_utf8(&message.payload[..]).unwrap());
+
+    assert!(client.receive().unwrap().is_none());
+    client.set_unsubscribed();
+}
+
 #[test]
 fn test_subscribe_confirmation_other_responses_ignored() {
     let clock = TestClock::new(vec![]);
@@ -164,6 +216,7 @@ fn test_subscribe_confirmation_within_timeout() {
         timeout_duration: 150.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     client.subscribe(["test_topic".into()]).unwrap().set_unsubscribed();
@@ -195,6 +248,7 @@ fn test_subscribe_confirmation_timeout() {
         timeout_duration: 150.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     let error = client.subscribe(["test_topic".into()]).unwrap_err();
@@ -267,6 +321,107 @@ fn test_receive_other_unknown_sub_type_ignored() {
     client.set_unsubscribed();
 }
 
+#[test]
+fn test_receive_unknown_push_policy_ignore() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .sub_confirmation_resp3("test_topic", 1)
+        .response_no_data()
+        .response(">2\r\n$10\r\ninvalidate\r\n")
+        .response("*1\r\n$3\r\nkey\r\n")
+        .response_no_data()
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .subscribe(["test_topic".into()])
+        .unwrap();
+
+    client.unknown_push_policy(UnknownPushPolicy::Ignore);
+    assert!(client.receive().unwrap().is_none());
+    assert!(client.take_unknown_pushes().is_empty());
+    client.set_unsubscribed();
+}
+
+#[test]
+fn test_receive_unknown_push_policy_error() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .sub_confirmation_resp3("test_topic", 1)
+        .response_no_data()
+        .response(">2\r\n$10\r\ninvalidate\r\n")
+        .response("*1\r\n$3\r\nkey\r\n")
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .subscribe(["test_topic".into()])
+        .unwrap();
+
+    client.unknown_push_policy(UnknownPushPolicy::Error);
+    assert_eq!(Error::UnknownPush, client.receive().unwrap_err());
+    assert!(client.take_unknown_pushes().is_empty());
+    client.set_unsubscribed();
+}
+
+#[test]
+fn test_receive_unknown_push_policy_collect() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .sub_confirmation_resp3("test_topic", 1)
+        .response_no_data()
+        .response(">2\r\n$10\r\ninvalidate\r\n")
+        .response("*1\r\n$3\r\nkey\r\n")
+        .sub_message("test_channel", "test_payload")
+        .response_no_data()
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .subscribe(["test_topic".into()])
+        .unwrap();
+
+    client.unknown_push_policy(UnknownPushPolicy::Collect);
+    let message = client.receive().unwrap().unwrap();
+    assert_eq!(
+        "test_payload",
+        core::str::from_utf8(&message.payload[..]).unwrap()
+    );
+
+    let pushes = client.take_unknown_pushes();
+    assert_eq!(1, pushes.len());
+    assert_eq!(
+        Resp3Frame::Push {
+            data: vec![
+                Resp3Frame::BlobString {
+                    data: "invalidate".into(),
+                    attributes: None
+                },
+                Resp3Frame::Array {
+                    data: vec![Resp3Frame::BlobString {
+                        data: "key".into(),
+                        attributes: None
+                    }],
+                    attributes: None
+                }
+            ],
+            attributes: None
+        },
+        pushes[0]
+    );
+    assert!(client.take_unknown_pushes().is_empty());
+    client.set_unsubscribed();
+}
+
 #[test]
 fn test_receive_correct_message() {
     let clock = TestClock::new(vec![]);
@@ -424,6 +579,7 @@ fn test_unsubscribe_confirmation_within_timeout() {
         timeout_duration: 150.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     client.subscribe(["test_topic".into()]).unwrap().unsubscribe().unwrap();
@@ -458,6 +614,7 @@ fn test_unsubscribe_confirmation_timeout() {
         timeout_duration: 150.microseconds(),
         clock: Some(&clock),
         hello_response: None,
+        assumed_version: None,
     };
 
     let error = client.subscribe(["test_topic".into()]).unwrap().unsubscribe().unwrap_err();
@@ -486,3 +643,319 @@ fn test_unsubscribe_on_drop() {
             .unwrap();
     }
 }
+
+#[test]
+fn test_handle_reflects_subscription_status() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*3\r\n$9\r\nSUBSCRIBE\r\n$5\r\nfirst\r\n$6\r\nsecond\r\n")
+        .sub_confirmation_resp3("first", 1)
+        .response_no_data()
+        .sub_confirmation_resp3("second", 2)
+        .response_no_data()
+        .send(164, "*1\r\n$11\r\nUNSUBSCRIBE\r\n")
+        .unsub_confirmation_resp3("second", 0)
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let subscription = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .subscribe(["first".into(), "second".into()])
+        .unwrap();
+
+    let handle = subscription.handle();
+    assert!(handle.is_active());
+    assert_eq!(2, handle.channel_count());
+
+    subscription.unsubscribe().unwrap();
+    assert!(!handle.is_active());
+    assert_eq!(0, handle.channel_count());
+}
+
+#[test]
+fn test_handle_send_sync() {
+    fn assert_send<T: Send>() {}
+    fn assert_sync<T: Sync>() {}
+
+    assert_send::<SubscriptionHandle>();
+    assert_sync::<SubscriptionHandle>();
+}
+
+#[test]
+fn test_send_command_resp2_disallowed_command() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$9\r\nSUBSCRIBE\r\n$10\r\ntest_topic\r\n")
+        .response("*3\r\n$9\r\nsubscribe\r\n")
+        .response("$10\r\ntest_topic\r\n:1\r\n")
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut subscription = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {})
+        .subscribe(["test_topic".into()])
+        .unwrap();
+
+    let error = subscription
+        .send_command(CommandBuilder::new("GET").arg_static("key"))
+        .unwrap_err();
+    assert_eq!(Error::CommandNotAllowed, error);
+
+    subscription.set_unsubscribed();
+}
+
+#[test]
+fn test_send_command_resp2_ping_allowed() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$9\r\nSUBSCRIBE\r\n$10\r\ntest_topic\r\n")
+        .response("*3\r\n$9\r\nsubscribe\r\n")
+        .response("$10\r\ntest_topic\r\n:1\r\n")
+        .response_no_data()
+        .send(164, "*1\r\n$4\r\nping\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut subscription = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {})
+        .subscribe(["test_topic".into()])
+        .unwrap();
+
+    subscription.send_command(CommandBuilder::new("ping")).unwrap();
+    subscription.set_unsubscribed();
+}
+
+#[test]
+fn test_send_command_resp3_not_restricted() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$9\r\nSUBSCRIBE\r\n$10\r\ntest_topic\r\n")
+        .sub_confirmation_resp3("test_topic", 1)
+        .response_no_data()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut subscription = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .subscribe(["test_topic".into()])
+        .unwrap();
+
+    subscription.send_command(CommandBuilder::new("GET").arg_static("key")).unwrap();
+    subscription.set_unsubscribed();
+}
+
+#[test]
+fn test_send_command_resp3_reply_discarded() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$9\r\nSUBSCRIBE\r\n$10\r\ntest_topic\r\n")
+        .sub_confirmation_resp3("test_topic", 1)
+        .response_no_data()
+        .send(164, "*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n")
+        .response_string("value")
+        .response_no_data()
+        .sub_message("test_topic", "payload")
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut subscription = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .subscribe(["test_topic".into()])
+        .unwrap();
+    subscription.unknown_push_policy(UnknownPushPolicy::Collect);
+
+    subscription.send_command(CommandBuilder::new("GET").arg_static("key")).unwrap();
+    let message = subscription.receive().unwrap().unwrap();
+    assert_eq!("payload", core::str::from_utf8(&message.payload[..]).unwrap());
+    assert!(subscription.take_unknown_pushes().is_empty());
+    subscription.set_unsubscribed();
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(
+        "(un)subscription not confirmed within the expected time frame",
+        Error::Timeout.to_string()
+    );
+    assert_eq!("failed decoding push message", Error::DecodeError.to_string());
+
+    let error = Error::CommandError(CommandErrors::TcpError);
+    assert_eq!(
+        "sending (un)subscribe command failed: network error",
+        error.to_string()
+    );
+    assert!(error.source().is_some());
+
+    assert_eq!(
+        "command not allowed in RESP2 subscriber mode",
+        Error::CommandNotAllowed.to_string()
+    );
+}
+
+#[test]
+fn test_psubscribe_confirmation() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*3\r\n$10\r\nPSUBSCRIBE\r\n$7\r\nfirst.*\r\n$8\r\nsecond.*\r\n",
+        )
+        .sub_confirmation_resp3("first.*", 1)
+        .response_no_data()
+        .psub_confirmation_resp3("second.*", 1)
+        .response_no_data()
+        .psub_confirmation_resp3("first.*", 2)
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .psubscribe(["first.*".into(), "second.*".into()])
+        .unwrap()
+        .set_unsubscribed();
+}
+
+#[test]
+fn test_psubscribe_receive_pattern_message() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$10\r\nPSUBSCRIBE\r\n$7\r\nfirst.*\r\n")
+        .psub_confirmation_resp3("first.*", 1)
+        .response_no_data()
+        .sub_pattern_message("first.*", "first.channel", "payload")
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut subscription = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .psubscribe(["first.*".into()])
+        .unwrap();
+
+    let message = subscription.receive().unwrap().unwrap();
+    assert_eq!(
+        "first.*",
+        core::str::from_utf8(&message.pattern.unwrap()[..]).unwrap()
+    );
+    assert_eq!(
+        "first.channel",
+        core::str::from_utf8(&message.channel[..]).unwrap()
+    );
+    assert_eq!("payload", core::str::from_utf8(&message.payload[..]).unwrap());
+    subscription.set_unsubscribed();
+}
+
+#[test]
+fn test_punsubscribe_single_pattern_continue_receiving() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(
+            164,
+            "*3\r\n$10\r\nPSUBSCRIBE\r\n$7\r\nfirst.*\r\n$8\r\nsecond.*\r\n",
+        )
+        .psub_confirmation_resp3("first.*", 1)
+        .response_no_data()
+        .psub_confirmation_resp3("second.*", 2)
+        .response_no_data()
+        .send(164, "*2\r\n$12\r\nPUNSUBSCRIBE\r\n$7\r\nfirst.*\r\n")
+        .sub_pattern_message("second.*", "second.channel", "buffered")
+        .response_no_data()
+        .punsub_confirmation_resp3("first.*", 1)
+        .response_no_data()
+        .sub_pattern_message("second.*", "second.channel", "payload")
+        .response_no_data()
+        .send(164, "*1\r\n$12\r\nPUNSUBSCRIBE\r\n")
+        .punsub_confirmation_resp3("second.*", 0)
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut subscription = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .psubscribe(["first.*".into(), "second.*".into()])
+        .unwrap();
+
+    let handle = subscription.handle();
+    assert_eq!(2, handle.channel_count());
+
+    subscription.punsubscribe(["first.*".into()]).unwrap();
+    assert!(handle.is_active());
+    assert_eq!(1, handle.channel_count());
+
+    let message = subscription.receive().unwrap().unwrap();
+    assert_eq!("buffered", core::str::from_utf8(&message.payload[..]).unwrap());
+
+    let message = subscription.receive().unwrap().unwrap();
+    assert_eq!(
+        "second.*",
+        core::str::from_utf8(&message.pattern.unwrap()[..]).unwrap()
+    );
+    assert_eq!(
+        "second.channel",
+        core::str::from_utf8(&message.channel[..]).unwrap()
+    );
+    assert_eq!("payload", core::str::from_utf8(&message.payload[..]).unwrap());
+
+    subscription.unsubscribe().unwrap();
+    assert!(!handle.is_active());
+}
+
+#[test]
+fn test_punsubscribe_all_patterns_inactive() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$10\r\nPSUBSCRIBE\r\n$7\r\nfirst.*\r\n")
+        .psub_confirmation_resp3("first.*", 1)
+        .response_no_data()
+        .send(164, "*2\r\n$12\r\nPUNSUBSCRIBE\r\n$7\r\nfirst.*\r\n")
+        .punsub_confirmation_resp3("first.*", 0)
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut subscription = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {})
+        .psubscribe(["first.*".into()])
+        .unwrap();
+    let handle = subscription.handle();
+
+    // No further PUNSUBSCRIBE is sent on drop
+    subscription.punsubscribe(["first.*".into()]).unwrap();
+    assert!(!handle.is_active());
+    assert_eq!(0, handle.channel_count());
+}
+
+#[test]
+fn test_punsubscribe_confirmation_timeout() {
+    let clock = TestClock::new(vec![
+        1,    // Timer creation
+        50,   // receive() call
+        1000, // Timer creation
+        1050, // receive() call
+        1250, // receive() call
+    ]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*2\r\n$10\r\nPSUBSCRIBE\r\n$7\r\nfirst.*\r\n")
+        .psub_confirmation_resp3("first.*", 1)
+        .response_no_data()
+        .send(164, "*2\r\n$12\r\nPUNSUBSCRIBE\r\n$7\r\nfirst.*\r\n")
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut client = create_mocked_client(&mut network, &mut socket, &clock, Resp3 {});
+    client.timeout_duration = 200.microseconds();
+
+    let mut subscription = client.psubscribe(["first.*".into()]).unwrap();
+    assert_eq!(
+        Error::Timeout,
+        subscription.punsubscribe(["first.*".into()]).unwrap_err()
+    );
+    subscription.set_unsubscribed();
+}
@@ -0,0 +1,82 @@
+use crate::commands::Command;
+use crate::subscription::client::Message;
+use crate::subscription::cursor::StreamCursor;
+use redis_protocol::resp2::types::{BytesFrame as Resp2Frame, Resp2Frame as _};
+
+fn message(payload: &'static str) -> Message {
+    Message {
+        channel: "events".into(),
+        payload: payload.into(),
+        pattern: None,
+    }
+}
+
+#[test]
+fn test_default_no_id() {
+    assert!(StreamCursor::default().last_id().is_none());
+}
+
+#[test]
+fn test_track_full_id() {
+    let mut cursor = StreamCursor::default();
+
+    assert!(cursor.track(&message("1526919030474-55")));
+    assert_eq!("1526919030474-55", cursor.last_id().unwrap());
+}
+
+#[test]
+fn test_track_milliseconds_only() {
+    let mut cursor = StreamCursor::default();
+
+    assert!(cursor.track(&message("1526919030474")));
+    assert_eq!("1526919030474", cursor.last_id().unwrap());
+}
+
+#[test]
+fn test_track_latest_id() {
+    let mut cursor = StreamCursor::default();
+
+    cursor.track(&message("1-1"));
+    cursor.track(&message("1-2"));
+    assert_eq!("1-2", cursor.last_id().unwrap());
+}
+
+#[test]
+fn test_track_invalid_id_ignored() {
+    let mut cursor = StreamCursor::resume("1-1");
+
+    assert!(!cursor.track(&message("payload")));
+    assert!(!cursor.track(&message("")));
+    assert!(!cursor.track(&message("1-")));
+    assert!(!cursor.track(&message("-1")));
+    assert!(!cursor.track(&message("1-2-3")));
+    assert_eq!("1-1", cursor.last_id().unwrap());
+}
+
+#[test]
+fn test_xread_from_start() {
+    let frame: Resp2Frame = StreamCursor::default().xread("stream").encode();
+
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("XREAD", array[0].to_string().unwrap());
+        assert_eq!("STREAMS", array[1].to_string().unwrap());
+        assert_eq!("stream", array[2].to_string().unwrap());
+        assert_eq!("0-0", array[3].to_string().unwrap());
+    } else {
+        panic!("Unexpected frame type");
+    }
+}
+
+#[test]
+fn test_xread_resumed() {
+    let frame: Resp2Frame = StreamCursor::resume("5-3").xread("stream").encode();
+
+    if let Resp2Frame::Array(array) = frame {
+        assert_eq!(4, array.len());
+        assert_eq!("stream", array[2].to_string().unwrap());
+        assert_eq!("5-3", array[3].to_string().unwrap());
+    } else {
+        panic!("Unexpected frame type");
+    }
+}
@@ -14,6 +14,29 @@ fn test_decode_resp2_no_push() {
     assert_eq!(Message::Unknown, MockFrames::ok_resp2().decode_push().unwrap())
 }
 
+#[test]
+fn test_decode_resp3_unknown_push() {
+    let frame = Resp3Frame::Push {
+        data: vec![
+            Resp3Frame::BlobString {
+                data: Bytes::from_static(b"invalidate"),
+                attributes: None,
+            },
+            Resp3Frame::Null,
+        ],
+        attributes: None,
+    };
+
+    assert_eq!(Message::UnknownPush, frame.decode_push().unwrap())
+}
+
+#[test]
+fn test_decode_resp2_unknown_push() {
+    let frame = Resp2Frame::Array(vec![Resp2Frame::BulkString(Bytes::from_static(b"invalidate"))]);
+
+    assert_eq!(Message::UnknownPush, frame.decode_push().unwrap())
+}
+
 #[test]
 fn test_decode_resp3_incomplete_push() {
     let frame = Resp3Frame::Push {
@@ -107,7 +130,10 @@ fn test_decode_resp3_subscribe_correct() {
         attributes: None,
     };
 
-    assert_eq!(Message::SubConfirmation(3), frame.decode_push().unwrap())
+    assert_eq!(
+        Message::SubConfirmation(Bytes::from_static(b"test"), 3),
+        frame.decode_push().unwrap()
+    )
 }
 
 #[test]
@@ -118,7 +144,10 @@ fn test_decode_resp2_subscribe_correct() {
         Resp2Frame::Integer(4),
     ]);
 
-    assert_eq!(Message::SubConfirmation(4), frame.decode_push().unwrap())
+    assert_eq!(
+        Message::SubConfirmation(Bytes::from_static(b"test_channel"), 4),
+        frame.decode_push().unwrap()
+    )
 }
 
 #[test]
@@ -302,3 +331,147 @@ fn test_decode_resp2_message_blob() {
         frame.decode_push().unwrap()
     )
 }
+
+#[test]
+fn test_decode_resp3_psubscribe_correct() {
+    let frame = Resp3Frame::Push {
+        data: vec![
+            Resp3Frame::SimpleString {
+                data: Bytes::from_static(b"psubscribe"),
+                attributes: None,
+            },
+            Resp3Frame::SimpleString {
+                data: Bytes::from_static(b"test.*"),
+                attributes: None,
+            },
+            Resp3Frame::Number {
+                data: 2,
+                attributes: None,
+            },
+        ],
+        attributes: None,
+    };
+
+    assert_eq!(
+        Message::PatternSubConfirmation(Bytes::from_static(b"test.*"), 2),
+        frame.decode_push().unwrap()
+    )
+}
+
+#[test]
+fn test_decode_resp2_psubscribe_correct() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString(Bytes::from_static(b"psubscribe")),
+        Resp2Frame::BulkString(Bytes::from_static(b"test.*")),
+        Resp2Frame::Integer(1),
+    ]);
+
+    assert_eq!(
+        Message::PatternSubConfirmation(Bytes::from_static(b"test.*"), 1),
+        frame.decode_push().unwrap()
+    )
+}
+
+#[test]
+fn test_decode_resp3_punsubscribe_correct() {
+    let frame = Resp3Frame::Push {
+        data: vec![
+            Resp3Frame::SimpleString {
+                data: Bytes::from_static(b"punsubscribe"),
+                attributes: None,
+            },
+            Resp3Frame::SimpleString {
+                data: Bytes::from_static(b"test.*"),
+                attributes: None,
+            },
+            Resp3Frame::Number {
+                data: 0,
+                attributes: None,
+            },
+        ],
+        attributes: None,
+    };
+
+    assert_eq!(
+        Message::PatternUnSubConfirmation(Bytes::from_static(b"test.*"), 0),
+        frame.decode_push().unwrap()
+    )
+}
+
+#[test]
+fn test_decode_resp2_punsubscribe_correct() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString(Bytes::from_static(b"punsubscribe")),
+        Resp2Frame::BulkString(Bytes::from_static(b"test.*")),
+        Resp2Frame::Integer(3),
+    ]);
+
+    assert_eq!(
+        Message::PatternUnSubConfirmation(Bytes::from_static(b"test.*"), 3),
+        frame.decode_push().unwrap()
+    )
+}
+
+#[test]
+fn test_decode_resp3_pmessage_correct() {
+    let frame = Resp3Frame::Push {
+        data: vec![
+            Resp3Frame::SimpleString {
+                data: Bytes::from_static(b"pmessage"),
+                attributes: None,
+            },
+            Resp3Frame::BlobString {
+                data: Bytes::from_static(b"test.*"),
+                attributes: None,
+            },
+            Resp3Frame::BlobString {
+                data: Bytes::from_static(b"test.channel"),
+                attributes: None,
+            },
+            Resp3Frame::BlobString {
+                data: Bytes::from_static(b"payload"),
+                attributes: None,
+            },
+        ],
+        attributes: None,
+    };
+
+    assert_eq!(
+        Message::PatternPublish(
+            Bytes::from_static(b"test.*"),
+            Bytes::from_static(b"test.channel"),
+            Bytes::from_static(b"payload")
+        ),
+        frame.decode_push().unwrap()
+    )
+}
+
+#[test]
+fn test_decode_resp2_pmessage_correct() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString(Bytes::from_static(b"pmessage")),
+        Resp2Frame::BulkString(Bytes::from_static(b"test.*")),
+        Resp2Frame::BulkString(Bytes::from_static(b"test.channel")),
+        Resp2Frame::BulkString(Bytes::from_static(b"payload")),
+    ]);
+
+    assert_eq!(
+        Message::PatternPublish(
+            Bytes::from_static(b"test.*"),
+            Bytes::from_static(b"test.channel"),
+            Bytes::from_static(b"payload")
+        ),
+        frame.decode_push().unwrap()
+    )
+}
+
+#[test]
+fn test_decode_resp2_pmessage_missing_payload() {
+    let frame = Resp2Frame::Array(vec![
+        Resp2Frame::BulkString(Bytes::from_static(b"pmessage")),
+        Resp2Frame::BulkString(Bytes::from_static(b"test.*")),
+        Resp2Frame::BulkString(Bytes::from_static(b"test.channel")),
+    ]);
+
+    assert_eq!(DecodeError::ProtocolViolation, frame.decode_push().unwrap_err())
+}
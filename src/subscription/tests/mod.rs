@@ -1,2 +1,4 @@
 mod client;
+mod cursor;
 mod message;
+mod monitor;
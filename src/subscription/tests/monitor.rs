@@ -0,0 +1,128 @@
+use crate::network::tests::mocks::{create_mocked_client, NetworkMockBuilder, SocketMock, TestClock};
+use crate::network::{CommandErrors, Resp2};
+use crate::subscription::client::Error;
+use crate::subscription::monitor::MonitorEntry;
+use alloc::string::ToString;
+use bytes::Bytes;
+
+#[test]
+fn test_parse_entry() {
+    let entry = MonitorEntry::parse(br#"1339518083.107412 [0 127.0.0.1:60866] "set" "key" "value""#).unwrap();
+
+    assert_eq!(1339518083107412, entry.timestamp);
+    assert_eq!(0, entry.db);
+    assert_eq!("127.0.0.1:60866", entry.addr);
+    assert_eq!(
+        vec![
+            Bytes::from_static(b"set"),
+            Bytes::from_static(b"key"),
+            Bytes::from_static(b"value")
+        ],
+        entry.command
+    );
+}
+
+#[test]
+fn test_parse_entry_lua() {
+    let entry = MonitorEntry::parse(br#"1339518087.877697 [15 lua] "get" "counter""#).unwrap();
+
+    assert_eq!(15, entry.db);
+    assert_eq!("lua", entry.addr);
+    assert_eq!(2, entry.command.len());
+}
+
+#[test]
+fn test_parse_entry_escaped_arguments() {
+    let entry = MonitorEntry::parse(
+        br#"1339518083.000001 [0 unix:/tmp/redis.sock] "set" "a \"b\"" "x\r\n\x00\xff\\""#,
+    )
+    .unwrap();
+
+    assert_eq!(1339518083000001, entry.timestamp);
+    assert_eq!("unix:/tmp/redis.sock", entry.addr);
+    assert_eq!(Bytes::from_static(b"a \"b\""), entry.command[1]);
+    assert_eq!(Bytes::from_static(b"x\r\n\x00\xff\\"), entry.command[2]);
+}
+
+#[test]
+fn test_parse_entry_empty_argument() {
+    let entry = MonitorEntry::parse(br#"1339518083.107412 [0 127.0.0.1:1] "set" "key" """#).unwrap();
+    assert_eq!(Bytes::new(), entry.command[2]);
+}
+
+#[test]
+fn test_parse_entry_invalid() {
+    assert!(MonitorEntry::parse(b"").is_none());
+    assert!(MonitorEntry::parse(br#"1339518083 [0 127.0.0.1:1] "get""#).is_none());
+    assert!(MonitorEntry::parse(br#"1339518083.107412 0 127.0.0.1:1 "get""#).is_none());
+    assert!(MonitorEntry::parse(br#"1339518083.107412 [x 127.0.0.1:1] "get""#).is_none());
+    assert!(MonitorEntry::parse(br#"1339518083.107412 [0 127.0.0.1:1] "get"#).is_none());
+    assert!(MonitorEntry::parse(br#"1339518083.107412 [0 127.0.0.1:1] get"#).is_none());
+    assert!(MonitorEntry::parse(br#"1339518083.107412 [0 127.0.0.1:1] "\xZZ""#).is_none());
+}
+
+#[test]
+fn test_monitor_stream_receive() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "*1\r\n$7\r\nMONITOR\r\n")
+        .response_ok()
+        .response_no_data()
+        .response("+1.000001 [0 lua] \"ping\"\r\n")
+        .response_no_data()
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut stream = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {})
+        .monitor()
+        .unwrap();
+
+    let entry = stream.receive().unwrap().unwrap();
+    assert_eq!(1000001, entry.timestamp);
+    assert_eq!(vec![Bytes::from_static(b"ping")], entry.command);
+
+    assert!(stream.receive().unwrap().is_none());
+}
+
+#[test]
+fn test_monitor_error_response() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .response_error()
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let error = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {})
+        .monitor()
+        .unwrap_err();
+
+    assert_eq!(
+        Error::CommandError(CommandErrors::ErrorResponse("Error".to_string())),
+        error
+    );
+}
+
+#[test]
+fn test_monitor_invalid_entry() {
+    let clock = TestClock::new(vec![]);
+
+    let mut network = NetworkMockBuilder::default()
+        .send(164, "")
+        .response_ok()
+        .response_no_data()
+        .response("+invalid\r\n")
+        .response_no_data()
+        .into_mock();
+
+    let mut socket = SocketMock::new(164);
+    let mut stream = create_mocked_client(&mut network, &mut socket, &clock, Resp2 {})
+        .monitor()
+        .unwrap();
+
+    assert_eq!(Error::DecodeError, stream.receive().unwrap_err());
+}